@@ -2,6 +2,14 @@ use std::collections::HashSet;
 
 use domain::traits::field::Field;
 
+use crate::{
+    error::{AppError, AppResult},
+    ports::SortBy,
+    projection::fields::FieldCatalog,
+};
+
+use super::field_policy::FieldGrant;
+
 pub struct AccessControl<T: Field> {
     pub can_read: bool,
     pub readable_fields: HashSet<T>,
@@ -16,4 +24,53 @@ impl<T: Field> AccessControl<T> {
             writable_fields,
         }
     }
+
+    /// Rows sorted by a field give its values away, so every field in
+    /// `sort_by` must be readable; sort fields are matched by path.
+    pub fn check_sortable(&self, sort_by: &[SortBy]) -> AppResult<()> {
+        for sort in sort_by {
+            if let SortBy::Ascending(field) | SortBy::Descending(field) = sort
+                && !self
+                    .readable_fields
+                    .iter()
+                    .any(|readable| readable.path() == *field)
+            {
+                return Err(AppError::Forbidden);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: FieldCatalog> AccessControl<T> {
+    /// The fields of `T` whose paths `readable` and `writable` permit.
+    pub fn from_grants(can_read: bool, readable: &FieldGrant, writable: &FieldGrant) -> Self {
+        let permitted = |grant: &FieldGrant| {
+            T::all()
+                .into_iter()
+                .filter(|field| grant.permits(&field.path()))
+                .collect()
+        };
+        Self::new(can_read, permitted(readable), permitted(writable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::projection::fields::report::RepotField;
+
+    use super::*;
+
+    #[test]
+    fn only_readable_fields_sort() {
+        let access = AccessControl::new(true, HashSet::from([RepotField::Title]), HashSet::new());
+
+        assert!(
+            access
+                .check_sortable(&[SortBy::Ascending("title".into()), SortBy::None])
+                .is_ok()
+        );
+        let result = access.check_sortable(&[SortBy::Descending("due_date".into())]);
+        assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+    }
 }
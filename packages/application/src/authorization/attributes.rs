@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::{
     collections::HashMap,
     ops::{Deref, DerefMut},
@@ -39,7 +40,7 @@ impl DerefMut for AuthorizationAttributes {
 }
 
 // More value objects...
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct AttributeKey(String);
 
 impl AttributeKey {
@@ -71,7 +72,8 @@ impl std::fmt::Display for AttributeKey {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
 pub enum AttributeValue {
     String(String),
     Bool(bool),
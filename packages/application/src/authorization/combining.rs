@@ -0,0 +1,136 @@
+use serde::Serialize;
+
+use super::AccessDecision;
+
+/// How the engine turns the decisions of every applicable policy into one.
+/// Policies are always visited from the highest priority down; no applicable
+/// policy at all is a deny.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombiningAlgorithm {
+    /// A single deny wins over any number of allows.
+    #[default]
+    DenyOverrides,
+    /// A single allow wins over any number of denies.
+    PermitOverrides,
+    /// The highest priority policy that applies decides.
+    FirstApplicable,
+    /// Exactly one policy may apply; if several do, the request is denied.
+    OnlyOneApplicable,
+}
+
+impl CombiningAlgorithm {
+    /// Combines the decisions of the applicable policies, which must come in
+    /// priority order, and returns the policy that decided alongside the
+    /// result. Only `FirstApplicable` stops before the end.
+    pub fn combine<P>(
+        &self,
+        mut decisions: impl Iterator<Item = (P, AccessDecision)>,
+    ) -> (AccessDecision, Option<P>) {
+        match self {
+            CombiningAlgorithm::DenyOverrides => {
+                let mut allowed = None;
+                for (policy, decision) in decisions {
+                    match decision {
+                        AccessDecision::Deny => return (AccessDecision::Deny, Some(policy)),
+                        AccessDecision::Allow => {
+                            allowed.get_or_insert(policy);
+                        }
+                    }
+                }
+                match allowed {
+                    Some(policy) => (AccessDecision::Allow, Some(policy)),
+                    None => (AccessDecision::Deny, None),
+                }
+            }
+            CombiningAlgorithm::PermitOverrides => {
+                let mut denied = None;
+                for (policy, decision) in decisions {
+                    match decision {
+                        AccessDecision::Allow => return (AccessDecision::Allow, Some(policy)),
+                        AccessDecision::Deny => {
+                            denied.get_or_insert(policy);
+                        }
+                    }
+                }
+                (AccessDecision::Deny, denied)
+            }
+            CombiningAlgorithm::FirstApplicable => match decisions.next() {
+                Some((policy, decision)) => (decision, Some(policy)),
+                None => (AccessDecision::Deny, None),
+            },
+            CombiningAlgorithm::OnlyOneApplicable => match (decisions.next(), decisions.next()) {
+                (Some((policy, decision)), None) => (decision, Some(policy)),
+                _ => (AccessDecision::Deny, None),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use AccessDecision::{Allow, Deny};
+
+    fn combine(
+        algorithm: CombiningAlgorithm,
+        decisions: &[(&'static str, AccessDecision)],
+    ) -> (AccessDecision, Option<&'static str>) {
+        algorithm.combine(decisions.iter().copied())
+    }
+
+    #[test]
+    fn deny_overrides_lets_any_deny_win() {
+        let algorithm = CombiningAlgorithm::DenyOverrides;
+        assert_eq!(
+            combine(algorithm, &[("a", Allow), ("b", Deny), ("c", Deny)]),
+            (Deny, Some("b"))
+        );
+        assert_eq!(
+            combine(algorithm, &[("a", Allow), ("b", Allow)]),
+            (Allow, Some("a"))
+        );
+        assert_eq!(combine(algorithm, &[]), (Deny, None));
+    }
+
+    #[test]
+    fn permit_overrides_lets_any_allow_win() {
+        let algorithm = CombiningAlgorithm::PermitOverrides;
+        assert_eq!(
+            combine(algorithm, &[("a", Deny), ("b", Allow), ("c", Allow)]),
+            (Allow, Some("b"))
+        );
+        assert_eq!(
+            combine(algorithm, &[("a", Deny), ("b", Deny)]),
+            (Deny, Some("a"))
+        );
+        assert_eq!(combine(algorithm, &[]), (Deny, None));
+    }
+
+    #[test]
+    fn first_applicable_takes_the_highest_priority_decision() {
+        let algorithm = CombiningAlgorithm::FirstApplicable;
+        assert_eq!(
+            combine(algorithm, &[("a", Deny), ("b", Allow)]),
+            (Deny, Some("a"))
+        );
+        assert_eq!(
+            combine(algorithm, &[("a", Allow), ("b", Deny)]),
+            (Allow, Some("a"))
+        );
+        assert_eq!(combine(algorithm, &[]), (Deny, None));
+    }
+
+    #[test]
+    fn only_one_applicable_denies_on_conflict() {
+        let algorithm = CombiningAlgorithm::OnlyOneApplicable;
+        assert_eq!(combine(algorithm, &[("a", Allow)]), (Allow, Some("a")));
+        assert_eq!(combine(algorithm, &[("a", Deny)]), (Deny, Some("a")));
+        assert_eq!(
+            combine(algorithm, &[("a", Allow), ("b", Allow)]),
+            (Deny, None)
+        );
+        assert_eq!(combine(algorithm, &[]), (Deny, None));
+    }
+}
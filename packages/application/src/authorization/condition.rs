@@ -0,0 +1,240 @@
+use serde::Serialize;
+
+use super::attributes::{AttributeKey, AttributeValue, AuthorizationAttributes};
+
+/// A test applied to a single attribute value. Operators that expect a
+/// particular kind of value fail on any other kind.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "op", content = "value", rename_all = "snake_case")]
+pub enum AttributeOperator {
+    Equals(AttributeValue),
+    NotEquals(AttributeValue),
+    GreaterThan(i64),
+    GreaterOrEqual(i64),
+    LessThan(i64),
+    LessOrEqual(i64),
+    /// A `StringList` attribute contains the value.
+    Contains(String),
+    /// A `String` attribute is one of the values.
+    In(Vec<String>),
+    /// A `String` attribute starts with the value.
+    StartsWith(String),
+    Present,
+    Absent,
+}
+
+impl AttributeOperator {
+    pub fn matches(&self, value: Option<&AttributeValue>) -> bool {
+        match (self, value) {
+            (AttributeOperator::Present, value) => value.is_some(),
+            (AttributeOperator::Absent, value) => value.is_none(),
+            (_, None) => false,
+            (AttributeOperator::Equals(expected), Some(value)) => value == expected,
+            (AttributeOperator::NotEquals(expected), Some(value)) => value != expected,
+            (AttributeOperator::GreaterThan(bound), Some(AttributeValue::Number(n))) => n > bound,
+            (AttributeOperator::GreaterOrEqual(bound), Some(AttributeValue::Number(n))) => {
+                n >= bound
+            }
+            (AttributeOperator::LessThan(bound), Some(AttributeValue::Number(n))) => n < bound,
+            (AttributeOperator::LessOrEqual(bound), Some(AttributeValue::Number(n))) => n <= bound,
+            (AttributeOperator::Contains(item), Some(AttributeValue::StringList(items))) => {
+                items.contains(item)
+            }
+            (AttributeOperator::In(items), Some(AttributeValue::String(value))) => {
+                items.contains(value)
+            }
+            (AttributeOperator::StartsWith(prefix), Some(AttributeValue::String(value))) => {
+                value.starts_with(prefix.as_str())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Requires the attribute `key` to satisfy `operator`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AttributeCondition {
+    pub key: AttributeKey,
+    pub operator: AttributeOperator,
+}
+
+impl AttributeCondition {
+    pub fn new(key: AttributeKey, operator: AttributeOperator) -> Self {
+        Self { key, operator }
+    }
+
+    pub fn matches(&self, attributes: &AuthorizationAttributes) -> bool {
+        self.operator.matches(attributes.get(&self.key))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    Equals,
+    NotEquals,
+}
+
+/// Compares a subject attribute to a resource attribute, e.g.
+/// `subject.department == resource.department`. A missing attribute on
+/// either side never matches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AttributeComparison {
+    pub subject_key: AttributeKey,
+    pub operator: ComparisonOperator,
+    pub resource_key: AttributeKey,
+}
+
+impl AttributeComparison {
+    pub fn new(
+        subject_key: AttributeKey,
+        operator: ComparisonOperator,
+        resource_key: AttributeKey,
+    ) -> Self {
+        Self {
+            subject_key,
+            operator,
+            resource_key,
+        }
+    }
+
+    pub fn matches(
+        &self,
+        subject_attributes: &AuthorizationAttributes,
+        resource_attributes: &AuthorizationAttributes,
+    ) -> bool {
+        match (
+            subject_attributes.get(&self.subject_key),
+            resource_attributes.get(&self.resource_key),
+        ) {
+            (Some(subject), Some(resource)) => match self.operator {
+                ComparisonOperator::Equals => subject == resource,
+                ComparisonOperator::NotEquals => subject != resource,
+            },
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes(entries: &[(&str, AttributeValue)]) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        for (key, value) in entries {
+            attributes.add_attribute((AttributeKey::new(key), value.clone()));
+        }
+        attributes
+    }
+
+    fn text(value: &str) -> AttributeValue {
+        AttributeValue::String(value.to_string())
+    }
+
+    #[test]
+    fn equality_operators() {
+        let value = Some(&AttributeValue::Number(3));
+        assert!(AttributeOperator::Equals(AttributeValue::Number(3)).matches(value));
+        assert!(!AttributeOperator::Equals(AttributeValue::Number(4)).matches(value));
+        assert!(!AttributeOperator::Equals(text("3")).matches(value));
+        assert!(AttributeOperator::NotEquals(AttributeValue::Number(4)).matches(value));
+        assert!(!AttributeOperator::NotEquals(AttributeValue::Number(3)).matches(value));
+    }
+
+    #[test]
+    fn numeric_operators_compare_numbers_only() {
+        let three = Some(&AttributeValue::Number(3));
+        assert!(AttributeOperator::GreaterThan(2).matches(three));
+        assert!(!AttributeOperator::GreaterThan(3).matches(three));
+        assert!(AttributeOperator::GreaterOrEqual(3).matches(three));
+        assert!(!AttributeOperator::GreaterOrEqual(4).matches(three));
+        assert!(AttributeOperator::LessThan(4).matches(three));
+        assert!(!AttributeOperator::LessThan(3).matches(three));
+        assert!(AttributeOperator::LessOrEqual(3).matches(three));
+        assert!(!AttributeOperator::LessOrEqual(2).matches(three));
+        assert!(!AttributeOperator::GreaterThan(0).matches(Some(&text("3"))));
+    }
+
+    #[test]
+    fn string_operators() {
+        let list = AttributeValue::StringList(vec!["eu".to_string(), "us".to_string()]);
+        assert!(AttributeOperator::Contains("eu".to_string()).matches(Some(&list)));
+        assert!(!AttributeOperator::Contains("asia".to_string()).matches(Some(&list)));
+        assert!(!AttributeOperator::Contains("eu".to_string()).matches(Some(&text("eu"))));
+
+        let allowed = vec!["draft".to_string(), "in_review".to_string()];
+        assert!(AttributeOperator::In(allowed.clone()).matches(Some(&text("draft"))));
+        assert!(!AttributeOperator::In(allowed.clone()).matches(Some(&text("approved"))));
+        assert!(!AttributeOperator::In(allowed).matches(Some(&list)));
+
+        let prefix = AttributeOperator::StartsWith("finance/".to_string());
+        assert!(prefix.matches(Some(&text("finance/q3"))));
+        assert!(!prefix.matches(Some(&text("sales/q3"))));
+        assert!(!prefix.matches(Some(&AttributeValue::Bool(true))));
+    }
+
+    #[test]
+    fn presence_operators() {
+        let value = Some(&AttributeValue::Bool(false));
+        assert!(AttributeOperator::Present.matches(value));
+        assert!(!AttributeOperator::Present.matches(None));
+        assert!(AttributeOperator::Absent.matches(None));
+        assert!(!AttributeOperator::Absent.matches(value));
+    }
+
+    #[test]
+    fn missing_attributes_fail_every_value_operator() {
+        let operators = [
+            AttributeOperator::Equals(text("a")),
+            AttributeOperator::NotEquals(text("a")),
+            AttributeOperator::GreaterThan(0),
+            AttributeOperator::LessOrEqual(0),
+            AttributeOperator::Contains("a".to_string()),
+            AttributeOperator::In(vec!["a".to_string()]),
+            AttributeOperator::StartsWith("a".to_string()),
+        ];
+        for operator in operators {
+            assert!(!operator.matches(None), "{operator:?}");
+        }
+    }
+
+    #[test]
+    fn conditions_read_their_key() {
+        let attributes = attributes(&[("level", AttributeValue::Number(5))]);
+        assert!(AttributeCondition::new(
+            AttributeKey::new("level"),
+            AttributeOperator::GreaterThan(4)
+        )
+        .matches(&attributes));
+        assert!(!AttributeCondition::new(
+            AttributeKey::new("clearance"),
+            AttributeOperator::GreaterThan(4)
+        )
+        .matches(&attributes));
+    }
+
+    #[test]
+    fn comparisons_need_both_sides() {
+        let subject = attributes(&[("department", text("finance"))]);
+        let same = attributes(&[("department", text("finance"))]);
+        let other = attributes(&[("department", text("sales"))]);
+        let equals = AttributeComparison::new(
+            AttributeKey::new("department"),
+            ComparisonOperator::Equals,
+            AttributeKey::new("department"),
+        );
+        let differs = AttributeComparison::new(
+            AttributeKey::new("department"),
+            ComparisonOperator::NotEquals,
+            AttributeKey::new("department"),
+        );
+
+        assert!(equals.matches(&subject, &same));
+        assert!(!equals.matches(&subject, &other));
+        assert!(differs.matches(&subject, &other));
+        assert!(!differs.matches(&subject, &same));
+        assert!(!equals.matches(&subject, &AuthorizationAttributes::new()));
+        assert!(!differs.matches(&AuthorizationAttributes::new(), &other));
+    }
+}
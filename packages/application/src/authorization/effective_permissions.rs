@@ -0,0 +1,50 @@
+use std::{collections::HashSet, sync::Arc};
+
+use domain::{role::RoleHierarchy, Permission, Role};
+
+use crate::{error::AppResult, ports::RoleRepository, SubjectContex};
+
+/// Resolves what a set of roles grants once their `parent_roles` are
+/// followed, loading the ancestors through the [`RoleRepository`].
+pub struct EffectivePermissionResolver {
+    roles: Arc<dyn RoleRepository>,
+}
+
+impl EffectivePermissionResolver {
+    pub fn new(roles: Arc<dyn RoleRepository>) -> Self {
+        Self { roles }
+    }
+
+    /// `roles` and every role they inherit from. A parent that cannot be
+    /// loaded fails the whole resolution rather than silently granting less.
+    pub async fn hierarchy(&self, ctx: &SubjectContex, roles: &[Role]) -> AppResult<RoleHierarchy> {
+        let mut hierarchy = RoleHierarchy::new(roles.iter().cloned());
+        let mut pending: Vec<_> = roles
+            .iter()
+            .flat_map(|role| role.parent_roles().iter().cloned())
+            .collect();
+
+        while let Some(id) = pending.pop() {
+            if hierarchy.contains(&id) {
+                continue;
+            }
+            let parent = self.roles.load(ctx.clone(), id).await?;
+            pending.extend(parent.parent_roles().iter().cloned());
+            hierarchy.insert(parent);
+        }
+        Ok(hierarchy)
+    }
+
+    /// Every permission the subject holds through its roles.
+    pub async fn effective_permissions(
+        &self,
+        ctx: &SubjectContex,
+    ) -> AppResult<HashSet<Permission>> {
+        let hierarchy = self.hierarchy(ctx, &ctx.roles).await?;
+        Ok(ctx
+            .roles
+            .iter()
+            .flat_map(|role| hierarchy.effective_permissions(role))
+            .collect())
+    }
+}
@@ -1,13 +1,24 @@
-use domain::tenant::environment::Environment;
+use std::cmp::Reverse;
 
-use crate::{usecases::usecase_discriptor::UseCaseDescriptor, SubjectContex};
+use domain::{tenant::environment::Environment, Tenant};
+
+use crate::{
+    ports::policy::PolicyRepository, usecases::usecase_discriptor::UseCaseDescriptor, SubjectContex,
+};
 
 use super::{
     action::AuthorizationAction,
     attributes::AuthorizationAttributes,
-    policys::{ApplicationAuthorizationPolicy, ApplicationStoredPolicy},
+    combining::CombiningAlgorithm,
+    field_policy::{FieldGrant, FieldPolicy},
+    guard::{
+        environment::{ManagedDevicePolicy, NetworkZonePolicy, RiskScorePolicy, TimeWindowPolicy},
+        tenant::{TenantIsolationPolicy, TenantStatusPolicy},
+    },
+    policys::{ApplicationAuthorizationPolicy, ApplicationPolicyId, ApplicationStoredPolicy},
     relation::AuthorizationRelations,
     resource_type::AuthorizationResourceType,
+    trace::{DecisionTrace, PolicyEvaluation, PolicyStage},
     AccessDecision,
 };
 
@@ -22,6 +33,9 @@ pub struct AuthorizationContext<'a> {
 
     pub relations: AuthorizationRelations,
     pub environment: Environment,
+    /// The tenant owning the resource, when the use case knows it. Guard
+    /// policies read its environment and collaboration rules.
+    pub owner_tenant: Option<Tenant>,
 }
 
 impl<'a> AuthorizationContext<'a> {
@@ -40,23 +54,74 @@ impl<'a> AuthorizationContext<'a> {
             resource_attributes,
             relations,
             environment,
+            owner_tenant: None,
         }
     }
+
+    pub fn set_owner_tenant(&mut self, tenant: Tenant) -> &mut Self {
+        self.owner_tenant = Some(tenant);
+        self
+    }
 }
 
+/// Evaluates guard policies first: they are hard security rules, so any
+/// guard that denies ends the evaluation and a guard that allows grants
+/// nothing on its own. The remaining policies, registered or loaded from a
+/// [`PolicyRepository`], are combined with the configured
+/// [`CombiningAlgorithm`]. Field policies then decide which fields of an
+/// allowed resource the subject sees and writes.
+#[derive(Default)]
 pub struct AuthorizationEngine {
-    guard_policies: Vec<ApplicationStoredPolicy>,
+    combining_algorithm: CombiningAlgorithm,
+    guard_policies: Vec<Box<dyn ApplicationAuthorizationPolicy>>,
     dynamic_policies: Vec<Box<dyn ApplicationAuthorizationPolicy>>,
+    stored_policies: Vec<ApplicationStoredPolicy>,
+    field_policies: Vec<FieldPolicy>,
 }
 
 impl AuthorizationEngine {
     pub fn new() -> Self {
         Self {
+            combining_algorithm: CombiningAlgorithm::default(),
             dynamic_policies: Vec::new(),
             guard_policies: Vec::new(),
+            stored_policies: Vec::new(),
+            field_policies: Vec::new(),
         }
     }
 
+    /// An engine guarded by the tenant status, tenant isolation and
+    /// environment guards, ready for the policies granting access.
+    pub fn with_default_guards() -> Self {
+        let mut engine = Self::new();
+        engine.register_guard_policy(TenantStatusPolicy);
+        engine.register_guard_policy(TenantIsolationPolicy);
+        engine.register_guard_policy(TimeWindowPolicy);
+        engine.register_guard_policy(NetworkZonePolicy);
+        engine.register_guard_policy(ManagedDevicePolicy);
+        engine.register_guard_policy(RiskScorePolicy);
+        engine
+    }
+
+    pub fn set_combining_algorithm(
+        &mut self,
+        combining_algorithm: CombiningAlgorithm,
+    ) -> &mut Self {
+        self.combining_algorithm = combining_algorithm;
+        self
+    }
+
+    pub fn combining_algorithm(&self) -> CombiningAlgorithm {
+        self.combining_algorithm
+    }
+
+    pub fn register_guard_policy<P>(&mut self, policy: P)
+    where
+        P: ApplicationAuthorizationPolicy + 'static,
+    {
+        self.guard_policies.push(Box::new(policy));
+    }
+
     pub fn register_policy<P>(&mut self, policy: P)
     where
         P: ApplicationAuthorizationPolicy + 'static,
@@ -64,23 +129,106 @@ impl AuthorizationEngine {
         self.dynamic_policies.push(Box::new(policy));
     }
 
+    pub fn register_field_policy(&mut self, policy: FieldPolicy) {
+        self.field_policies.push(policy);
+    }
+
+    /// The fields open to `ctx`. Resource types and actions without any
+    /// field policy keep every field open, so field policies only need
+    /// registering for the resources whose fields are restricted.
+    pub fn field_grant(&self, ctx: &AuthorizationContext) -> FieldGrant {
+        let mut targeted = self
+            .field_policies
+            .iter()
+            .filter(|policy| policy.targets(ctx))
+            .peekable();
+        if targeted.peek().is_none() {
+            return FieldGrant::all();
+        }
+
+        let mut grant = FieldGrant::default();
+        for policy in targeted {
+            let fields = policy.fields.iter().cloned();
+            match policy.policy.evaluate(ctx) {
+                Some(AccessDecision::Allow) => grant.allow(fields),
+                Some(AccessDecision::Deny) => grant.deny(fields),
+                None => continue,
+            };
+        }
+        grant
+    }
+
+    /// Replaces the policies loaded by a previous call with the active
+    /// policies of `repository`.
+    pub fn load_policies(&mut self, repository: &dyn PolicyRepository) {
+        self.stored_policies = repository.load_active_policies();
+    }
+
     pub(crate) fn evaluate(&self, ctx: &AuthorizationContext) -> AccessDecision {
+        self.decide(ctx, None).0
+    }
+
+    /// Evaluates `ctx` like `authorize` does, recording every policy
+    /// evaluation on the way.
+    pub fn explain(&self, ctx: &AuthorizationContext) -> DecisionTrace {
+        let mut evaluations = Vec::new();
+        let (decision, decided_by) = self.decide(ctx, Some(&mut evaluations));
+        DecisionTrace {
+            resource_type: ctx.resource_type.resource_type(),
+            action: ctx.action.action(),
+            combining_algorithm: self.combining_algorithm,
+            evaluations,
+            decided_by: decided_by.map(|id| id.id()),
+            decision,
+        }
+    }
+
+    fn decide(
+        &self,
+        ctx: &AuthorizationContext,
+        mut evaluations: Option<&mut Vec<PolicyEvaluation>>,
+    ) -> (AccessDecision, Option<ApplicationPolicyId>) {
+        let mut evaluate = |stage: PolicyStage, policy: &dyn ApplicationAuthorizationPolicy| {
+            let decision = policy.evaluate(ctx);
+            if let Some(evaluations) = evaluations.as_deref_mut() {
+                evaluations.push(PolicyEvaluation {
+                    policy_id: policy.id().id(),
+                    stage,
+                    matched: decision.is_some(),
+                    decision,
+                    mismatch: decision.is_none().then(|| policy.mismatch(ctx)).flatten(),
+                });
+            }
+            decision
+        };
+
         // 1. Guard policies (hard security rules)
         for policy in &self.guard_policies {
-            if let Some(decision) = policy.evaluate(ctx) {
-                return decision;
+            if let Some(AccessDecision::Deny) = evaluate(PolicyStage::Guard, policy.as_ref()) {
+                return (AccessDecision::Deny, Some(policy.id()));
             }
         }
 
-        // 2. Dynamic policies (editable)
-        for policy in &self.dynamic_policies {
-            if let Some(decision) = policy.evaluate(ctx) {
-                return decision;
-            }
-        }
+        // 2. Dynamic and stored policies (editable), highest priority first
+        let mut policies: Vec<&dyn ApplicationAuthorizationPolicy> = self
+            .dynamic_policies
+            .iter()
+            .map(|policy| policy.as_ref())
+            .chain(
+                self.stored_policies
+                    .iter()
+                    .map(|policy| policy as &dyn ApplicationAuthorizationPolicy),
+            )
+            .collect();
+        policies.sort_by_key(|policy| Reverse(policy.priority()));
 
-        // 3. Secure default
-        AccessDecision::Deny
+        // 3. Secure default when nothing applies
+        let (decision, decided_by) = self.combining_algorithm.combine(
+            policies
+                .into_iter()
+                .filter_map(|policy| Some((policy, evaluate(PolicyStage::Policy, policy)?))),
+        );
+        (decision, decided_by.map(|policy| policy.id()))
     }
 
     pub fn authorize<U: UseCaseDescriptor>(
@@ -102,3 +250,184 @@ impl AuthorizationEngine {
         self.evaluate(&ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        authorization::policys::{ApplicationPolicyEffect, ApplicationPolicyPriority},
+        fixtures::{context, subject},
+    };
+
+    /// Always answers `decision`.
+    struct Fixed {
+        id: &'static str,
+        decision: Option<AccessDecision>,
+        priority: i32,
+    }
+
+    impl Fixed {
+        fn new(id: &'static str, decision: Option<AccessDecision>, priority: i32) -> Self {
+            Self {
+                id,
+                decision,
+                priority,
+            }
+        }
+    }
+
+    impl ApplicationAuthorizationPolicy for Fixed {
+        fn evaluate(&self, _ctx: &AuthorizationContext) -> Option<AccessDecision> {
+            self.decision
+        }
+
+        fn id(&self) -> ApplicationPolicyId {
+            ApplicationPolicyId::new(self.id)
+        }
+
+        fn priority(&self) -> ApplicationPolicyPriority {
+            ApplicationPolicyPriority::new(self.priority)
+        }
+    }
+
+    struct Stored(Vec<ApplicationStoredPolicy>);
+
+    impl PolicyRepository for Stored {
+        fn load_active_policies(&self) -> Vec<ApplicationStoredPolicy> {
+            self.0.clone()
+        }
+    }
+
+    fn stored(id: &str, effect: ApplicationPolicyEffect, priority: i32) -> ApplicationStoredPolicy {
+        let mut builder = ApplicationStoredPolicy::new(
+            ApplicationPolicyId::new(id),
+            ApplicationPolicyPriority::new(priority),
+        );
+        builder
+            .set_resource_type(AuthorizationResourceType::new("report"))
+            .set_action(AuthorizationAction::new("read"))
+            .set_effect(effect);
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn denies_when_no_policy_applies() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::new();
+        engine.register_policy(Fixed::new("silent", None, 0));
+
+        let trace = engine.explain(&context(&subject, "report", "read"));
+        assert_eq!(trace.decision, AccessDecision::Deny);
+        assert_eq!(trace.decided_by, None);
+    }
+
+    #[test]
+    fn a_denying_guard_ends_the_evaluation() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::new();
+        engine.register_guard_policy(Fixed::new("guard", Some(AccessDecision::Deny), 0));
+        engine.register_policy(Fixed::new("allow", Some(AccessDecision::Allow), 0));
+
+        let trace = engine.explain(&context(&subject, "report", "read"));
+        assert_eq!(trace.decision, AccessDecision::Deny);
+        assert_eq!(trace.decided_by.as_deref(), Some("guard"));
+        assert_eq!(trace.evaluations.len(), 1);
+    }
+
+    #[test]
+    fn an_allowing_guard_grants_nothing_on_its_own() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::new();
+        engine.register_guard_policy(Fixed::new("guard", Some(AccessDecision::Allow), 0));
+        let ctx = context(&subject, "report", "read");
+        assert_eq!(engine.evaluate(&ctx), AccessDecision::Deny);
+
+        engine.register_policy(Fixed::new("allow", Some(AccessDecision::Allow), 0));
+        assert_eq!(engine.evaluate(&ctx), AccessDecision::Allow);
+    }
+
+    #[test]
+    fn policies_are_visited_by_priority() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::new();
+        engine.set_combining_algorithm(CombiningAlgorithm::FirstApplicable);
+        engine.register_policy(Fixed::new("low", Some(AccessDecision::Deny), 1));
+        engine.register_policy(Fixed::new("high", Some(AccessDecision::Allow), 10));
+
+        let trace = engine.explain(&context(&subject, "report", "read"));
+        assert_eq!(trace.decision, AccessDecision::Allow);
+        assert_eq!(trace.decided_by.as_deref(), Some("high"));
+        assert_eq!(trace.evaluations[0].policy_id, "high");
+    }
+
+    #[test]
+    fn stored_policies_combine_with_registered_ones() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::new();
+        engine.register_policy(Fixed::new("allow", Some(AccessDecision::Allow), 0));
+        engine.load_policies(&Stored(vec![stored(
+            "no-reads",
+            ApplicationPolicyEffect::Deny,
+            5,
+        )]));
+        assert_eq!(
+            engine.evaluate(&context(&subject, "report", "read")),
+            AccessDecision::Deny
+        );
+        assert_eq!(
+            engine.evaluate(&context(&subject, "report", "update")),
+            AccessDecision::Allow
+        );
+
+        engine.set_combining_algorithm(CombiningAlgorithm::PermitOverrides);
+        assert_eq!(
+            engine.evaluate(&context(&subject, "report", "read")),
+            AccessDecision::Allow
+        );
+
+        // Loading again replaces the stored policies.
+        engine.set_combining_algorithm(CombiningAlgorithm::DenyOverrides);
+        engine.load_policies(&Stored(Vec::new()));
+        assert_eq!(
+            engine.evaluate(&context(&subject, "report", "read")),
+            AccessDecision::Allow
+        );
+    }
+
+    #[test]
+    fn default_guards_run_before_the_policies() {
+        let subject = subject("alice", "acme");
+        let mut engine = AuthorizationEngine::with_default_guards();
+        engine.register_policy(Fixed::new("allow", Some(AccessDecision::Allow), 0));
+
+        let trace = engine.explain(&context(&subject, "report", "read"));
+        let guards: Vec<&str> = trace
+            .evaluations
+            .iter()
+            .filter(|evaluation| evaluation.stage == PolicyStage::Guard)
+            .map(|evaluation| evaluation.policy_id.as_str())
+            .collect();
+        assert_eq!(
+            guards,
+            [
+                "tenant_status",
+                "tenant_isolation",
+                "environment_time_window",
+                "environment_network_zone",
+                "environment_managed_device",
+                "environment_risk_score",
+            ]
+        );
+        assert_eq!(trace.decision, AccessDecision::Allow);
+    }
+
+    #[test]
+    fn fields_stay_open_without_field_policies() {
+        let subject = subject("alice", "acme");
+        let engine = AuthorizationEngine::new();
+        assert_eq!(
+            engine.field_grant(&context(&subject, "report", "read")),
+            FieldGrant::all()
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Per-tenant feature gating. A use case declaring
+//! [`UseCaseDescriptor::FEATURE`] only runs when the tenant owning its
+//! resource has that feature enabled and rolled out to it.
+
+use domain::{tenant::config::feature::Feature, Tenant};
+
+use crate::{
+    error::{AppError, AppResult},
+    usecases::usecase_discriptor::UseCaseDescriptor,
+};
+
+pub struct FeatureGate;
+
+impl FeatureGate {
+    /// Checks the feature `U` declares, if any, against `owner_tenant`.
+    pub fn require_for<U: UseCaseDescriptor>(owner_tenant: Option<&Tenant>) -> AppResult<()> {
+        match U::FEATURE {
+            Some(feature) => Self::require(feature, owner_tenant),
+            None => Ok(()),
+        }
+    }
+
+    /// Fails closed: without an owner tenant there is no configuration to
+    /// enable `feature`, so it is treated as disabled.
+    pub fn require(feature: Feature, owner_tenant: Option<&Tenant>) -> AppResult<()> {
+        match owner_tenant {
+            Some(tenant) if tenant.has_feature(feature) => Ok(()),
+            Some(tenant) => Err(AppError::PolicyError(format!(
+                "feature {feature} is not enabled for tenant {}",
+                tenant.id()
+            ))),
+            None => Err(AppError::PolicyError(format!(
+                "feature {feature} requires an owner tenant"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Field-level access. A [`FieldPolicy`] opens or hides fields of a resource
+//! type for the requests its stored policy matches; the engine folds the
+//! matching ones into the [`FieldGrant`] an
+//! [`AccessControl`](super::access_descriptor::AccessControl) is built from.
+//!
+//! Fields are named by their dotted path, e.g. `content.body`. A path also
+//! covers every field below it, and `*` covers every field.
+
+use super::{engine::AuthorizationContext, policys::ApplicationStoredPolicy};
+
+pub const ALL_FIELDS: &str = "*";
+
+/// Opens `fields` when `policy` matches the request with an allow effect,
+/// or hides them when it matches with a deny effect. The policy's action
+/// says what the fields are opened for: `read`, `create` or `update`.
+#[derive(Debug, Clone)]
+pub struct FieldPolicy {
+    pub policy: ApplicationStoredPolicy,
+    pub fields: Vec<String>,
+}
+
+impl FieldPolicy {
+    pub fn new(policy: ApplicationStoredPolicy, fields: &[&str]) -> Self {
+        Self {
+            policy,
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+        }
+    }
+
+    /// Whether the policy is about the resource type and action of `ctx`,
+    /// whether or not the rest of it matches.
+    pub fn targets(&self, ctx: &AuthorizationContext) -> bool {
+        self.policy.resource_type.as_str() == ctx.resource_type.as_str()
+            && self.policy.action.as_str() == ctx.action.as_str()
+    }
+}
+
+/// The field paths open to one request. A denied path wins over an allowed
+/// one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldGrant {
+    allowed: Vec<String>,
+    denied: Vec<String>,
+}
+
+impl FieldGrant {
+    /// Opens every field.
+    pub fn all() -> Self {
+        Self {
+            allowed: vec![ALL_FIELDS.to_string()],
+            denied: Vec::new(),
+        }
+    }
+
+    pub fn allow(&mut self, paths: impl IntoIterator<Item = String>) -> &mut Self {
+        self.allowed.extend(paths);
+        self
+    }
+
+    pub fn deny(&mut self, paths: impl IntoIterator<Item = String>) -> &mut Self {
+        self.denied.extend(paths);
+        self
+    }
+
+    pub fn permits(&self, path: &str) -> bool {
+        self.allowed.iter().any(|pattern| covers(pattern, path))
+            && !self.denied.iter().any(|pattern| covers(pattern, path))
+    }
+}
+
+fn covers(pattern: &str, path: &str) -> bool {
+    pattern == ALL_FIELDS
+        || pattern == path
+        || path
+            .strip_prefix(pattern)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(allowed: &[&str], denied: &[&str]) -> FieldGrant {
+        let mut grant = FieldGrant::default();
+        grant
+            .allow(allowed.iter().map(|path| path.to_string()))
+            .deny(denied.iter().map(|path| path.to_string()));
+        grant
+    }
+
+    #[test]
+    fn paths_cover_the_fields_below_them() {
+        let grant = grant(&["content", "title"], &[]);
+        assert!(grant.permits("title"));
+        assert!(grant.permits("content.body"));
+        assert!(grant.permits("content.review_comments.comment"));
+        assert!(!grant.permits("content_type"));
+        assert!(!grant.permits("status"));
+        assert!(FieldGrant::all().permits("content.body"));
+        assert!(!FieldGrant::default().permits("title"));
+    }
+
+    #[test]
+    fn denied_paths_win() {
+        let grant = grant(&["*"], &["content.review_comments"]);
+        assert!(grant.permits("content.body"));
+        assert!(!grant.permits("content.review_comments.reviewer_id"));
+    }
+}
@@ -0,0 +1,313 @@
+//! Cross-tenant access. A member of tenant B may act on a resource owned by
+//! tenant A only when A has `Feature::CrossTenantSharing` rolled out, the
+//! resource is shared with B, A trusts B under its `FederationPolicy` and
+//! allows resource sharing, and the action fits A's
+//! `CollaborationAccessLevel`. `TenantIsolationPolicy` applies these rules
+//! to every cross-tenant request.
+
+use domain::{
+    tenant::{
+        config::{
+            collaboration::{
+                CollaborationAccessLevel, CollaborationMode, FederationPolicy,
+                TenantCollaborationConfig,
+            },
+            feature::Feature,
+        },
+        environment::risk_signals::AuthenticationStrength,
+    },
+    TenantId,
+};
+
+use crate::authorization::{
+    attributes::{AttributeKey, AttributeValue},
+    engine::AuthorizationContext,
+    relation::AuthorizationRelation,
+};
+
+const READ_ACTIONS: &[&str] = &["read", "list"];
+const CONTRIBUTOR_ACTIONS: &[&str] = &["create", "update", "submit", "review", "comment"];
+
+pub fn resource_tenant(ctx: &AuthorizationContext) -> Option<TenantId> {
+    match ctx.resource_attributes.get(&AttributeKey::new("tenant_id")) {
+        Some(AttributeValue::String(tenant_id)) => Some(TenantId::new(tenant_id)),
+        _ => None,
+    }
+}
+
+pub fn is_cross_tenant(ctx: &AuthorizationContext) -> bool {
+    resource_tenant(ctx).is_some_and(|tenant| tenant != ctx.subject.tenant_id)
+}
+
+/// Whether the subject is accepting an invitation addressed to them. The
+/// invitee is not a member of the inviting tenant yet, so they act from
+/// another one.
+pub fn is_own_invitation(ctx: &AuthorizationContext) -> bool {
+    ctx.resource_type.as_str() == "membership"
+        && ctx.action.as_str() == "accept"
+        && ctx.relations.contains(&AuthorizationRelation::new("self"))
+}
+
+/// Whether the owner tenant's collaboration rules let the subject's tenant
+/// run the requested action on the resource.
+pub fn collaboration_permits(ctx: &AuthorizationContext) -> bool {
+    let Some(owner) = ctx.owner_tenant.as_ref() else {
+        return false;
+    };
+    if resource_tenant(ctx).as_ref() != Some(owner.id()) {
+        return false;
+    }
+    if !owner.has_feature(Feature::CrossTenantSharing) {
+        return false;
+    }
+    if !ctx
+        .relations
+        .contains(&AuthorizationRelation::new("shared_tenant"))
+    {
+        return false;
+    }
+
+    let collaboration = owner.config().collaboration();
+    allows_sharing(collaboration)
+        && trusts(collaboration, &ctx.subject.tenant_id, ctx)
+        && fits_access_level(collaboration.access_level(), ctx.action.as_str())
+}
+
+fn allows_sharing(collaboration: &TenantCollaborationConfig) -> bool {
+    collaboration.allowed_modes().iter().any(|mode| {
+        matches!(
+            mode,
+            CollaborationMode::ResourceSharing | CollaborationMode::FullFederation
+        )
+    })
+}
+
+/// `VerifiedOnly` additionally requires the request to be authenticated
+/// with more than a password.
+fn trusts(
+    collaboration: &TenantCollaborationConfig,
+    tenant: &TenantId,
+    ctx: &AuthorizationContext,
+) -> bool {
+    let trusted = collaboration.trusted_tenants().contains(tenant);
+    match collaboration.federation() {
+        FederationPolicy::Disabled => false,
+        FederationPolicy::TrustedTenantsOnly => trusted,
+        FederationPolicy::VerifiedOnly => {
+            trusted
+                && !matches!(
+                    ctx.environment.risk().authentication_strength(),
+                    AuthenticationStrength::PasswordOnly
+                )
+        }
+    }
+}
+
+/// `Admin` opens every action to the trusted tenant; its members still need
+/// a grant for the action itself.
+fn fits_access_level(access_level: &CollaborationAccessLevel, action: &str) -> bool {
+    match access_level {
+        CollaborationAccessLevel::ReadOnly => READ_ACTIONS.contains(&action),
+        CollaborationAccessLevel::Contributor => {
+            READ_ACTIONS.contains(&action) || CONTRIBUTOR_ACTIONS.contains(&action)
+        }
+        CollaborationAccessLevel::Admin => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::tenant::{
+        config::TenantConfig,
+        environment::{
+            datetime::EnvironmentTime,
+            device_security_posture::DeviceSecurityPosture,
+            location::{EnvironmentLocation, LocationZone},
+            network_information::{ConnectionType, NetworkInformation},
+            risk_signals::RiskSignals,
+            Environment,
+        },
+    };
+    use domain::DateTime;
+
+    use super::*;
+    use crate::{
+        authorization::{
+            guard::tenant::TenantIsolationPolicy, policys::ApplicationAuthorizationPolicy,
+            AccessDecision,
+        },
+        fixtures::{
+            authorization_config, context, environment_config, feature_config, subject, tenant,
+        },
+        SubjectContex,
+    };
+
+    /// `acme` shares read access with its trusted `partner`.
+    struct Sharing {
+        features: Vec<Feature>,
+        trusted: Vec<TenantId>,
+        modes: Vec<CollaborationMode>,
+        federation: FederationPolicy,
+        access_level: CollaborationAccessLevel,
+        shared: bool,
+        strength: AuthenticationStrength,
+    }
+
+    impl Default for Sharing {
+        fn default() -> Self {
+            Self {
+                features: vec![Feature::CrossTenantSharing],
+                trusted: vec![TenantId::new("partner")],
+                modes: vec![CollaborationMode::ResourceSharing],
+                federation: FederationPolicy::TrustedTenantsOnly,
+                access_level: CollaborationAccessLevel::ReadOnly,
+                shared: true,
+                strength: AuthenticationStrength::MultiFactor,
+            }
+        }
+    }
+
+    impl Sharing {
+        /// Whether a member of `tenant` may `action` a report owned by `acme`.
+        fn permits(self, tenant: &str, action: &str) -> bool {
+            let subject = subject("bob", tenant);
+            collaboration_permits(&self.context(&subject, action))
+        }
+
+        fn context<'a>(self, subject: &'a SubjectContex, action: &str) -> AuthorizationContext<'a> {
+            let mut ctx = context(subject, "report", action);
+            ctx.resource_attributes.add_attribute((
+                AttributeKey::new("tenant_id"),
+                AttributeValue::String("acme".to_string()),
+            ));
+            if self.shared {
+                ctx.relations
+                    .add_relation(AuthorizationRelation::new("shared_tenant"));
+            }
+            ctx.environment = Environment::new(
+                EnvironmentTime::new(DateTime::new(0), true),
+                EnvironmentLocation::new(LocationZone::Unknown),
+                DeviceSecurityPosture::new(true, true, true),
+                NetworkInformation::new(false, true, ConnectionType::Unknown),
+                RiskSignals::new(0, self.strength, 0),
+            );
+            ctx.owner_tenant = Some(tenant(
+                "acme",
+                TenantConfig::new(
+                    authorization_config(),
+                    environment_config(),
+                    TenantCollaborationConfig::new(
+                        self.trusted,
+                        self.modes,
+                        self.federation,
+                        self.access_level,
+                    ),
+                    feature_config(self.features),
+                ),
+            ));
+            ctx
+        }
+    }
+
+    #[test]
+    fn trusted_tenants_read_shared_resources() {
+        assert!(Sharing::default().permits("partner", "read"));
+        assert!(Sharing::default().permits("partner", "list"));
+    }
+
+    #[test]
+    fn every_rule_of_the_owner_tenant_must_hold() {
+        let without_feature = Sharing {
+            features: Vec::new(),
+            ..Default::default()
+        };
+        assert!(!without_feature.permits("partner", "read"));
+        let not_shared = Sharing {
+            shared: false,
+            ..Default::default()
+        };
+        assert!(!not_shared.permits("partner", "read"));
+        let guests_only = Sharing {
+            modes: vec![CollaborationMode::GuestAccess],
+            ..Default::default()
+        };
+        assert!(!guests_only.permits("partner", "read"));
+        let federation_disabled = Sharing {
+            federation: FederationPolicy::Disabled,
+            ..Default::default()
+        };
+        assert!(!federation_disabled.permits("partner", "read"));
+        assert!(!Sharing::default().permits("stranger", "read"));
+    }
+
+    #[test]
+    fn verified_federation_needs_more_than_a_password() {
+        let verified = || Sharing {
+            federation: FederationPolicy::VerifiedOnly,
+            ..Default::default()
+        };
+        assert!(verified().permits("partner", "read"));
+        let password_only = Sharing {
+            strength: AuthenticationStrength::PasswordOnly,
+            ..verified()
+        };
+        assert!(!password_only.permits("partner", "read"));
+    }
+
+    #[test]
+    fn the_access_level_bounds_the_action() {
+        let level = |access_level| Sharing {
+            access_level,
+            ..Default::default()
+        };
+        assert!(!level(CollaborationAccessLevel::ReadOnly).permits("partner", "update"));
+        assert!(level(CollaborationAccessLevel::Contributor).permits("partner", "update"));
+        assert!(!level(CollaborationAccessLevel::Contributor).permits("partner", "delete"));
+        assert!(level(CollaborationAccessLevel::Admin).permits("partner", "delete"));
+    }
+
+    #[test]
+    fn requires_the_owner_tenant_of_the_resource() {
+        let subject = subject("bob", "partner");
+        let mut ctx = Sharing::default().context(&subject, "read");
+        ctx.owner_tenant = None;
+        assert!(!collaboration_permits(&ctx));
+
+        let mut ctx = Sharing::default().context(&subject, "read");
+        ctx.resource_attributes.add_attribute((
+            AttributeKey::new("tenant_id"),
+            AttributeValue::String("other".to_string()),
+        ));
+        assert!(!collaboration_permits(&ctx));
+    }
+
+    #[test]
+    fn isolation_denies_only_unpermitted_cross_tenant_requests() {
+        let isolation = TenantIsolationPolicy;
+        let own = subject("alice", "acme");
+        assert_eq!(
+            isolation.evaluate(&Sharing::default().context(&own, "delete")),
+            None
+        );
+        let partner = subject("bob", "partner");
+        assert_eq!(
+            isolation.evaluate(&Sharing::default().context(&partner, "read")),
+            None
+        );
+        assert_eq!(
+            isolation.evaluate(&Sharing::default().context(&partner, "update")),
+            Some(AccessDecision::Deny)
+        );
+
+        let mut invitation = context(&partner, "membership", "accept");
+        invitation.resource_attributes.add_attribute((
+            AttributeKey::new("tenant_id"),
+            AttributeValue::String("acme".to_string()),
+        ));
+        assert_eq!(isolation.evaluate(&invitation), Some(AccessDecision::Deny));
+        invitation
+            .relations
+            .add_relation(AuthorizationRelation::new("self"));
+        assert_eq!(isolation.evaluate(&invitation), None);
+    }
+}
@@ -0,0 +1,330 @@
+//! Guards enforcing the `TenantEnvironmentConfig` of the tenant owning the
+//! resource. A request without tenant environment rules passes them all.
+
+use domain::tenant::config::environment::TenantEnvironmentConfig;
+
+use crate::authorization::{
+    classifiers::NetworkZoneClassifier,
+    engine::AuthorizationContext,
+    policys::{ApplicationAuthorizationPolicy, ApplicationPolicyId},
+    AccessDecision,
+};
+
+/// Denies requests made outside the tenant's allowed time window.
+pub struct TimeWindowPolicy;
+
+impl ApplicationAuthorizationPolicy for TimeWindowPolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let window = environment_config(ctx)?.allowed_time_window().as_ref()?;
+        let now = *ctx.environment.time().timestamp();
+
+        if !window.allows(now, now.weekday(), now.seconds_since_midnight()) {
+            return Some(AccessDecision::Deny);
+        }
+
+        None
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("environment_time_window")
+    }
+}
+
+/// Denies requests from a network zone the tenant does not allow. An empty
+/// list allows every zone.
+pub struct NetworkZonePolicy;
+
+impl ApplicationAuthorizationPolicy for NetworkZonePolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let allowed_networks = environment_config(ctx)?.allowed_networks();
+        let zone = NetworkZoneClassifier::classify(ctx.environment.network());
+
+        if !allowed_networks.is_empty() && !allowed_networks.contains(&zone) {
+            return Some(AccessDecision::Deny);
+        }
+
+        None
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("environment_network_zone")
+    }
+}
+
+/// Denies requests from unmanaged devices when the tenant requires managed
+/// ones.
+pub struct ManagedDevicePolicy;
+
+impl ApplicationAuthorizationPolicy for ManagedDevicePolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let config = environment_config(ctx)?;
+
+        if config.require_managed_device() && !ctx.environment.device().is_managed() {
+            return Some(AccessDecision::Deny);
+        }
+
+        None
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("environment_managed_device")
+    }
+}
+
+/// Denies requests whose risk score exceeds the tenant's maximum.
+pub struct RiskScorePolicy;
+
+impl ApplicationAuthorizationPolicy for RiskScorePolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let max_risk_score = environment_config(ctx)?.max_risk_score().as_ref()?;
+
+        if ctx.environment.risk().score() > max_risk_score {
+            return Some(AccessDecision::Deny);
+        }
+
+        None
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("environment_risk_score")
+    }
+}
+
+fn environment_config<'a>(ctx: &'a AuthorizationContext) -> Option<&'a TenantEnvironmentConfig> {
+    Some(ctx.owner_tenant.as_ref()?.config().environment())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        tenant::{
+            config::TenantConfig,
+            environment::{
+                datetime::EnvironmentTime,
+                device_security_posture::DeviceSecurityPosture,
+                location::{EnvironmentLocation, LocationZone},
+                network_information::{ConnectionType, NetworkInformation},
+                risk_signals::{AuthenticationStrength, RiskSignals},
+                Environment,
+            },
+        },
+        value_objects::{date_time::Weekday, NetworkZone, TimeWindow},
+        DateTime,
+    };
+
+    use super::*;
+    use crate::fixtures::{
+        authorization_config, collaboration_config, context, feature_config, subject, tenant,
+    };
+
+    const HOUR: i64 = 3600;
+    /// The unix epoch fell on a Thursday.
+    const THURSDAY: i64 = 0;
+
+    fn with_environment(
+        policy: &dyn ApplicationAuthorizationPolicy,
+        config: Option<TenantEnvironmentConfig>,
+        environment: Environment,
+    ) -> Option<AccessDecision> {
+        let subject = subject("alice", "acme");
+        let mut ctx = context(&subject, "report", "read");
+        ctx.environment = environment;
+        ctx.owner_tenant = config.map(|config| {
+            tenant(
+                "acme",
+                TenantConfig::new(
+                    authorization_config(),
+                    config,
+                    collaboration_config(),
+                    feature_config(Vec::new()),
+                ),
+            )
+        });
+        policy.evaluate(&ctx)
+    }
+
+    fn session(now: i64, managed: bool, network: NetworkInformation, risk: u8) -> Environment {
+        Environment::new(
+            EnvironmentTime::new(DateTime::new(now), true),
+            EnvironmentLocation::new(LocationZone::Unknown),
+            DeviceSecurityPosture::new(managed, true, true),
+            network,
+            RiskSignals::new(risk, AuthenticationStrength::MultiFactor, 0),
+        )
+    }
+
+    fn office(now: i64) -> Environment {
+        session(
+            now,
+            true,
+            NetworkInformation::new(false, true, ConnectionType::CorporateNetwork),
+            0,
+        )
+    }
+
+    fn config(
+        window: Option<TimeWindow>,
+        networks: &[NetworkZone],
+        require_managed_device: bool,
+        max_risk_score: Option<u8>,
+    ) -> Option<TenantEnvironmentConfig> {
+        Some(TenantEnvironmentConfig::new(
+            window,
+            networks,
+            require_managed_device,
+            max_risk_score,
+        ))
+    }
+
+    #[test]
+    fn requests_without_an_owner_tenant_pass_every_guard() {
+        let policies: [&dyn ApplicationAuthorizationPolicy; 4] = [
+            &TimeWindowPolicy,
+            &NetworkZonePolicy,
+            &ManagedDevicePolicy,
+            &RiskScorePolicy,
+        ];
+        let risky = session(
+            0,
+            false,
+            NetworkInformation::new(true, false, ConnectionType::PublicWifi),
+            100,
+        );
+        for policy in policies {
+            assert_eq!(with_environment(policy, None, risky.clone()), None);
+        }
+    }
+
+    #[test]
+    fn recurring_time_windows_cover_their_days_and_hours() {
+        let business_hours = config(
+            Some(TimeWindow::Recurring {
+                days: vec![Weekday::Thursday],
+                start_seconds: (9 * HOUR) as u32,
+                end_seconds: (17 * HOUR) as u32,
+            }),
+            &[],
+            false,
+            None,
+        );
+        let evaluate =
+            |now: i64| with_environment(&TimeWindowPolicy, business_hours.clone(), office(now));
+
+        assert_eq!(evaluate(THURSDAY + 9 * HOUR), None);
+        assert_eq!(evaluate(THURSDAY + 17 * HOUR), None);
+        assert_eq!(evaluate(THURSDAY + 8 * HOUR), Some(AccessDecision::Deny));
+        assert_eq!(evaluate(THURSDAY + 18 * HOUR), Some(AccessDecision::Deny));
+        assert_eq!(
+            evaluate(THURSDAY + 24 * HOUR + 10 * HOUR),
+            Some(AccessDecision::Deny)
+        );
+    }
+
+    #[test]
+    fn absolute_time_windows_include_their_bounds() {
+        let window = config(
+            Some(TimeWindow::Absolute {
+                start: DateTime::new(100),
+                end: DateTime::new(200),
+            }),
+            &[],
+            false,
+            None,
+        );
+        let evaluate = |now: i64| with_environment(&TimeWindowPolicy, window.clone(), office(now));
+
+        assert_eq!(evaluate(100), None);
+        assert_eq!(evaluate(200), None);
+        assert_eq!(evaluate(99), Some(AccessDecision::Deny));
+        assert_eq!(evaluate(201), Some(AccessDecision::Deny));
+    }
+
+    #[test]
+    fn network_zones_outside_the_allowed_list_are_denied() {
+        let corporate_only = config(None, &[NetworkZone::TrustedCorporate], false, None);
+        let anonymous = session(
+            0,
+            true,
+            NetworkInformation::new(true, false, ConnectionType::Unknown),
+            0,
+        );
+
+        assert_eq!(
+            with_environment(&NetworkZonePolicy, corporate_only.clone(), office(0)),
+            None
+        );
+        assert_eq!(
+            with_environment(&NetworkZonePolicy, corporate_only, anonymous.clone()),
+            Some(AccessDecision::Deny)
+        );
+        assert_eq!(
+            with_environment(
+                &NetworkZonePolicy,
+                config(None, &[], false, None),
+                anonymous
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn unmanaged_devices_are_denied_when_managed_ones_are_required() {
+        let unmanaged = session(
+            0,
+            false,
+            NetworkInformation::new(false, true, ConnectionType::CorporateNetwork),
+            0,
+        );
+
+        assert_eq!(
+            with_environment(
+                &ManagedDevicePolicy,
+                config(None, &[], true, None),
+                unmanaged.clone()
+            ),
+            Some(AccessDecision::Deny)
+        );
+        assert_eq!(
+            with_environment(
+                &ManagedDevicePolicy,
+                config(None, &[], true, None),
+                office(0)
+            ),
+            None
+        );
+        assert_eq!(
+            with_environment(
+                &ManagedDevicePolicy,
+                config(None, &[], false, None),
+                unmanaged
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn risk_scores_above_the_maximum_are_denied() {
+        let capped = config(None, &[], false, Some(50));
+        let risk = |score: u8| {
+            session(
+                0,
+                true,
+                NetworkInformation::new(false, true, ConnectionType::CorporateNetwork),
+                score,
+            )
+        };
+
+        assert_eq!(
+            with_environment(&RiskScorePolicy, capped.clone(), risk(50)),
+            None
+        );
+        assert_eq!(
+            with_environment(&RiskScorePolicy, capped, risk(51)),
+            Some(AccessDecision::Deny)
+        );
+        assert_eq!(
+            with_environment(&RiskScorePolicy, config(None, &[], false, None), risk(100)),
+            None
+        );
+    }
+}
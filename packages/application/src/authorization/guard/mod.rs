@@ -1 +1,3 @@
+pub mod collaboration;
+pub mod environment;
 pub mod tenant;
@@ -1,25 +1,55 @@
-use domain::TenantId;
-
 use crate::authorization::{
-    attributes::{AttributeKey, AttributeValue},
     engine::AuthorizationContext,
-    policys::ApplicationAuthorizationPolicy,
+    policys::{ApplicationAuthorizationPolicy, ApplicationPolicyId},
     AccessDecision,
 };
 
+use super::collaboration::{collaboration_permits, is_cross_tenant, is_own_invitation};
+
+/// Denies access to resources of another tenant, unless the owner tenant's
+/// collaboration rules permit it or the subject is accepting their own
+/// invitation. It never allows on its own, so a permitted cross-tenant
+/// request still needs a role or stored policy granting the action.
 pub struct TenantIsolationPolicy;
 
 impl ApplicationAuthorizationPolicy for TenantIsolationPolicy {
     fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
-        let subject_tenant = ctx.subject.tenant_id();
-        let resource_tenant = ctx.resource_attributes.get(&AttributeKey::new("tenant_id"));
-
-        if let Some(AttributeValue::String(tenant_id)) = resource_tenant {
-            if TenantId::new(&tenant_id) != subject_tenant {
-                return Some(AccessDecision::Deny);
-            }
+        if is_cross_tenant(ctx) && !collaboration_permits(ctx) && !is_own_invitation(ctx) {
+            return Some(AccessDecision::Deny);
         }
 
         None
     }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("tenant_isolation")
+    }
+}
+
+/// Tenant actions that still run while the tenant is not active, so its
+/// administrators can bring it back or let it go.
+const LIFECYCLE_ACTIONS: &[&str] = &["reactivate", "delete"];
+
+/// Denies all access to resources of a tenant that is not active: suspended,
+/// pending deletion or deleted. Only the lifecycle actions on the tenant
+/// itself are let through.
+pub struct TenantStatusPolicy;
+
+impl ApplicationAuthorizationPolicy for TenantStatusPolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let tenant = ctx.owner_tenant.as_ref()?;
+        if tenant.is_active() || is_lifecycle_action(ctx) {
+            return None;
+        }
+
+        Some(AccessDecision::Deny)
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("tenant_status")
+    }
+}
+
+fn is_lifecycle_action(ctx: &AuthorizationContext) -> bool {
+    ctx.resource_type.as_str() == "tenant" && LIFECYCLE_ACTIONS.contains(&ctx.action.as_str())
 }
@@ -1,15 +1,26 @@
+use serde::Serialize;
+
 pub mod access_descriptor;
 pub mod action;
 pub mod attributes;
 pub mod classifiers;
+pub mod combining;
+pub mod condition;
+pub mod effective_permissions;
 pub mod engine;
+pub mod feature_gate;
+pub mod field_policy;
 pub mod guard;
 pub mod policys;
 pub mod ports;
 pub mod relation;
+pub mod resource;
 pub mod resource_type;
+pub mod subject_resolver;
+pub mod trace;
+pub mod write_guard;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum AccessDecision {
     Allow,
     Deny,
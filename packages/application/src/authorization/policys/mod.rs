@@ -1,3 +1,6 @@
+pub mod role_permission;
+pub mod temporary_grant;
+
 use std::ops::DerefMut;
 
 use crate::error::{AppError, AppResult};
@@ -5,9 +8,11 @@ use crate::error::{AppError, AppResult};
 use super::{
     action::AuthorizationAction,
     attributes::{AttributeKey, AttributeValue, AuthorizationAttributes},
+    condition::{AttributeComparison, AttributeCondition},
     engine::AuthorizationContext,
     relation::{AuthorizationRelation, AuthorizationRelations},
     resource_type::AuthorizationResourceType,
+    trace::PolicyMismatch,
     AccessDecision,
 };
 
@@ -47,7 +52,8 @@ impl std::fmt::Display for ApplicationPolicyId {
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// Higher priorities are evaluated first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ApplicationPolicyPriority(i32);
 
 impl ApplicationPolicyPriority {
@@ -55,7 +61,7 @@ impl ApplicationPolicyPriority {
         Self(id)
     }
     pub fn id(&self) -> i32 {
-        self.0.clone()
+        self.0
     }
 }
 
@@ -97,6 +103,10 @@ pub struct ApplicationStoredPolicy {
     pub required_subject_attributes: AuthorizationAttributes,
     pub required_resource_attributes: AuthorizationAttributes,
 
+    pub subject_conditions: Vec<AttributeCondition>,
+    pub resource_conditions: Vec<AttributeCondition>,
+    pub attribute_comparisons: Vec<AttributeComparison>,
+
     pub effect: ApplicationPolicyEffect,
     pub priority: ApplicationPolicyPriority,
 }
@@ -109,17 +119,24 @@ impl ApplicationStoredPolicy {
         ApplicationStoredPolicyBuilder::new(id, priority)
     }
 }
-impl ApplicationAuthorizationPolicy for ApplicationStoredPolicy {
-    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+impl ApplicationStoredPolicy {
+    /// Checks every requirement of the policy against `ctx`, reporting the
+    /// first one that is not met.
+    pub fn check(&self, ctx: &AuthorizationContext) -> Result<(), PolicyMismatch> {
         // Match resource type
-        match ctx.resource_type.as_str() != self.resource_type.resource_type() {
-            true => return None,
-            false => (),
+        if ctx.resource_type.as_str() != self.resource_type.as_str() {
+            return Err(PolicyMismatch::ResourceType {
+                expected: self.resource_type.resource_type(),
+                actual: ctx.resource_type.resource_type(),
+            });
         }
 
         // Match action
         if ctx.action.as_str() != self.action.action() {
-            return None;
+            return Err(PolicyMismatch::Action {
+                expected: self.action.action(),
+                actual: ctx.action.action(),
+            });
         }
 
         // Match relations
@@ -128,7 +145,9 @@ impl ApplicationAuthorizationPolicy for ApplicationStoredPolicy {
                 .relations
                 .contains(&AuthorizationRelation::new(relation))
             {
-                return None;
+                return Err(PolicyMismatch::MissingRelation {
+                    relation: relation.relation(),
+                });
             }
         }
 
@@ -136,7 +155,11 @@ impl ApplicationAuthorizationPolicy for ApplicationStoredPolicy {
         for (key, value) in self.required_subject_attributes.iter() {
             let attr = ctx.subject_attributes.get(&AttributeKey::new(key));
             if attr != Some(&value.clone()) {
-                return None;
+                return Err(PolicyMismatch::SubjectAttribute {
+                    key: key.attribute_key(),
+                    expected: value.clone(),
+                    actual: attr.cloned(),
+                });
             }
         }
 
@@ -144,16 +167,72 @@ impl ApplicationAuthorizationPolicy for ApplicationStoredPolicy {
         for (key, value) in self.required_resource_attributes.iter() {
             let attr = ctx.resource_attributes.get(&AttributeKey::new(key));
             if attr != Some(&value.clone()) {
-                return None;
+                return Err(PolicyMismatch::ResourceAttribute {
+                    key: key.attribute_key(),
+                    expected: value.clone(),
+                    actual: attr.cloned(),
+                });
+            }
+        }
+
+        // Match attribute conditions
+        for condition in &self.subject_conditions {
+            if !condition.matches(&ctx.subject_attributes) {
+                return Err(PolicyMismatch::SubjectCondition {
+                    condition: condition.clone(),
+                    actual: ctx.subject_attributes.get(&condition.key).cloned(),
+                });
+            }
+        }
+        for condition in &self.resource_conditions {
+            if !condition.matches(&ctx.resource_attributes) {
+                return Err(PolicyMismatch::ResourceCondition {
+                    condition: condition.clone(),
+                    actual: ctx.resource_attributes.get(&condition.key).cloned(),
+                });
             }
         }
 
+        // Match subject attributes against resource attributes
+        for comparison in &self.attribute_comparisons {
+            if !comparison.matches(&ctx.subject_attributes, &ctx.resource_attributes) {
+                return Err(PolicyMismatch::AttributeComparison {
+                    comparison: comparison.clone(),
+                    subject: ctx.subject_attributes.get(&comparison.subject_key).cloned(),
+                    resource: ctx
+                        .resource_attributes
+                        .get(&comparison.resource_key)
+                        .cloned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ApplicationAuthorizationPolicy for ApplicationStoredPolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        self.check(ctx).ok()?;
+
         // If all matched, return effect
         match self.effect {
             ApplicationPolicyEffect::Allow => Some(AccessDecision::Allow),
             ApplicationPolicyEffect::Deny => Some(AccessDecision::Deny),
         }
     }
+
+    fn id(&self) -> ApplicationPolicyId {
+        self.id.clone()
+    }
+
+    fn priority(&self) -> ApplicationPolicyPriority {
+        self.priority
+    }
+
+    fn mismatch(&self, ctx: &AuthorizationContext) -> Option<PolicyMismatch> {
+        self.check(ctx).err()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -167,6 +246,10 @@ pub struct ApplicationStoredPolicyBuilder {
     required_subject_attributes: AuthorizationAttributes,
     required_resource_attributes: AuthorizationAttributes,
 
+    subject_conditions: Vec<AttributeCondition>,
+    resource_conditions: Vec<AttributeCondition>,
+    attribute_comparisons: Vec<AttributeComparison>,
+
     effect: ApplicationPolicyEffect,
     priority: ApplicationPolicyPriority,
 }
@@ -180,6 +263,9 @@ impl ApplicationStoredPolicyBuilder {
             required_relations: AuthorizationRelations::new(),
             required_subject_attributes: AuthorizationAttributes::new(),
             required_resource_attributes: AuthorizationAttributes::new(),
+            subject_conditions: Vec::new(),
+            resource_conditions: Vec::new(),
+            attribute_comparisons: Vec::new(),
             effect: ApplicationPolicyEffect::Deny,
             priority,
         }
@@ -194,6 +280,13 @@ impl ApplicationStoredPolicyBuilder {
         self.action = Some(action);
         self
     }
+
+    /// Policies deny unless set to allow.
+    pub fn set_effect(&mut self, effect: ApplicationPolicyEffect) -> &mut Self {
+        self.effect = effect;
+        self
+    }
+
     pub fn add_required_relations(
         &mut self,
         required_relations: AuthorizationRelations,
@@ -242,6 +335,21 @@ impl ApplicationStoredPolicyBuilder {
         self
     }
 
+    pub fn add_subject_condition(&mut self, condition: AttributeCondition) -> &mut Self {
+        self.subject_conditions.push(condition);
+        self
+    }
+
+    pub fn add_resource_condition(&mut self, condition: AttributeCondition) -> &mut Self {
+        self.resource_conditions.push(condition);
+        self
+    }
+
+    pub fn add_attribute_comparison(&mut self, comparison: AttributeComparison) -> &mut Self {
+        self.attribute_comparisons.push(comparison);
+        self
+    }
+
     pub fn build(self) -> AppResult<ApplicationStoredPolicy> {
         Ok(ApplicationStoredPolicy {
             id: self.id,
@@ -254,12 +362,29 @@ impl ApplicationStoredPolicyBuilder {
             required_relations: self.required_relations,
             required_subject_attributes: self.required_subject_attributes,
             required_resource_attributes: self.required_resource_attributes,
+            subject_conditions: self.subject_conditions,
+            resource_conditions: self.resource_conditions,
+            attribute_comparisons: self.attribute_comparisons,
             effect: self.effect,
             priority: self.priority,
         })
     }
 }
 
-pub trait ApplicationAuthorizationPolicy {
+pub trait ApplicationAuthorizationPolicy: Send + Sync {
     fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision>;
+
+    /// Names the policy in decision traces.
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new(std::any::type_name::<Self>())
+    }
+
+    fn priority(&self) -> ApplicationPolicyPriority {
+        ApplicationPolicyPriority::default()
+    }
+
+    /// Explains why the policy did not apply to `ctx`, if it can.
+    fn mismatch(&self, _ctx: &AuthorizationContext) -> Option<PolicyMismatch> {
+        None
+    }
 }
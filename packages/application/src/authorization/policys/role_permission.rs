@@ -0,0 +1,125 @@
+use domain::{
+    value_objects::{Action, Resource},
+    PermissionEffect,
+};
+
+use crate::authorization::{engine::AuthorizationContext, AccessDecision};
+
+use super::{ApplicationAuthorizationPolicy, ApplicationPolicyId};
+
+/// Allows an action granted to one of the subject's roles, directly or
+/// inherited through `parent_roles`, and denies one that a role's deny
+/// permission matches, so the combining algorithm can let that deny win
+/// over other policies. The hierarchy is the subject's own,
+/// loaded per request by [`SubjectResolver`].
+///
+/// [`SubjectResolver`]: crate::authorization::subject_resolver::SubjectResolver
+pub struct RolePermissionPolicy;
+
+impl ApplicationAuthorizationPolicy for RolePermissionPolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let resource = Resource::new(ctx.resource_type.as_str()).ok()?;
+        let action = Action::new(ctx.action.as_str()).ok()?;
+
+        match ctx
+            .subject
+            .role_hierarchy
+            .effect(&ctx.subject.roles, &resource, &action)?
+        {
+            PermissionEffect::Allow => Some(AccessDecision::Allow),
+            PermissionEffect::Deny => Some(AccessDecision::Deny),
+        }
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("role_permission")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        role::RoleHierarchy, DateTime, Description, Permission, PermissionId, Role, TemporaryGrant,
+        TemporaryGrantId, TenantId, UserId,
+    };
+
+    use super::*;
+    use crate::{
+        authorization::{
+            combining::CombiningAlgorithm, engine::AuthorizationEngine,
+            policys::temporary_grant::TemporaryGrantPolicy,
+        },
+        fixtures::{context, role},
+        SubjectContex,
+    };
+
+    fn permission(action: &str, effect: PermissionEffect) -> Permission {
+        Permission::new(
+            PermissionId::new(&format!("report:{action}:{effect}")),
+            Resource::new("report").unwrap(),
+            Action::new(action).unwrap(),
+            Description::new("permission").unwrap(),
+            DateTime::new(0),
+            1,
+        )
+        .with_effect(effect)
+    }
+
+    fn reader() -> Vec<Role> {
+        vec![
+            role(
+                "reader",
+                &["base"],
+                &[permission("read", PermissionEffect::Allow)],
+            ),
+            role("base", &[], &[permission("delete", PermissionEffect::Deny)]),
+        ]
+    }
+
+    fn subject(roles: &[Role], grants: &[TemporaryGrant]) -> SubjectContex {
+        let mut subject = SubjectContex::new(
+            UserId::new("alice"),
+            TenantId::new("acme"),
+            &roles[..1],
+            grants,
+        );
+        subject.set_role_hierarchy(RoleHierarchy::new(roles.to_vec()));
+        subject
+    }
+
+    #[test]
+    fn roles_allow_deny_or_do_not_apply() {
+        let subject = subject(&reader(), &[]);
+        let evaluate = |action| RolePermissionPolicy.evaluate(&context(&subject, "report", action));
+
+        assert_eq!(evaluate("read"), Some(AccessDecision::Allow));
+        assert_eq!(evaluate("delete"), Some(AccessDecision::Deny));
+        assert_eq!(evaluate("update"), None);
+    }
+
+    #[test]
+    fn a_role_deny_overrides_a_temporary_grant() {
+        let mut builder = TemporaryGrant::new(
+            TemporaryGrantId::new("grant"),
+            UserId::new("alice"),
+            TenantId::new("acme"),
+        );
+        builder
+            .set_description(Description::new("grant").unwrap())
+            .add_permission(permission("delete", PermissionEffect::Allow))
+            .set_expires_at(DateTime::new(100))
+            .set_created_at(DateTime::new(0));
+        let subject = subject(&reader(), &[builder.build().unwrap()]);
+        let mut engine = AuthorizationEngine::new();
+        engine.set_combining_algorithm(CombiningAlgorithm::DenyOverrides);
+        engine.register_policy(TemporaryGrantPolicy);
+        engine.register_policy(RolePermissionPolicy);
+
+        let ctx = context(&subject, "report", "delete");
+        assert_eq!(
+            TemporaryGrantPolicy.evaluate(&ctx),
+            Some(AccessDecision::Allow)
+        );
+        assert_eq!(engine.evaluate(&ctx), AccessDecision::Deny);
+    }
+}
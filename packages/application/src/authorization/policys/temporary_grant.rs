@@ -0,0 +1,97 @@
+use domain::value_objects::{Action, Resource};
+
+use crate::authorization::{engine::AuthorizationContext, AccessDecision};
+
+use super::{ApplicationAuthorizationPolicy, ApplicationPolicyId};
+
+/// Allows an action covered by one of the subject's temporary grants in the
+/// subject's tenant that has not expired at the request's time. Grants
+/// never deny; without one the policy does not apply.
+pub struct TemporaryGrantPolicy;
+
+impl ApplicationAuthorizationPolicy for TemporaryGrantPolicy {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        let resource = Resource::new(ctx.resource_type.as_str()).ok()?;
+        let action = Action::new(ctx.action.as_str()).ok()?;
+        let now = *ctx.environment.time().timestamp();
+
+        ctx.subject
+            .temporary_grants
+            .iter()
+            .any(|grant| {
+                grant.user_id() == &ctx.subject.user_id
+                    && grant.belongs_to(&ctx.subject.tenant_id)
+                    && grant.allows(&resource, &action, now)
+            })
+            .then_some(AccessDecision::Allow)
+    }
+
+    fn id(&self) -> ApplicationPolicyId {
+        ApplicationPolicyId::new("temporary_grant")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        DateTime, Description, Permission, PermissionId, TemporaryGrant, TemporaryGrantId,
+        TenantId, UserId,
+    };
+
+    use super::*;
+    use crate::{
+        fixtures::{context, environment},
+        SubjectContex,
+    };
+
+    /// A grant to read reports in `tenant` that expires at 100.
+    fn grant(user: &str, tenant: &str) -> TemporaryGrant {
+        let mut builder = TemporaryGrant::new(
+            TemporaryGrantId::new("grant"),
+            UserId::new(user),
+            TenantId::new(tenant),
+        );
+        builder
+            .set_description(Description::new("grant").unwrap())
+            .add_permission(Permission::new(
+                PermissionId::new("report:read"),
+                Resource::new("report").unwrap(),
+                Action::new("read").unwrap(),
+                Description::new("permission").unwrap(),
+                DateTime::new(0),
+                1,
+            ))
+            .set_expires_at(DateTime::new(100))
+            .set_created_at(DateTime::new(0));
+        builder.build().unwrap()
+    }
+
+    fn evaluate(grant: TemporaryGrant, action: &str, now: i64) -> Option<AccessDecision> {
+        let subject =
+            SubjectContex::new(UserId::new("alice"), TenantId::new("acme"), &[], &[grant]);
+        let mut ctx = context(&subject, "report", action);
+        ctx.environment = environment(now);
+        TemporaryGrantPolicy.evaluate(&ctx)
+    }
+
+    #[test]
+    fn grants_end_at_their_expiry() {
+        assert_eq!(
+            evaluate(grant("alice", "acme"), "read", 0),
+            Some(AccessDecision::Allow)
+        );
+        assert_eq!(
+            evaluate(grant("alice", "acme"), "read", 99),
+            Some(AccessDecision::Allow)
+        );
+        assert_eq!(evaluate(grant("alice", "acme"), "read", 100), None);
+        assert_eq!(evaluate(grant("alice", "acme"), "read", 101), None);
+    }
+
+    #[test]
+    fn grants_cover_only_their_permissions_user_and_tenant() {
+        assert_eq!(evaluate(grant("alice", "acme"), "update", 50), None);
+        assert_eq!(evaluate(grant("bob", "acme"), "read", 50), None);
+        assert_eq!(evaluate(grant("alice", "evil"), "read", 50), None);
+    }
+}
@@ -1,14 +1,44 @@
 use super::{
+    combining::CombiningAlgorithm,
     engine::{AuthorizationContext, AuthorizationEngine},
+    field_policy::FieldGrant,
+    trace::DecisionTrace,
     AccessDecision,
 };
 
-pub trait AuthorizationService {
+pub trait AuthorizationService: Send + Sync {
     fn authorize(&self, ctx: &AuthorizationContext) -> AccessDecision;
+
+    /// Like `authorize`, but tells why. Services that cannot explain their
+    /// decisions report the bare outcome.
+    fn explain(&self, ctx: &AuthorizationContext) -> DecisionTrace {
+        DecisionTrace {
+            resource_type: ctx.resource_type.resource_type(),
+            action: ctx.action.action(),
+            combining_algorithm: CombiningAlgorithm::default(),
+            evaluations: Vec::new(),
+            decided_by: None,
+            decision: self.authorize(ctx),
+        }
+    }
+
+    /// The fields of the resource in `ctx` open for its action. Services
+    /// without field-level rules open every field.
+    fn field_grant(&self, _ctx: &AuthorizationContext) -> FieldGrant {
+        FieldGrant::all()
+    }
 }
 
 impl AuthorizationService for AuthorizationEngine {
     fn authorize(&self, ctx: &AuthorizationContext) -> AccessDecision {
         self.evaluate(ctx)
     }
+
+    fn explain(&self, ctx: &AuthorizationContext) -> DecisionTrace {
+        AuthorizationEngine::explain(self, ctx)
+    }
+
+    fn field_grant(&self, ctx: &AuthorizationContext) -> FieldGrant {
+        AuthorizationEngine::field_grant(self, ctx)
+    }
 }
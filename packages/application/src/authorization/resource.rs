@@ -0,0 +1,264 @@
+use domain::{
+    AccessRequest, Membership, Report, Role, TemporaryGrant, Tenant, TenantId, User, UserId,
+};
+
+use crate::{
+    dto::user::view::UserView,
+    usecases::{membership::MembershipScope, temporary_grant::TemporaryGrantScope},
+    SubjectContex,
+};
+
+use super::{
+    attributes::{AttributeKey, AttributeValue, AuthorizationAttributes},
+    relation::{AuthorizationRelation, AuthorizationRelations},
+};
+
+/// What a use case tells the authorization engine about the resource it acts
+/// on: its attributes and how the subject relates to it.
+pub trait AuthorizationResource {
+    fn attributes(&self) -> AuthorizationAttributes;
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations;
+}
+
+/// Use cases without a loaded resource (create, list) are authorized on the
+/// resource type alone.
+impl AuthorizationResource for () {
+    fn attributes(&self) -> AuthorizationAttributes {
+        AuthorizationAttributes::new()
+    }
+
+    fn relations(&self, _subject: &SubjectContex) -> AuthorizationRelations {
+        AuthorizationRelations::new()
+    }
+}
+
+impl AuthorizationResource for Report {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes.add_attribute(string("tenant_id", self.owner_tenant().as_str()));
+        attributes.add_attribute(string("author_id", self.author_id().as_str()));
+        attributes.add_attribute(string("status", &self.status().to_string()));
+        attributes.add_attribute(string_list(
+            "shared_with_tenants",
+            self.shared_with_tenants()
+                .iter()
+                .map(|tenant| tenant.as_str()),
+        ));
+        attributes.add_attribute(string_list(
+            "assigned_reviewer_id",
+            self.assigned_reviewer_id().iter().map(|user| user.as_str()),
+        ));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        let mut relations = AuthorizationRelations::new();
+        if self.is_author(&subject.user_id) {
+            relations.add_relation(AuthorizationRelation::new("author"));
+        }
+        if self.is_reviewer(&subject.user_id) {
+            relations.add_relation(AuthorizationRelation::new("reviewer"));
+        }
+        if self.belongs_to(&subject.tenant_id) {
+            relations.add_relation(AuthorizationRelation::new("owner_tenant"));
+        }
+        if self.is_shared_with(&subject.tenant_id) {
+            relations.add_relation(AuthorizationRelation::new("shared_tenant"));
+        }
+        relations
+    }
+}
+
+impl AuthorizationResource for User {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes.add_attribute(string("status", &self.status().to_string()));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        self_relation(self.id() == &subject.user_id)
+    }
+}
+
+impl AuthorizationResource for UserView {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        if let Some(id) = &self.id {
+            attributes.add_attribute(string("id", id.as_str()));
+        }
+        if let Some(status) = &self.status {
+            attributes.add_attribute(string("status", &status.to_string()));
+        }
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        self_relation(self.id.as_ref() == Some(&subject.user_id))
+    }
+}
+
+impl AuthorizationResource for Tenant {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes.add_attribute(string("tenant_id", self.id().as_str()));
+        attributes.add_attribute(string("status", &self.status().to_string()));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        let mut relations = AuthorizationRelations::new();
+        if self.id() == &subject.tenant_id {
+            relations.add_relation(AuthorizationRelation::new("owner_tenant"));
+        }
+        relations
+    }
+}
+
+/// Roles are shared by every tenant, so nobody relates to one in particular.
+impl AuthorizationResource for Role {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes.add_attribute(string_list(
+            "parent_roles",
+            self.parent_roles().iter().map(|role| role.as_str()),
+        ));
+        attributes
+    }
+
+    fn relations(&self, _subject: &SubjectContex) -> AuthorizationRelations {
+        AuthorizationRelations::new()
+    }
+}
+
+impl AuthorizationResource for AccessRequest {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = AuthorizationAttributes::new();
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes.add_attribute(string("tenant_id", self.tenant_id().as_str()));
+        attributes.add_attribute(string("requester_id", self.requester_id().as_str()));
+        attributes.add_attribute(string("status", &self.status().to_string()));
+        attributes.add_attribute(string("resource", self.resource()));
+        attributes.add_attribute(string("action", self.action()));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        let mut relations = AuthorizationRelations::new();
+        if self.is_requester(&subject.user_id) {
+            relations.add_relation(AuthorizationRelation::new("requester"));
+        }
+        if self.belongs_to(&subject.tenant_id) {
+            relations.add_relation(AuthorizationRelation::new("owner_tenant"));
+        }
+        relations
+    }
+}
+
+impl AuthorizationResource for TemporaryGrant {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = user_tenant_attributes(self.user_id(), self.tenant_id());
+        attributes.add_attribute(string("id", self.id().as_str()));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        user_tenant_relations(self.user_id(), self.tenant_id(), subject)
+    }
+}
+
+impl AuthorizationResource for TemporaryGrantScope {
+    fn attributes(&self) -> AuthorizationAttributes {
+        user_tenant_attributes(&self.user_id, &self.tenant_id)
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        user_tenant_relations(&self.user_id, &self.tenant_id, subject)
+    }
+}
+
+impl AuthorizationResource for Membership {
+    fn attributes(&self) -> AuthorizationAttributes {
+        let mut attributes = user_tenant_attributes(self.user_id(), self.tenet_id());
+        attributes.add_attribute(string("status", &self.status().to_string()));
+        attributes.add_attribute(string_list(
+            "roles",
+            self.roles().iter().map(|role| role.as_str()),
+        ));
+        attributes
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        user_tenant_relations(self.user_id(), self.tenet_id(), subject)
+    }
+}
+
+impl AuthorizationResource for MembershipScope {
+    fn attributes(&self) -> AuthorizationAttributes {
+        user_tenant_attributes(&self.user_id, &self.tenant_id)
+    }
+
+    fn relations(&self, subject: &SubjectContex) -> AuthorizationRelations {
+        user_tenant_relations(&self.user_id, &self.tenant_id, subject)
+    }
+}
+
+/// Describes the subject itself, so policies can match on its tenant and
+/// role names, along with whatever was derived when it was resolved.
+pub fn subject_attributes(subject: &SubjectContex) -> AuthorizationAttributes {
+    let mut attributes = subject.attributes.clone();
+    attributes.add_attribute(string("user_id", subject.user_id.as_str()));
+    attributes.add_attribute(string("tenant_id", subject.tenant_id.as_str()));
+    attributes.add_attribute(string_list(
+        "roles",
+        subject.roles.iter().map(|role| role.name().name()),
+    ));
+    attributes
+}
+
+fn self_relation(is_self: bool) -> AuthorizationRelations {
+    let mut relations = AuthorizationRelations::new();
+    if is_self {
+        relations.add_relation(AuthorizationRelation::new("self"));
+    }
+    relations
+}
+
+fn user_tenant_attributes(user_id: &UserId, tenant_id: &TenantId) -> AuthorizationAttributes {
+    let mut attributes = AuthorizationAttributes::new();
+    attributes.add_attribute(string("user_id", user_id.as_str()));
+    attributes.add_attribute(string("tenant_id", tenant_id.as_str()));
+    attributes
+}
+
+fn user_tenant_relations(
+    user_id: &UserId,
+    tenant_id: &TenantId,
+    subject: &SubjectContex,
+) -> AuthorizationRelations {
+    let mut relations = self_relation(user_id == &subject.user_id);
+    if tenant_id == &subject.tenant_id {
+        relations.add_relation(AuthorizationRelation::new("owner_tenant"));
+    }
+    relations
+}
+
+fn string(key: &str, value: &str) -> (AttributeKey, AttributeValue) {
+    (
+        AttributeKey::new(key),
+        AttributeValue::String(value.to_string()),
+    )
+}
+
+fn string_list<'a>(
+    key: &str,
+    values: impl Iterator<Item = &'a str>,
+) -> (AttributeKey, AttributeValue) {
+    let mut values: Vec<String> = values.map(str::to_string).collect();
+    values.sort();
+    (AttributeKey::new(key), AttributeValue::StringList(values))
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use domain::{DateTime, Membership, MembershipStatus, Role, TenantId, User, UserId};
+
+use crate::{
+    error::{AppError, AppResult},
+    ports::{MembershipRepository, RoleRepository, TemporaryGrantRepository, UserRepository},
+    SubjectContex,
+};
+
+use super::{
+    attributes::{AttributeKey, AttributeValue, AuthorizationAttributes},
+    effective_permissions::EffectivePermissionResolver,
+};
+
+/// Builds the [`SubjectContex`] a user acts with inside a tenant from their
+/// stored membership, instead of trusting a caller-supplied role list.
+pub struct SubjectResolver {
+    users: Arc<dyn UserRepository>,
+    memberships: Arc<dyn MembershipRepository>,
+    roles: Arc<dyn RoleRepository>,
+    grants: Arc<dyn TemporaryGrantRepository>,
+}
+
+impl SubjectResolver {
+    pub fn new(
+        users: Arc<dyn UserRepository>,
+        memberships: Arc<dyn MembershipRepository>,
+        roles: Arc<dyn RoleRepository>,
+        grants: Arc<dyn TemporaryGrantRepository>,
+    ) -> Self {
+        Self {
+            users,
+            memberships,
+            roles,
+            grants,
+        }
+    }
+
+    /// Resolves `user_id` as a member of `tenant_id`: the membership's roles,
+    /// the temporary grants still live at `now` and the attributes derived
+    /// from the user and the membership. Fails unless both the user and the
+    /// membership are active, with [`AppError::Forbidden`].
+    pub async fn resolve(
+        &self,
+        user_id: UserId,
+        tenant_id: TenantId,
+        now: DateTime,
+    ) -> AppResult<SubjectContex> {
+        let mut subject = SubjectContex::new(user_id.clone(), tenant_id.clone(), &[], &[]);
+
+        let user = self.users.load(subject.clone(), user_id.clone()).await?;
+        if !user.is_active() {
+            return Err(AppError::Forbidden);
+        }
+
+        let membership = self
+            .memberships
+            .load(subject.clone(), tenant_id.clone(), user_id.clone())
+            .await?;
+        if !membership.is_active() {
+            return Err(AppError::Forbidden);
+        }
+
+        let mut role_ids: Vec<_> = membership.roles().iter().cloned().collect();
+        role_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let mut roles = Vec::with_capacity(role_ids.len());
+        for id in role_ids {
+            roles.push(self.roles.load(subject.clone(), id).await?);
+        }
+
+        let grants = self
+            .grants
+            .load_by_user(subject.clone(), user_id, tenant_id)
+            .await?
+            .into_iter()
+            .filter(|grant| grant.is_active_at(now))
+            .collect::<Vec<_>>();
+
+        subject.add_attributes(derived_attributes(&user, &membership, &roles));
+        let hierarchy = EffectivePermissionResolver::new(self.roles.clone())
+            .hierarchy(&subject, &roles)
+            .await?;
+        subject.add_roles(&roles);
+        subject.set_role_hierarchy(hierarchy);
+        subject.add_temporary_grants(&grants);
+        Ok(subject)
+    }
+
+    /// Resolves `user_id` as the invitee of a pending invitation to
+    /// `tenant_id`: no roles and no grants, just enough to accept it. Fails
+    /// unless the user is active and the membership is still invited.
+    pub async fn resolve_invitee(
+        &self,
+        user_id: UserId,
+        tenant_id: TenantId,
+    ) -> AppResult<SubjectContex> {
+        let mut subject = SubjectContex::new(user_id.clone(), tenant_id.clone(), &[], &[]);
+
+        let user = self.users.load(subject.clone(), user_id.clone()).await?;
+        if !user.is_active() {
+            return Err(AppError::Forbidden);
+        }
+
+        let membership = self
+            .memberships
+            .load(subject.clone(), tenant_id.clone(), user_id.clone())
+            .await?;
+        if membership.status() != &MembershipStatus::Invited {
+            return Err(AppError::Forbidden);
+        }
+
+        subject.add_attributes(derived_attributes(&user, &membership, &[]));
+        Ok(subject)
+    }
+}
+
+fn derived_attributes(
+    user: &User,
+    membership: &Membership,
+    roles: &[Role],
+) -> AuthorizationAttributes {
+    let mut attributes = AuthorizationAttributes::new();
+    attributes.add_attribute((
+        AttributeKey::new("user_status"),
+        AttributeValue::String(user.status().to_string()),
+    ));
+    attributes.add_attribute((
+        AttributeKey::new("membership_status"),
+        AttributeValue::String(membership.status().to_string()),
+    ));
+    attributes.add_attribute((
+        AttributeKey::new("member_since"),
+        AttributeValue::Number(*membership.created_at().datetime()),
+    ));
+    attributes.add_attribute((
+        AttributeKey::new("role_ids"),
+        AttributeValue::StringList(
+            roles
+                .iter()
+                .map(|role| role.id().as_str().to_string())
+                .collect(),
+        ),
+    ));
+    attributes
+}
@@ -0,0 +1,241 @@
+use serde::Serialize;
+
+use super::{
+    attributes::AttributeValue,
+    combining::CombiningAlgorithm,
+    condition::{AttributeComparison, AttributeCondition},
+    AccessDecision,
+};
+
+/// Why the engine reached its decision, for logs and for admins
+/// investigating a denial.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecisionTrace {
+    pub resource_type: String,
+    pub action: String,
+    pub combining_algorithm: CombiningAlgorithm,
+    /// Every policy the engine evaluated, in evaluation order.
+    pub evaluations: Vec<PolicyEvaluation>,
+    /// The policy whose decision was taken; `None` when nothing applied or
+    /// the combining algorithm rejected conflicting policies.
+    pub decided_by: Option<String>,
+    pub decision: AccessDecision,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyStage {
+    Guard,
+    Policy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PolicyEvaluation {
+    pub policy_id: String,
+    pub stage: PolicyStage,
+    pub matched: bool,
+    pub decision: Option<AccessDecision>,
+    /// The first requirement the policy did not meet, when it can tell.
+    pub mismatch: Option<PolicyMismatch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PolicyMismatch {
+    ResourceType {
+        expected: String,
+        actual: String,
+    },
+    Action {
+        expected: String,
+        actual: String,
+    },
+    MissingRelation {
+        relation: String,
+    },
+    SubjectAttribute {
+        key: String,
+        expected: AttributeValue,
+        actual: Option<AttributeValue>,
+    },
+    ResourceAttribute {
+        key: String,
+        expected: AttributeValue,
+        actual: Option<AttributeValue>,
+    },
+    SubjectCondition {
+        condition: AttributeCondition,
+        actual: Option<AttributeValue>,
+    },
+    ResourceCondition {
+        condition: AttributeCondition,
+        actual: Option<AttributeValue>,
+    },
+    AttributeComparison {
+        comparison: AttributeComparison,
+        subject: Option<AttributeValue>,
+        resource: Option<AttributeValue>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        authorization::{
+            action::AuthorizationAction,
+            attributes::AttributeKey,
+            condition::AttributeOperator,
+            engine::{AuthorizationContext, AuthorizationEngine},
+            policys::{
+                ApplicationAuthorizationPolicy, ApplicationPolicyEffect, ApplicationPolicyId,
+                ApplicationPolicyPriority, ApplicationStoredPolicy, ApplicationStoredPolicyBuilder,
+            },
+            relation::AuthorizationRelation,
+            resource_type::AuthorizationResourceType,
+        },
+        fixtures::{context, subject},
+        ports::policy::PolicyRepository,
+    };
+
+    struct OpenGuard;
+
+    impl ApplicationAuthorizationPolicy for OpenGuard {
+        fn evaluate(&self, _ctx: &AuthorizationContext) -> Option<AccessDecision> {
+            Some(AccessDecision::Allow)
+        }
+
+        fn id(&self) -> ApplicationPolicyId {
+            ApplicationPolicyId::new("open_guard")
+        }
+    }
+
+    struct Stored(Vec<ApplicationStoredPolicy>);
+
+    impl PolicyRepository for Stored {
+        fn load_active_policies(&self) -> Vec<ApplicationStoredPolicy> {
+            self.0.clone()
+        }
+    }
+
+    fn policy(
+        id: &str,
+        action: &str,
+        priority: i32,
+        configure: impl FnOnce(&mut ApplicationStoredPolicyBuilder),
+    ) -> ApplicationStoredPolicy {
+        let mut builder = ApplicationStoredPolicy::new(
+            ApplicationPolicyId::new(id),
+            ApplicationPolicyPriority::new(priority),
+        );
+        builder
+            .set_resource_type(AuthorizationResourceType::new("report"))
+            .set_action(AuthorizationAction::new(action))
+            .set_effect(ApplicationPolicyEffect::Allow);
+        configure(&mut builder);
+        builder.build().unwrap()
+    }
+
+    fn engine() -> AuthorizationEngine {
+        let mut engine = AuthorizationEngine::new();
+        engine.register_guard_policy(OpenGuard);
+        engine.load_policies(&Stored(vec![
+            policy("update_reports", "update", 30, |_| {}),
+            policy("owners_read", "read", 20, |builder| {
+                builder.add_required_relation(AuthorizationRelation::new("owner"));
+            }),
+            policy("seniors_read", "read", 10, |builder| {
+                builder.add_subject_condition(AttributeCondition::new(
+                    AttributeKey::new("level"),
+                    AttributeOperator::GreaterOrEqual(3),
+                ));
+            }),
+            policy("anyone_reads", "read", 0, |_| {}),
+        ]));
+        engine
+    }
+
+    #[test]
+    fn records_every_evaluation_in_order() {
+        let subject = subject("alice", "acme");
+        let trace = engine().explain(&context(&subject, "report", "read"));
+
+        assert_eq!(trace.resource_type, "report");
+        assert_eq!(trace.action, "read");
+        assert_eq!(trace.combining_algorithm, CombiningAlgorithm::DenyOverrides);
+        let stages: Vec<(&str, PolicyStage, bool)> = trace
+            .evaluations
+            .iter()
+            .map(|evaluation| {
+                (
+                    evaluation.policy_id.as_str(),
+                    evaluation.stage,
+                    evaluation.matched,
+                )
+            })
+            .collect();
+        assert_eq!(
+            stages,
+            [
+                ("open_guard", PolicyStage::Guard, true),
+                ("update_reports", PolicyStage::Policy, false),
+                ("owners_read", PolicyStage::Policy, false),
+                ("seniors_read", PolicyStage::Policy, false),
+                ("anyone_reads", PolicyStage::Policy, true),
+            ]
+        );
+        assert_eq!(trace.decided_by.as_deref(), Some("anyone_reads"));
+        assert_eq!(trace.decision, AccessDecision::Allow);
+    }
+
+    #[test]
+    fn explains_why_a_policy_did_not_apply() {
+        let subject = subject("alice", "acme");
+        let trace = engine().explain(&context(&subject, "report", "read"));
+        let mismatches: Vec<Option<PolicyMismatch>> = trace
+            .evaluations
+            .into_iter()
+            .map(|evaluation| evaluation.mismatch)
+            .collect();
+
+        assert_eq!(
+            mismatches,
+            [
+                None,
+                Some(PolicyMismatch::Action {
+                    expected: "update".to_string(),
+                    actual: "read".to_string(),
+                }),
+                Some(PolicyMismatch::MissingRelation {
+                    relation: "owner".to_string(),
+                }),
+                Some(PolicyMismatch::SubjectCondition {
+                    condition: AttributeCondition::new(
+                        AttributeKey::new("level"),
+                        AttributeOperator::GreaterOrEqual(3),
+                    ),
+                    actual: None,
+                }),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn names_no_policy_when_nothing_applies() {
+        let subject = subject("alice", "acme");
+        let trace = engine().explain(&context(&subject, "user", "read"));
+
+        assert_eq!(trace.decision, AccessDecision::Deny);
+        assert_eq!(trace.decided_by, None);
+        assert!(trace
+            .evaluations
+            .iter()
+            .skip(1)
+            .all(|evaluation| evaluation.decision.is_none()
+                && matches!(
+                    evaluation.mismatch,
+                    Some(PolicyMismatch::ResourceType { .. })
+                )));
+    }
+}
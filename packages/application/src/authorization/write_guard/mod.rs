@@ -0,0 +1,51 @@
+//! Field-level write authorization. Commands are patches whose set fields
+//! are the ones being written; [`WriteGuard`] rejects a command setting any
+//! field outside [`AccessControl::writable_fields`].
+//!
+//! Nested fields are checked at their leaves, so writing
+//! `ReportCommand::content` with only `body` set needs
+//! `RepotField::Content(ReportContentField::Body)`.
+
+pub mod report;
+pub mod user;
+
+use std::hash::Hash;
+
+use domain::traits::field::Field;
+
+use crate::{
+    authorization::access_descriptor::AccessControl,
+    error::{AppError, AppResult},
+};
+
+/// A command that can tell which fields it sets.
+pub trait WrittenFields {
+    type Field: Field + Hash;
+
+    /// Every field the command sets, in declaration order. The id and
+    /// version only name the record and the version it was read at, so
+    /// they are never written.
+    fn written_fields(&self) -> Vec<Self::Field>;
+}
+
+pub struct WriteGuard;
+
+impl WriteGuard {
+    /// Fails with [`AppError::FieldNotWritable`] naming the first field
+    /// `command` sets that `access` does not allow writing.
+    pub fn check<C: WrittenFields>(command: &C, access: &AccessControl<C::Field>) -> AppResult<()> {
+        match command
+            .written_fields()
+            .into_iter()
+            .find(|field| !access.writable_fields.contains(field))
+        {
+            Some(field) => Err(AppError::FieldNotWritable(field.path())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `field` when `set`, for building [`WrittenFields::written_fields`].
+pub(crate) fn written<F>(set: bool, field: F) -> Option<F> {
+    set.then_some(field)
+}
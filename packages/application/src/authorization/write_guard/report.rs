@@ -0,0 +1,75 @@
+use crate::{
+    dto::report::command::{ReportCommand, ReportContentCommand, ReportTypeCommand},
+    projection::fields::report::{ReportContentField, ReportTypeField, RepotField},
+};
+
+use super::{written, WrittenFields};
+
+impl WrittenFields for ReportCommand {
+    type Field = RepotField;
+
+    fn written_fields(&self) -> Vec<RepotField> {
+        let mut fields: Vec<RepotField> = written(self.title.is_some(), RepotField::Title)
+            .into_iter()
+            .collect();
+        if let Some(content) = &self.content {
+            fields.extend(content_fields(content).into_iter().map(RepotField::Content));
+        }
+        if let Some(report_type) = &self.report_type {
+            fields.extend(
+                report_type_fields(report_type)
+                    .into_iter()
+                    .map(RepotField::ReportType),
+            );
+        }
+        fields.extend(
+            [
+                written(self.status.is_some(), RepotField::Status),
+                written(self.author_id.is_some(), RepotField::AuthorId),
+                written(self.owner_tenant.is_some(), RepotField::OwnerTenant),
+                written(
+                    !self.shared_with_tenants.is_empty(),
+                    RepotField::SharedWithTenants,
+                ),
+                written(self.created_at.is_some(), RepotField::CreatedAt),
+                written(self.updated_at.is_some(), RepotField::UpdatedAt),
+                written(self.due_date.is_some(), RepotField::DueDate),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        fields
+    }
+}
+
+fn content_fields(content: &ReportContentCommand) -> Vec<ReportContentField> {
+    [
+        written(content.body.is_some(), ReportContentField::Body),
+        written(
+            content.attachments.is_some(),
+            ReportContentField::Attachments,
+        ),
+        written(
+            content.rejection_reason.is_some(),
+            ReportContentField::RejectionReason,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn report_type_fields(report_type: &ReportTypeCommand) -> Vec<ReportTypeField> {
+    [
+        written(report_type.id.is_some(), ReportTypeField::Id),
+        written(report_type.name.is_some(), ReportTypeField::Name),
+        written(
+            report_type.description.is_some(),
+            ReportTypeField::Description,
+        ),
+        written(report_type.created_at.is_some(), ReportTypeField::CreatedAt),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
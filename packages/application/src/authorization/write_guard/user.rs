@@ -0,0 +1,79 @@
+use crate::{
+    dto::user::command::{UserCommand, UserPreferencesCommand, UserProfileCommand},
+    projection::fields::user::{UserField, UserPreferencesField, UserProfileField},
+};
+
+use super::{written, WrittenFields};
+
+impl WrittenFields for UserCommand {
+    type Field = UserField;
+
+    fn written_fields(&self) -> Vec<UserField> {
+        let mut fields: Vec<UserField> = [
+            written(self.email.is_some(), UserField::Email),
+            written(self.username.is_some(), UserField::Username),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if let Some(profile) = &self.profile {
+            fields.extend(profile_fields(profile).into_iter().map(UserField::Profile));
+        }
+        if let Some(preferences) = &self.preferences {
+            fields.extend(
+                preferences_fields(preferences)
+                    .into_iter()
+                    .map(UserField::Preferences),
+            );
+        }
+        fields.extend(written(self.status.is_some(), UserField::Status));
+        fields
+    }
+}
+
+fn profile_fields(profile: &UserProfileCommand) -> Vec<UserProfileField> {
+    [
+        written(profile.first_name.is_some(), UserProfileField::FirstName),
+        written(profile.last_name.is_some(), UserProfileField::LastName),
+        written(profile.password.is_some(), UserProfileField::Password),
+        written(profile.bio.is_some(), UserProfileField::Bio),
+        written(
+            profile.phone_numbers.is_some(),
+            UserProfileField::PhoneNumbers,
+        ),
+        written(profile.avatar_url.is_some(), UserProfileField::AvatarUrl),
+        written(
+            profile.date_of_birth.is_some(),
+            UserProfileField::DateOfBirth,
+        ),
+        written(profile.addressess.is_some(), UserProfileField::Addressess),
+        written(profile.website.is_some(), UserProfileField::Website),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn preferences_fields(preferences: &UserPreferencesCommand) -> Vec<UserPreferencesField> {
+    [
+        written(
+            preferences.email_notifications.is_some(),
+            UserPreferencesField::EmailNotifications,
+        ),
+        written(
+            preferences.push_notifications.is_some(),
+            UserPreferencesField::PushNotifications,
+        ),
+        written(
+            preferences.two_factor_auth.is_some(),
+            UserPreferencesField::TwoFactorAuth,
+        ),
+        written(
+            preferences.language.is_some(),
+            UserPreferencesField::Language,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
@@ -0,0 +1,17 @@
+use domain::{
+    value_objects::{Action, Comment, DateTime, Resource},
+    AccessRequestId, TenantId, UserId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct AccessRequestCommand {
+    pub id: Option<AccessRequestId>,
+    pub requester_id: Option<UserId>,
+    pub tenant_id: Option<TenantId>,
+    pub resource: Option<Resource>,
+    pub action: Option<Action>,
+    pub justification: Option<Comment>,
+    /// Requested grant lifetime in seconds.
+    pub requested_duration: Option<i64>,
+    pub created_at: Option<DateTime>,
+}
@@ -0,0 +1,44 @@
+use domain::{
+    value_objects::{Action, Comment, DateTime, Resource},
+    AccessRequest, AccessRequestId, AccessRequestStatus, TemporaryGrantId, TenantId, UserId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct AccessRequestView {
+    pub id: Option<AccessRequestId>,
+    pub requester_id: Option<UserId>,
+    pub tenant_id: Option<TenantId>,
+    pub resource: Option<Resource>,
+    pub action: Option<Action>,
+    pub justification: Option<Comment>,
+    pub requested_duration: Option<i64>,
+    pub status: Option<AccessRequestStatus>,
+    pub decided_by: Option<UserId>,
+    pub decision_reason: Option<Comment>,
+    pub grant_id: Option<TemporaryGrantId>,
+    pub created_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+    pub version: Option<u64>,
+}
+
+impl From<AccessRequest> for AccessRequestView {
+    fn from(request: AccessRequest) -> Self {
+        let parts = request.into_parts();
+        Self {
+            id: Some(parts.id),
+            requester_id: Some(parts.requester_id),
+            tenant_id: Some(parts.tenant_id),
+            resource: Some(parts.resource),
+            action: Some(parts.action),
+            justification: Some(parts.justification),
+            requested_duration: Some(parts.requested_duration),
+            status: Some(parts.status),
+            decided_by: parts.decided_by,
+            decision_reason: parts.decision_reason,
+            grant_id: parts.grant_id,
+            created_at: Some(parts.created_at),
+            updated_at: Some(parts.updated_at),
+            version: Some(parts.version),
+        }
+    }
+}
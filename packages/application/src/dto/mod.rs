@@ -1,3 +1,4 @@
+pub mod access_request;
 pub mod report;
 pub mod role;
 pub mod tenant;
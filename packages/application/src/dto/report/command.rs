@@ -16,25 +16,16 @@ pub struct ReportCommand {
     pub author_id: Option<UserId>,
     pub owner_tenant: Option<TenantId>,
     pub shared_with_tenants: HashSet<TenantId>,
-    pub assigned_reviewer_id: HashSet<UserId>,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
     pub due_date: Option<DateTime>,
     pub version: Option<u64>,
 }
 
-#[derive(Debug, Default)]
-pub struct ReviewCommentCommand {
-    pub reviewer_id: Option<UserId>,
-    pub comment: Option<Comment>,
-    pub created_at: Option<DateTime>,
-}
-
 #[derive(Debug)]
 pub struct ReportContentCommand {
     pub body: Option<Body>,
     pub attachments: Option<HashSet<Url>>, // URLs or paths to attachments
-    pub review_comments: Option<HashSet<ReviewCommentCommand>>,
     pub rejection_reason: Option<Option<Comment>>,
 }
 
@@ -1,7 +1,8 @@
 use domain::{
-    report::report_type::ReportTypeId,
+    report::{content::ReviewComment, report_type::ReportTypeId},
     value_objects::{Body, Comment, DateTime, Url},
-    Description, Name, ReportId, ReportStatus, TenantId, Title, UserId,
+    Description, Name, Report, ReportContent, ReportId, ReportStatus, ReportType, TenantId, Title,
+    UserId,
 };
 
 use std::collections::HashSet;
@@ -23,7 +24,7 @@ pub struct ReportView {
     pub version: Option<u64>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct ReviewCommentView {
     pub reviewer_id: Option<UserId>,
     pub comment: Option<Comment>,
@@ -45,3 +46,65 @@ pub struct ReportTypeView {
     pub description: Option<Description>,
     pub created_at: Option<DateTime>,
 }
+
+impl From<Report> for ReportView {
+    fn from(report: Report) -> Self {
+        let parts = report.into_parts();
+        Self {
+            id: Some(parts.id),
+            title: Some(parts.title),
+            content: Some(parts.content.into()),
+            report_type: Some(parts.report_type.into()),
+            status: Some(parts.status),
+            author_id: Some(parts.author_id),
+            owner_tenant: Some(parts.owner_tenant),
+            shared_with_tenants: parts.shared_with_tenants,
+            assigned_reviewer_id: parts.assigned_reviewer_id,
+            created_at: Some(parts.created_at),
+            updated_at: Some(parts.updated_at),
+            due_date: parts.due_date,
+            version: Some(parts.version),
+        }
+    }
+}
+
+impl From<ReviewComment> for ReviewCommentView {
+    fn from(review_comment: ReviewComment) -> Self {
+        let parts = review_comment.into_parts();
+        Self {
+            reviewer_id: Some(parts.reviewer_id),
+            comment: Some(parts.comment),
+            created_at: Some(parts.created_at),
+        }
+    }
+}
+
+impl From<ReportContent> for ReportContentView {
+    fn from(content: ReportContent) -> Self {
+        let parts = content.into_parts();
+        Self {
+            body: Some(parts.body),
+            attachments: Some(parts.attachments),
+            review_comments: Some(
+                parts
+                    .review_comments
+                    .into_iter()
+                    .map(ReviewCommentView::from)
+                    .collect(),
+            ),
+            rejection_reason: Some(parts.rejection_reason),
+        }
+    }
+}
+
+impl From<ReportType> for ReportTypeView {
+    fn from(report_type: ReportType) -> Self {
+        let parts = report_type.into_parts();
+        Self {
+            id: Some(parts.id),
+            name: Some(parts.name),
+            description: Some(parts.description),
+            created_at: Some(parts.created_at),
+        }
+    }
+}
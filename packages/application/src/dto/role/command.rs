@@ -8,6 +8,8 @@ pub struct RoleCommand {
     pub name: Option<Name>,
     pub description: Option<Description>,
     pub permissions: HashSet<PermissionId>,
+    /// `None` keeps the stored parents; an empty set removes them all.
+    pub parent_roles: Option<HashSet<RoleId>>,
     pub is_system_role: Option<bool>,
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use domain::{
     value_objects::{Action, Resource},
-    DateTime, Description, Name, PermissionId, RoleId,
+    DateTime, Description, Name, Permission, PermissionEffect, PermissionId, Role, RoleId,
 };
 
 #[derive(Debug, Clone)]
@@ -11,17 +11,54 @@ pub struct RoleView {
     pub name: Option<Name>,
     pub description: Option<Description>,
     pub permissions: HashSet<PermissionView>,
+    pub parent_roles: HashSet<RoleId>,
     pub is_system_role: Option<bool>,
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PermissionView {
     pub id: Option<PermissionId>,
     pub resource: Option<Resource>,
     pub action: Option<Action>,
+    pub effect: Option<PermissionEffect>,
     pub description: Option<Description>,
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
 }
+
+impl From<Role> for RoleView {
+    fn from(role: Role) -> Self {
+        let parts = role.into_parts();
+        Self {
+            id: Some(parts.id),
+            name: Some(parts.name),
+            description: Some(parts.description),
+            permissions: parts
+                .permissions
+                .into_iter()
+                .map(PermissionView::from)
+                .collect(),
+            parent_roles: parts.parent_roles,
+            is_system_role: Some(parts.is_system_role),
+            created_at: Some(parts.created_at),
+            version: Some(parts.version),
+        }
+    }
+}
+
+impl From<Permission> for PermissionView {
+    fn from(permission: Permission) -> Self {
+        let parts = permission.into_parts();
+        Self {
+            id: Some(parts.id),
+            resource: Some(parts.resource),
+            action: Some(parts.action),
+            effect: Some(parts.effect),
+            description: Some(parts.description),
+            created_at: Some(parts.created_at),
+            version: Some(parts.version),
+        }
+    }
+}
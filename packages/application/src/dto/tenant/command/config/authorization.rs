@@ -5,4 +5,6 @@ pub struct TenantAuthorizationConfigCommand {
     pub allow_cross_tenant_access: Option<bool>,
     pub require_reviewer_for_publish: Option<bool>,
     pub default_role: Option<RoleId>,
+    pub access_request_approver_role: Option<RoleId>,
+    pub max_access_grant_duration: Option<i64>,
 }
@@ -11,5 +11,6 @@ pub struct TenantFeatureConfigCommand {
 pub struct FeatureLimitsCommand {
     pub max_projects: Option<u32>,
     pub max_users: Option<u32>,
+    /// Requests per minute; 0 means unlimited.
     pub api_rate_limit: Option<u32>,
 }
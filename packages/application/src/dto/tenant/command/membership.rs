@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 
-use domain::{DateTime, RoleId, TenantId, UserId};
+use domain::{DateTime, MembershipStatus, RoleId, TenantId, UserId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MembershipCommand {
     pub user_id: Option<UserId>,
     pub tenet_id: Option<TenantId>,
     pub roles: Option<HashSet<RoleId>>,
+    pub status: Option<MembershipStatus>,
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
 }
@@ -1,11 +1,13 @@
 use domain::{
     value_objects::{Action, Resource},
-    DateTime, Description, UserId,
+    DateTime, Description, TemporaryGrantId, TenantId, UserId,
 };
 
 #[derive(Debug, Clone)]
 pub struct TemporaryGrantCommand {
+    pub id: Option<TemporaryGrantId>,
     pub user_id: Option<UserId>,
+    pub tenant_id: Option<TenantId>,
     pub description: Option<Description>,
     pub resource: Option<Resource>,
     pub action: Option<Action>,
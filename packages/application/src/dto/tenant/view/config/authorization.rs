@@ -1,8 +1,23 @@
-use domain::RoleId;
+use domain::{tenant::config::authorization::TenantAuthorizationConfig, RoleId};
 
 #[derive(Debug, Clone)]
 pub struct TenantAuthorizationConfigView {
     pub allow_cross_tenant_access: Option<bool>,
     pub require_reviewer_for_publish: Option<bool>,
     pub default_role: Option<RoleId>,
+    pub access_request_approver_role: Option<RoleId>,
+    pub max_access_grant_duration: Option<i64>,
+}
+
+impl From<TenantAuthorizationConfig> for TenantAuthorizationConfigView {
+    fn from(config: TenantAuthorizationConfig) -> Self {
+        let parts = config.into_parts();
+        Self {
+            allow_cross_tenant_access: Some(parts.allow_cross_tenant_access),
+            require_reviewer_for_publish: Some(parts.require_reviewer_for_publish),
+            default_role: parts.default_role,
+            access_request_approver_role: parts.access_request_approver_role,
+            max_access_grant_duration: Some(parts.max_access_grant_duration),
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use domain::{
     tenant::config::collaboration::{
-        CollaborationAccessLevel, CollaborationMode, FederationPolicy,
+        CollaborationAccessLevel, CollaborationMode, FederationPolicy, TenantCollaborationConfig,
     },
     TenantId,
 };
@@ -12,3 +12,15 @@ pub struct TenantCollaborationConfigView {
     pub federation: Option<FederationPolicy>,
     pub access_level: Option<CollaborationAccessLevel>,
 }
+
+impl From<TenantCollaborationConfig> for TenantCollaborationConfigView {
+    fn from(config: TenantCollaborationConfig) -> Self {
+        let parts = config.into_parts();
+        Self {
+            trusted_tenants: Some(parts.trusted_tenants),
+            allowed_modes: Some(parts.allowed_modes),
+            federation: Some(parts.federation),
+            access_level: Some(parts.access_level),
+        }
+    }
+}
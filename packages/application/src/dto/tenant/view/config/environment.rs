@@ -1,4 +1,7 @@
-use domain::value_objects::{NetworkZone, TimeWindow};
+use domain::{
+    tenant::config::environment::TenantEnvironmentConfig,
+    value_objects::{NetworkZone, TimeWindow},
+};
 
 #[derive(Debug, Clone)]
 pub struct TenantEnvironmentConfigView {
@@ -7,3 +10,15 @@ pub struct TenantEnvironmentConfigView {
     pub require_managed_device: Option<bool>,
     pub max_risk_score: Option<u8>,
 }
+
+impl From<TenantEnvironmentConfig> for TenantEnvironmentConfigView {
+    fn from(config: TenantEnvironmentConfig) -> Self {
+        let parts = config.into_parts();
+        Self {
+            allowed_time_window: parts.allowed_time_window,
+            allowed_networks: Some(parts.allowed_networks),
+            require_managed_device: Some(parts.require_managed_device),
+            max_risk_score: parts.max_risk_score,
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use domain::tenant::config::feature::{Feature, FeatureRolloutPolicy};
+use domain::tenant::config::feature::{
+    Feature, FeatureLimits, FeatureRolloutPolicy, TenantFeatureConfig,
+};
 
 #[derive(Debug, Clone)]
 pub struct TenantFeatureConfigView {
@@ -13,3 +15,25 @@ pub struct FeatureLimitsView {
     pub max_users: Option<u32>,
     pub api_rate_limit: Option<u32>,
 }
+
+impl From<TenantFeatureConfig> for TenantFeatureConfigView {
+    fn from(config: TenantFeatureConfig) -> Self {
+        let parts = config.into_parts();
+        Self {
+            enabled_features: Some(parts.enabled_features),
+            limits: Some(parts.limits.into()),
+            rollout: Some(parts.rollout),
+        }
+    }
+}
+
+impl From<FeatureLimits> for FeatureLimitsView {
+    fn from(limits: FeatureLimits) -> Self {
+        let parts = limits.into_parts();
+        Self {
+            max_projects: Some(parts.max_projects),
+            max_users: Some(parts.max_users),
+            api_rate_limit: Some(parts.api_rate_limit),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use authorization::TenantAuthorizationConfigView;
 use collaboration::TenantCollaborationConfigView;
+use domain::tenant::config::TenantConfig;
 use environment::TenantEnvironmentConfigView;
 use feature::TenantFeatureConfigView;
 
@@ -15,3 +16,15 @@ pub struct TenantConfigView {
     pub collaboration: Option<TenantCollaborationConfigView>,
     pub features: Option<TenantFeatureConfigView>,
 }
+
+impl From<TenantConfig> for TenantConfigView {
+    fn from(config: TenantConfig) -> Self {
+        let parts = config.into_parts();
+        Self {
+            authorization: Some(parts.authorization.into()),
+            environment: Some(parts.environment.into()),
+            collaboration: Some(parts.collaboration.into()),
+            features: Some(parts.features.into()),
+        }
+    }
+}
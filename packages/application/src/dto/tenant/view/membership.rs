@@ -1,12 +1,27 @@
 use std::collections::HashSet;
 
-use domain::{DateTime, RoleId, TenantId, UserId};
+use domain::{DateTime, Membership, MembershipStatus, RoleId, TenantId, UserId};
 
 #[derive(Debug, Clone)]
 pub struct MembershipView {
     pub user_id: Option<UserId>,
     pub tenet_id: Option<TenantId>,
     pub roles: Option<HashSet<RoleId>>,
+    pub status: Option<MembershipStatus>,
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
 }
+
+impl From<Membership> for MembershipView {
+    fn from(membership: Membership) -> Self {
+        let parts = membership.into_parts();
+        Self {
+            user_id: Some(parts.user_id),
+            tenet_id: Some(parts.tenet_id),
+            roles: Some(parts.roles),
+            status: Some(parts.status),
+            created_at: Some(parts.created_at),
+            version: Some(parts.version),
+        }
+    }
+}
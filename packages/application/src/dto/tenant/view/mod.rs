@@ -3,7 +3,7 @@ pub mod environment;
 pub mod membership;
 pub mod temporary_grant;
 use config::TenantConfigView;
-use domain::TenantId;
+use domain::{Tenant, TenantId, TenantStatus};
 pub use membership::MembershipView;
 
 use domain::{DateTime, Description, Name};
@@ -15,5 +15,21 @@ pub struct TenantView {
     pub description: Option<Description>,
     pub created_at: Option<DateTime>,
     pub config: Option<TenantConfigView>,
+    pub status: Option<TenantStatus>,
     pub version: Option<u64>,
 }
+
+impl From<Tenant> for TenantView {
+    fn from(tenant: Tenant) -> Self {
+        let parts = tenant.into_parts();
+        Self {
+            id: Some(parts.id),
+            name: Some(parts.name),
+            description: Some(parts.description),
+            created_at: Some(parts.created_at),
+            config: Some(parts.config.into()),
+            status: Some(parts.status),
+            version: Some(parts.version),
+        }
+    }
+}
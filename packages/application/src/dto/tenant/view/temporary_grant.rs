@@ -1,11 +1,13 @@
 use domain::{
     value_objects::{Action, Resource},
-    DateTime, Description, UserId,
+    DateTime, Description, TemporaryGrant, TemporaryGrantId, TenantId, UserId,
 };
 
 #[derive(Debug, Clone)]
 pub struct TemporaryGrantView {
+    pub id: Option<TemporaryGrantId>,
     pub user_id: Option<UserId>,
+    pub tenant_id: Option<TenantId>,
     pub description: Option<Description>,
     pub resource: Option<Resource>,
     pub action: Option<Action>,
@@ -13,3 +15,28 @@ pub struct TemporaryGrantView {
     pub created_at: Option<DateTime>,
     pub version: Option<u64>,
 }
+
+/// Grants issued through `TemporaryGrantCommand` carry a single permission;
+/// for grants with several, the one with the lowest id is shown.
+impl From<TemporaryGrant> for TemporaryGrantView {
+    fn from(grant: TemporaryGrant) -> Self {
+        let parts = grant.into_parts();
+        let permission = parts
+            .permissions
+            .into_iter()
+            .min_by(|a, b| a.id().as_str().cmp(b.id().as_str()));
+        Self {
+            id: Some(parts.id),
+            user_id: Some(parts.user_id),
+            tenant_id: Some(parts.tenant_id),
+            description: Some(parts.description),
+            resource: permission
+                .as_ref()
+                .map(|permission| permission.resource().clone()),
+            action: permission.map(|permission| permission.action().clone()),
+            expires_at: Some(parts.expires_at),
+            created_at: Some(parts.created_at),
+            version: Some(parts.version),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use domain::{
     user::UserStatus, value_objects::Language, Addressess, Bio, DateTime, Email, Name, Password,
-    PhoneNumbers, Url, Username,
+    PhoneNumbers, Url, UserId, Username,
 };
 
 #[derive(Debug, Default)]
 pub struct UserCommand {
+    pub id: Option<UserId>,
     pub email: Option<Email>,
     pub username: Option<Username>,
     pub profile: Option<UserProfileCommand>,
@@ -1,6 +1,7 @@
 use domain::{
-    user::UserStatus, value_objects::Language, Addressess, Bio, DateTime, Email, Name, Password,
-    PhoneNumbers, Url, UserId, Username,
+    user::{UserPreferences, UserStatus},
+    value_objects::Language,
+    Addressess, Bio, DateTime, Email, Name, PhoneNumbers, Url, User, UserId, UserProfile, Username,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -39,3 +40,52 @@ pub struct UserPreferencesView {
     pub two_factor_auth: Option<bool>,
     pub language: Option<Language>,
 }
+
+impl From<User> for UserView {
+    fn from(user: User) -> Self {
+        let parts = user.into_parts();
+        Self {
+            id: Some(parts.id),
+            email: Some(parts.email),
+            username: Some(parts.username),
+            profile: Some(parts.profile.into()),
+            preferences: Some(parts.preferences.into()),
+            status: Some(parts.status),
+            failed_logins: parts.failed_logins,
+            locked_until: parts.locked_until,
+            last_login: parts.last_login,
+            version: Some(parts.version),
+        }
+    }
+}
+
+impl From<UserProfile> for UserProfileView {
+    fn from(profile: UserProfile) -> Self {
+        let parts = profile.into_parts();
+        Self {
+            first_name: Some(parts.first_name),
+            last_name: Some(parts.last_name),
+            bio: parts.bio,
+            phone_numbers: Some(parts.phone_numbers),
+            avatar_url: parts.avatar_url,
+            date_of_birth: parts.date_of_birth,
+            addressess: Some(parts.addressess),
+            website: parts.website,
+            is_deleted: Some(parts.is_deleted),
+            created_at: Some(parts.created_at),
+            updated_at: Some(parts.updated_at),
+        }
+    }
+}
+
+impl From<UserPreferences> for UserPreferencesView {
+    fn from(preferences: UserPreferences) -> Self {
+        let parts = preferences.into_parts();
+        Self {
+            email_notifications: Some(parts.email_notifications),
+            push_notifications: Some(parts.push_notifications),
+            two_factor_auth: Some(parts.two_factor_auth),
+            language: Some(parts.language),
+        }
+    }
+}
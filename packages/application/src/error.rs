@@ -18,6 +18,16 @@ pub enum AppError {
     #[error("Application Policy failed: {0}")]
     PolicyError(String),
 
+    /// The command sets a field the subject may not write; holds its path,
+    /// e.g. `content.review_comments.comment`.
+    #[error("Not allowed to write field {0}")]
+    FieldNotWritable(String),
+
+    /// The tenant's API rate limit is exhausted; the request may be retried
+    /// after `retry_after` seconds.
+    #[error("Rate limit exceeded, retry after {retry_after}s")]
+    RateLimited { retry_after: u64 },
+
     #[error("Unknown application error: {0}")]
     Unknown(String),
 }
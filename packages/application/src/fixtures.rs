@@ -0,0 +1,139 @@
+//! Aggregates shared by the unit tests of this crate.
+
+use domain::{
+    DateTime, Description, Name, Permission, Role, RoleId, Tenant, TenantId, TenantStatus, UserId,
+    tenant::{
+        config::{
+            TenantConfig,
+            authorization::TenantAuthorizationConfig,
+            collaboration::{
+                CollaborationAccessLevel, FederationPolicy, TenantCollaborationConfig,
+            },
+            environment::TenantEnvironmentConfig,
+            feature::{Feature, FeatureLimits, FeatureRolloutPolicy, TenantFeatureConfig},
+        },
+        environment::{
+            Environment,
+            datetime::EnvironmentTime,
+            device_security_posture::DeviceSecurityPosture,
+            location::{EnvironmentLocation, LocationZone},
+            network_information::{ConnectionType, NetworkInformation},
+            risk_signals::{AuthenticationStrength, RiskSignals},
+        },
+    },
+};
+
+use crate::{
+    SubjectContex,
+    authorization::{
+        action::AuthorizationAction, attributes::AuthorizationAttributes,
+        engine::AuthorizationContext, relation::AuthorizationRelations,
+        resource_type::AuthorizationResourceType,
+    },
+};
+
+/// Nothing shared, no approver role and grants of up to an hour.
+pub(crate) fn authorization_config() -> TenantAuthorizationConfig {
+    TenantAuthorizationConfig::new(false, false, None, None, 3600)
+}
+
+/// Open at any time, from any network and device.
+pub(crate) fn environment_config() -> TenantEnvironmentConfig {
+    TenantEnvironmentConfig::new(None, &[], false, None)
+}
+
+/// Trusts no other tenant.
+pub(crate) fn collaboration_config() -> TenantCollaborationConfig {
+    TenantCollaborationConfig::new(
+        Vec::new(),
+        Vec::new(),
+        FederationPolicy::Disabled,
+        CollaborationAccessLevel::ReadOnly,
+    )
+}
+
+/// `features` rolled out to every tenant.
+pub(crate) fn feature_config(features: Vec<Feature>) -> TenantFeatureConfig {
+    TenantFeatureConfig::new(
+        features,
+        FeatureLimits::new(10, 10, 10),
+        FeatureRolloutPolicy::Stable,
+    )
+    .unwrap()
+}
+
+pub(crate) fn tenant(id: &str, config: TenantConfig) -> Tenant {
+    Tenant::new(
+        TenantId::new(id),
+        Name::new("Tenant").unwrap(),
+        Description::new("A tenant").unwrap(),
+        DateTime::new(0),
+        config,
+        TenantStatus::Active,
+        1,
+    )
+}
+
+/// An active tenant with the default configuration of this module.
+pub(crate) fn default_tenant(id: &str) -> Tenant {
+    tenant(
+        id,
+        TenantConfig::new(
+            authorization_config(),
+            environment_config(),
+            collaboration_config(),
+            feature_config(Vec::new()),
+        ),
+    )
+}
+
+/// A tenant role with `permissions` that inherits from `parents`.
+pub(crate) fn role(id: &str, parents: &[&str], permissions: &[Permission]) -> Role {
+    let mut builder = Role::new(RoleId::new(id));
+    builder
+        .set_name(Name::new(id).unwrap())
+        .set_description(Description::new("A role").unwrap())
+        .set_is_system_role(false)
+        .set_created_at(DateTime::new(0));
+    for parent in parents {
+        builder.add_parent_role(RoleId::new(parent));
+    }
+    for permission in permissions {
+        builder.add_permission(permission.clone());
+    }
+    builder.build().unwrap()
+}
+
+pub(crate) fn subject(user: &str, tenant: &str) -> SubjectContex {
+    SubjectContex::new(UserId::new(user), TenantId::new(tenant), &[], &[])
+}
+
+/// A trusted, multi factor session on a managed device at `now`.
+pub(crate) fn environment(now: i64) -> Environment {
+    Environment::new(
+        EnvironmentTime::new(DateTime::new(now), true),
+        EnvironmentLocation::new(LocationZone::Unknown),
+        DeviceSecurityPosture::new(true, true, true),
+        NetworkInformation::new(false, true, ConnectionType::Unknown),
+        RiskSignals::new(0, AuthenticationStrength::MultiFactor, 0),
+    )
+}
+
+/// `subject` asking to `action` a `resource_type`, with no attributes,
+/// relations or owner tenant.
+pub(crate) fn context<'a>(
+    subject: &'a SubjectContex,
+    resource_type: &str,
+    action: &str,
+) -> AuthorizationContext<'a> {
+    AuthorizationContext {
+        subject,
+        action: AuthorizationAction::new(action),
+        resource_type: AuthorizationResourceType::new(resource_type),
+        subject_attributes: AuthorizationAttributes::new(),
+        resource_attributes: AuthorizationAttributes::new(),
+        relations: AuthorizationRelations::new(),
+        environment: environment(0),
+        owner_tenant: None,
+    }
+}
@@ -1,11 +1,16 @@
-#![allow(dead_code)]
+#![allow(dead_code, clippy::new_ret_no_self)]
 
-use domain::{Role, TemporaryGrant, TenantId, UserId};
+use authorization::attributes::AuthorizationAttributes;
+use domain::{role::RoleHierarchy, Role, TemporaryGrant, TenantId, UserId};
 pub mod authorization;
 pub mod dto;
 pub mod error;
+#[cfg(test)]
+mod fixtures;
 pub mod ports;
 pub mod projection;
+pub mod query;
+pub mod rate_limit;
 pub mod usecases;
 
 #[derive(Debug, Clone)]
@@ -14,6 +19,12 @@ pub struct SubjectContex {
     pub tenant_id: TenantId,
     pub roles: Vec<Role>,
     pub temporary_grants: Vec<TemporaryGrant>,
+    /// Attributes derived when the subject was resolved, on top of the ones
+    /// every subject has; see [`authorization::resource::subject_attributes`].
+    pub attributes: AuthorizationAttributes,
+    /// `roles` and every role they inherit from, resolved with the subject
+    /// so role checks see the hierarchy as it is stored now.
+    pub role_hierarchy: RoleHierarchy,
 }
 
 impl SubjectContex {
@@ -28,17 +39,29 @@ impl SubjectContex {
             tenant_id,
             roles: role_set.to_vec(),
             temporary_grants: temporary_grants.to_vec(),
+            attributes: AuthorizationAttributes::new(),
+            role_hierarchy: RoleHierarchy::new(role_set.to_vec()),
         }
     }
 
     pub fn add_role(&mut self, role: Role) {
+        self.role_hierarchy.insert(role.clone());
         self.roles.push(role);
     }
 
     pub fn add_roles(&mut self, roles: &[Role]) {
+        for role in roles {
+            self.role_hierarchy.insert(role.clone());
+        }
         self.roles.extend_from_slice(roles);
     }
 
+    /// Adds the ancestors of the subject's roles, as loaded by
+    /// [`EffectivePermissionResolver::hierarchy`](authorization::effective_permissions::EffectivePermissionResolver::hierarchy).
+    pub fn set_role_hierarchy(&mut self, hierarchy: RoleHierarchy) {
+        self.role_hierarchy = hierarchy;
+    }
+
     pub fn add_temporary_grant(&mut self, temporary_grant: TemporaryGrant) {
         self.temporary_grants.push(temporary_grant);
     }
@@ -47,6 +70,10 @@ impl SubjectContex {
         self.temporary_grants.extend_from_slice(temporary_grant);
     }
 
+    pub fn add_attributes(&mut self, attributes: AuthorizationAttributes) {
+        self.attributes.add_attributes(attributes);
+    }
+
     pub fn user_id(&self) -> UserId {
         self.user_id.clone()
     }
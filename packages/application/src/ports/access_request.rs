@@ -0,0 +1,17 @@
+use domain::{AccessRequest, AccessRequestId, AccessRequestStatus};
+
+use crate::{SubjectContex, dto::access_request::{command::AccessRequestCommand, view::AccessRequestView}, error::AppResult};
+
+use super::{Cursor, Page, SortBy};
+
+/// Access requests are scoped to the subject's tenant.
+#[async_trait::async_trait]
+pub trait AccessRequestRepository: Send + Sync {
+    async fn create(&self,ctx: SubjectContex, request: AccessRequestCommand) -> AppResult<AccessRequestView>;
+    async fn get_by_id(&self,ctx: SubjectContex, id: AccessRequestId) -> AppResult<AccessRequestView>;
+    /// A page of the requests in the subject's tenant, all of them when
+    /// `status` is `None`.
+    async fn get_by_status_page(&self,ctx: SubjectContex, status: Option<AccessRequestStatus>, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<AccessRequestView>>;
+    async fn load(&self,ctx: SubjectContex, id: AccessRequestId) -> AppResult<AccessRequest>;
+    async fn save(&self,ctx: SubjectContex, request: AccessRequest) -> AppResult<AccessRequest>;
+}
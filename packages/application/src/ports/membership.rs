@@ -0,0 +1,28 @@
+use domain::{Membership, TenantId, UserId};
+
+use crate::{SubjectContex, dto::tenant::{command::MembershipCommand, view::MembershipView}, error::AppResult};
+
+use super::{Cursor, Page, SortBy};
+
+/// Memberships are keyed by tenant and user. Reads are scoped to the
+/// subject's tenant, except that a user always sees their own memberships.
+#[async_trait::async_trait]
+pub trait MembershipRepository: Send + Sync {
+    async fn create(&self,ctx: SubjectContex, membership: MembershipCommand) -> AppResult<MembershipView>;
+    /// Like `create`, but fails with `MemberLimitReached` when the tenant
+    /// already has `max_members` members. The seat is checked and taken in
+    /// the same write as the membership, so concurrent calls cannot
+    /// overshoot the limit.
+    async fn create_within_limit(&self,ctx: SubjectContex, membership: MembershipCommand, max_members: u32) -> AppResult<MembershipView>;
+    async fn get(&self,ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<MembershipView>;
+    /// Removes the membership and records its pending events.
+    async fn delete(&self,ctx: SubjectContex, membership: Membership) -> AppResult<bool>;
+    /// Members of the tenant, invited ones included.
+    async fn count_members(&self,ctx: SubjectContex, tenant_id: TenantId) -> AppResult<u32>;
+    /// Up to `page_size` members of the tenant ordered by `sort_by`, starting
+    /// after `after`.
+    async fn get_members_page(&self,ctx: SubjectContex, tenant_id: TenantId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>>;
+    async fn get_user_tenants_page(&self,ctx: SubjectContex, user_id: UserId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>>;
+    async fn load(&self,ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<Membership>;
+    async fn save(&self,ctx: SubjectContex, membership: Membership) -> AppResult<Membership>;
+}
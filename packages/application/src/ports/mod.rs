@@ -1,15 +1,28 @@
+//! Repository ports. `save` persists an aggregate together with its pending
+//! domain events in a single transaction, so the outbox never drifts from the
+//! data it describes.
+
+pub mod access_request;
 pub mod events;
+pub mod membership;
+pub mod page;
 pub mod policy;
 pub mod report;
 pub mod role;
+pub mod temporary_grant;
 pub mod tenant;
 pub mod user;
 
+pub use access_request::AccessRequestRepository;
+pub use membership::MembershipRepository;
+pub use page::{Cursor, Page};
 pub use report::ReportRepository;
 pub use role::RoleRepository;
+pub use temporary_grant::TemporaryGrantRepository;
+pub use tenant::TenantRepository;
 pub use user::UserRepository;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SortBy {
     Descending(String),
     Ascending(String),
@@ -21,7 +34,7 @@ impl SortBy {
         match self {
             Self::Descending(field) => format!(" {} DESC ", field),
             Self::Ascending(field) => format!(" {} ASC ", field),
-            Self::None => format!(""),
+            Self::None => String::new(),
         }
     }
 }
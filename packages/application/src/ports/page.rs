@@ -0,0 +1,195 @@
+//! Keyset pagination. A page ends with a [`Cursor`] holding the sort keys of
+//! its last row, and the next page starts strictly after those keys instead of
+//! skipping `page * page_size` rows, so rows inserted while paging neither
+//! shift nor repeat what the caller sees.
+
+use std::{fmt, str::FromStr};
+
+use domain::DateTime;
+
+use crate::{
+    error::{AppError, AppResult},
+    query::ast::Value,
+};
+
+use super::SortBy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Where the next page starts; `None` on the last page.
+    pub next_cursor: Option<Cursor>,
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            has_more: self.has_more,
+        }
+    }
+}
+
+/// Position after a row, in a given order. Callers treat it as opaque and
+/// hand back the string it was [`encode`](Cursor::encode)d to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    order: String,
+    keys: Vec<Value>,
+}
+
+impl Cursor {
+    /// `keys` are the row's values for each field of `sort_by`, followed by
+    /// its id, which breaks ties.
+    pub fn new(sort_by: &[SortBy], keys: Vec<Value>) -> Self {
+        Self {
+            order: order(sort_by),
+            keys,
+        }
+    }
+
+    pub fn keys(&self) -> &[Value] {
+        &self.keys
+    }
+
+    /// The keys, provided the cursor was issued for `sort_by`; a cursor from
+    /// a differently ordered listing points nowhere meaningful.
+    pub fn keys_for(&self, sort_by: &[SortBy]) -> AppResult<&[Value]> {
+        if self.order != order(sort_by) {
+            return Err(AppError::ValidationError(
+                "cursor was issued for a different sort order".to_string(),
+            ));
+        }
+        Ok(&self.keys)
+    }
+
+    pub fn encode(&self) -> String {
+        std::iter::once(self.order.clone())
+            .chain(self.keys.iter().map(encode_value))
+            .map(|part| hex(&part))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    pub fn decode(token: &str) -> AppResult<Self> {
+        let mut parts = token.split('.').map(unhex);
+        let order = parts.next().ok_or_else(invalid)??;
+        let keys = parts
+            .map(|part| decode_value(&part?))
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(Self { order, keys })
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = AppError;
+
+    fn from_str(token: &str) -> AppResult<Self> {
+        Self::decode(token)
+    }
+}
+
+/// `title:asc,created_at:desc`
+fn order(sort_by: &[SortBy]) -> String {
+    sort_by
+        .iter()
+        .filter_map(|sort| match sort {
+            SortBy::Ascending(field) => Some(format!("{field}:asc")),
+            SortBy::Descending(field) => Some(format!("{field}:desc")),
+            SortBy::None => None,
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn invalid() -> AppError {
+    AppError::ValidationError("invalid cursor".to_string())
+}
+
+/// Sort keys are scalars; a list has no place in a cursor and is kept as null.
+fn encode_value(value: &Value) -> String {
+    match value {
+        Value::Null | Value::List(_) => "n".to_string(),
+        Value::Bool(value) => format!("b{}", u8::from(*value)),
+        Value::Int(value) => format!("i{value}"),
+        Value::Float(value) => format!("f{value}"),
+        Value::String(value) => format!("s{value}"),
+        Value::DateTime(value) => format!("d{}", value.datetime()),
+    }
+}
+
+fn decode_value(part: &str) -> AppResult<Value> {
+    let mut chars = part.chars();
+    let tag = chars.next().ok_or_else(invalid)?;
+    let rest = chars.as_str();
+    match tag {
+        'n' => Ok(Value::Null),
+        'b' => Ok(Value::Bool(rest == "1")),
+        'i' => rest.parse().map(Value::Int).map_err(|_| invalid()),
+        'f' => rest.parse().map(Value::Float).map_err(|_| invalid()),
+        's' => Ok(Value::String(rest.to_string())),
+        'd' => rest
+            .parse()
+            .map(|seconds| Value::DateTime(DateTime::new(seconds)))
+            .map_err(|_| invalid()),
+        _ => Err(invalid()),
+    }
+}
+
+fn hex(part: &str) -> String {
+    part.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn unhex(part: &str) -> AppResult<String> {
+    if !part.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+    let bytes = (0..part.len())
+        .step_by(2)
+        .map(|at| {
+            part.get(at..at + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect::<AppResult<Vec<u8>>>()?;
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursors_survive_encoding() {
+        let sort_by = [
+            SortBy::Descending("due_date".to_string()),
+            SortBy::Ascending("title".to_string()),
+            SortBy::Ascending("created_at".to_string()),
+        ];
+        let cursor = Cursor::new(
+            &sort_by,
+            vec![
+                Value::Null,
+                Value::String("a.b:c".to_string()),
+                Value::DateTime(DateTime::new(1_700_000_000)),
+                Value::Int(-3),
+                Value::Bool(true),
+                Value::Float(1.5),
+            ],
+        );
+
+        let decoded = Cursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.keys_for(&sort_by).unwrap(), cursor.keys());
+        assert!(decoded.keys_for(&sort_by[1..]).is_err());
+        assert!(Cursor::decode("zz").is_err());
+    }
+}
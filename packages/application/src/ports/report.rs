@@ -1,11 +1,12 @@
 use domain::{value_objects::Title, Report, ReportId, UserId};
 
-use crate::{SubjectContex, dto::report::{command::ReportCommand, view::ReportView}, error::AppResult};
+use crate::{SubjectContex, dto::report::{command::ReportCommand, view::ReportView}, error::AppResult, projection::Projection, query::Query};
 
-use super::SortBy;
+use super::{Cursor, Page, SortBy};
 
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum ReportQueryResult {
     Single(ReportView),
     Array(Vec<ReportView>),
@@ -30,7 +31,7 @@ impl ReportQueryResult {
 
 
 #[async_trait::async_trait]
-pub trait ReportRepository {
+pub trait ReportRepository: Send + Sync {
     async fn create(&self,ctx: SubjectContex, report: ReportCommand) -> AppResult<ReportView>;
     async fn delete(&self,ctx: SubjectContex, report_id: ReportId) -> AppResult<bool>;
     async fn update(&self,ctx: SubjectContex, report: ReportCommand) -> AppResult<ReportView>;
@@ -38,5 +39,18 @@ pub trait ReportRepository {
     async fn get_by_author_id(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32, auther_id: UserId) -> AppResult<Vec<ReportView>>;
     async fn get_by_title(&self,ctx: SubjectContex, title: Title) -> AppResult<ReportView>;
     async fn get_reports_paginated(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>>;
+    /// Up to `page_size` reports ordered by `sort_by`, starting after `after`;
+    /// unlike `get_reports_paginated` the page holds still while rows are added.
+    async fn get_reports_page(&self,ctx: SubjectContex,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<ReportView>>;
+    /// Like `get_by_id`, but reads only the fields `projection` selects and
+    /// leaves the rest of the view unset.
+    async fn get_projected(&self,ctx: SubjectContex, id: ReportId, projection: &Projection<Report>) -> AppResult<ReportView>;
+    async fn get_reports_paginated_projected(&self,ctx: SubjectContex, projection: &Projection<Report>, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>>;
+    /// Reports matching `query`, in its order and page, limited to the fields
+    /// it selects.
+    async fn query(&self,ctx: SubjectContex, query: &Query<Report>) -> AppResult<Vec<ReportView>>;
+    #[deprecated(note = "build a typed `query::Query` instead of SurrealQL text")]
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<ReportQueryResult>;
+    async fn load(&self,ctx: SubjectContex, id: ReportId) -> AppResult<Report>;
+    async fn save(&self,ctx: SubjectContex, report: Report) -> AppResult<Report>;
 }
@@ -1,8 +1,8 @@
-use domain::{Name, RoleId};
+use domain::{Name, Role, RoleId};
 
 use crate::{SubjectContex, dto::role::{command::RoleCommand, view::RoleView}, error::AppResult};
 
-use super::SortBy;
+use super::{Cursor, Page, SortBy};
 
 
 #[derive(Debug, Clone)]
@@ -29,12 +29,18 @@ impl RoleQueryResult {
 
 
 #[async_trait::async_trait]
-pub trait RoleRepository {
+pub trait RoleRepository: Send + Sync {
     async fn create(&self, ctx: SubjectContex, role: RoleCommand) -> AppResult<RoleView>;
     async fn update(&self, ctx: SubjectContex, role: RoleCommand) -> AppResult<RoleView>;
     async fn get_by_id(&self, ctx: SubjectContex, id: RoleId) -> AppResult<RoleView>;
     async fn get_by_name(&self, ctx: SubjectContex, id: Name) -> AppResult<RoleView>;
     async fn get_roles_paginated(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<RoleView>>;
+    /// Up to `page_size` roles ordered by `sort_by`, starting after `after`;
+    /// unlike `get_roles_paginated` the page holds still while rows are added.
+    async fn get_roles_page(&self,ctx: SubjectContex,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<RoleView>>;
     async fn delete(&self, ctx: SubjectContex, id: RoleId) -> AppResult<bool>;
+    /// SurrealQL passed through as is. Roles have no `Field` enum, so no typed query.
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<RoleQueryResult>;
+    async fn load(&self, ctx: SubjectContex, id: RoleId) -> AppResult<Role>;
+    async fn save(&self, ctx: SubjectContex, role: Role) -> AppResult<Role>;
 }
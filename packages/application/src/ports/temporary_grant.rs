@@ -0,0 +1,16 @@
+use domain::{TemporaryGrant, TemporaryGrantId, TenantId, UserId};
+
+use crate::{SubjectContex, dto::tenant::{command::temporary_grant::TemporaryGrantCommand, view::temporary_grant::TemporaryGrantView}, error::AppResult};
+
+use super::{Cursor, Page, SortBy};
+
+#[async_trait::async_trait]
+pub trait TemporaryGrantRepository: Send + Sync {
+    async fn issue(&self,ctx: SubjectContex, grant: TemporaryGrantCommand) -> AppResult<TemporaryGrantView>;
+    async fn revoke(&self,ctx: SubjectContex, id: TemporaryGrantId) -> AppResult<bool>;
+    /// A page of the user's grants in the tenant, expired ones included.
+    async fn get_by_user_page(&self,ctx: SubjectContex, user_id: UserId, tenant_id: TenantId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<TemporaryGrantView>>;
+    async fn load(&self,ctx: SubjectContex, id: TemporaryGrantId) -> AppResult<TemporaryGrant>;
+    async fn load_by_user(&self,ctx: SubjectContex, user_id: UserId, tenant_id: TenantId) -> AppResult<Vec<TemporaryGrant>>;
+    async fn save(&self,ctx: SubjectContex, grant: TemporaryGrant) -> AppResult<TemporaryGrant>;
+}
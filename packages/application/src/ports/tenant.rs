@@ -1,10 +1,11 @@
-use domain::TenantId;
+use domain::{DateTime, Tenant, TenantId};
 
 use crate::{SubjectContex, dto::tenant::{command::TenantCommand, view::TenantView}, error::AppResult};
 
-use super::{ SortBy};
+use super::{Cursor, Page, SortBy};
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum TenantQueryResult {
     Single(TenantView),
     Array(Vec<TenantView>),
@@ -27,11 +28,22 @@ impl TenantQueryResult {
 }
 
 #[async_trait::async_trait]
-pub trait TenantRepository {
+pub trait TenantRepository: Send + Sync {
     async fn create(&self,ctx: SubjectContex, tenant: TenantCommand) -> AppResult<TenantView>;
     async fn update(&self,ctx: SubjectContex, tenant: TenantCommand) -> AppResult<TenantView>;
     async fn get_by_id(&self,ctx: SubjectContex, id: TenantId) -> AppResult<TenantView>;
     async fn delete(&self,ctx: SubjectContex, id: TenantId) -> AppResult<bool>;
     async fn get_tenants_paginated(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<TenantView>>;
+    /// Up to `page_size` tenants ordered by `sort_by`, starting after `after`;
+    /// unlike `get_tenants_paginated` the page holds still while rows are added.
+    async fn get_tenants_page(&self,ctx: SubjectContex,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<TenantView>>;
+    /// SurrealQL passed through as is. Tenants have no `Field` enum, so no typed query.
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<TenantQueryResult>;
+    async fn load(&self,ctx: SubjectContex, id: TenantId) -> AppResult<Tenant>;
+    async fn save(&self,ctx: SubjectContex, tenant: Tenant) -> AppResult<Tenant>;
+    /// Tenants of every subject whose deletion grace period has ended by `now`.
+    async fn get_due_for_purge(&self,ctx: SubjectContex, now: DateTime) -> AppResult<Vec<Tenant>>;
+    /// Saves a `Deleted` tenant and archives its reports and memberships in
+    /// the same transaction.
+    async fn purge(&self,ctx: SubjectContex, tenant: Tenant, now: DateTime) -> AppResult<Tenant>;
 }
@@ -1,10 +1,11 @@
-use domain::{Email, UserId, Username};
+use domain::{Email, User, UserId, Username};
 
 use crate::{SubjectContex, dto::user::{command::UserCommand, view::UserView}, error::AppResult};
 
-use super::{ SortBy};
+use super::{Cursor, Page, SortBy};
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserQueryResult {
     Single(UserView),
     Array(Vec<UserView>),
@@ -27,7 +28,7 @@ impl UserQueryResult {
 }
 
 #[async_trait::async_trait]
-pub trait UserRepository {
+pub trait UserRepository: Send + Sync {
     async fn create(&self,ctx: SubjectContex, user: UserCommand) -> AppResult<UserView>;
     async fn update(&self,ctx: SubjectContex, user: UserCommand) -> AppResult<UserView>;
     async fn get_by_id(&self,ctx: SubjectContex, id: UserId) -> AppResult<UserView>;
@@ -35,5 +36,11 @@ pub trait UserRepository {
     async fn get_by_email(&self,ctx: SubjectContex, email: Email) -> AppResult<UserView>;
     async fn get_by_username(&self,ctx: SubjectContex, username: Username) -> AppResult<UserView>;
     async fn get_users_paginated(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<UserView>>;
+    /// Up to `page_size` users ordered by `sort_by`, starting after `after`;
+    /// unlike `get_users_paginated` the page holds still while rows are added.
+    async fn get_users_page(&self,ctx: SubjectContex,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<UserView>>;
+    /// SurrealQL passed through as is; there is no typed [`Query`](crate::query::Query) over users yet.
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<UserQueryResult>;
+    async fn load(&self,ctx: SubjectContex, id: UserId) -> AppResult<User>;
+    async fn save(&self,ctx: SubjectContex, user: User) -> AppResult<User>;
 }
@@ -5,3 +5,13 @@ pub mod role;
 pub mod temporary_grant;
 pub mod tenant;
 pub mod user;
+
+use std::hash::Hash;
+
+use domain::traits::field::Field;
+
+/// A field type that can list every field it names, nested ones at their
+/// leaves, so field grants given as paths can be turned into fields.
+pub trait FieldCatalog: Field + Hash {
+    fn all() -> Vec<Self>;
+}
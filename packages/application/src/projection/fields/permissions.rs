@@ -5,6 +5,7 @@ pub enum PermissionField {
     Id,
     Resource,
     Action,
+    Effect,
     Description,
     CreatedAt,
     Version,
@@ -16,6 +17,7 @@ impl Field for PermissionField {
             PermissionField::Id => "id",
             PermissionField::Resource => "resource",
             PermissionField::Action => "action",
+            PermissionField::Effect => "effect",
             PermissionField::Description => "description",
             PermissionField::CreatedAt => "crated_at",
             PermissionField::Version => "version",
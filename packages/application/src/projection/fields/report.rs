@@ -1,5 +1,7 @@
 use domain::traits::field::Field;
 
+use super::FieldCatalog;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RepotField {
     Id,
@@ -35,6 +37,14 @@ impl Field for RepotField {
             RepotField::Version => "version",
         }
     }
+
+    fn path(&self) -> String {
+        match self {
+            RepotField::Content(field) => format!("{}.{}", self.name(), field.path()),
+            RepotField::ReportType(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -67,10 +77,17 @@ impl Field for ReportContentField {
         match self {
             ReportContentField::Body => "body",
             ReportContentField::Attachments => "attachments",
-            ReportContentField::ReviewComments(_) => "review_comment",
+            ReportContentField::ReviewComments(_) => "review_comments",
             ReportContentField::RejectionReason => "rejection_reason",
         }
     }
+
+    fn path(&self) -> String {
+        match self {
+            ReportContentField::ReviewComments(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
 }
 // FIXME: Add field for tenant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -91,3 +108,57 @@ impl Field for ReportTypeField {
         }
     }
 }
+
+impl FieldCatalog for RepotField {
+    fn all() -> Vec<Self> {
+        let mut fields = vec![RepotField::Id, RepotField::Title];
+        fields.extend(ReportContentField::all().into_iter().map(RepotField::Content));
+        fields.extend(ReportTypeField::all().into_iter().map(RepotField::ReportType));
+        fields.extend([
+            RepotField::Status,
+            RepotField::AuthorId,
+            RepotField::OwnerTenant,
+            RepotField::SharedWithTenants,
+            RepotField::AssignedReviewerId,
+            RepotField::CreatedAt,
+            RepotField::UpdatedAt,
+            RepotField::DueDate,
+            RepotField::Version,
+        ]);
+        fields
+    }
+}
+
+impl FieldCatalog for ReportContentField {
+    fn all() -> Vec<Self> {
+        let mut fields = vec![ReportContentField::Body, ReportContentField::Attachments];
+        fields.extend(
+            ReviewCommentField::all()
+                .into_iter()
+                .map(ReportContentField::ReviewComments),
+        );
+        fields.push(ReportContentField::RejectionReason);
+        fields
+    }
+}
+
+impl FieldCatalog for ReviewCommentField {
+    fn all() -> Vec<Self> {
+        vec![
+            ReviewCommentField::ReviewerId,
+            ReviewCommentField::Comment,
+            ReviewCommentField::CreatedAt,
+        ]
+    }
+}
+
+impl FieldCatalog for ReportTypeField {
+    fn all() -> Vec<Self> {
+        vec![
+            ReportTypeField::Name,
+            ReportTypeField::Id,
+            ReportTypeField::Description,
+            ReportTypeField::CreatedAt,
+        ]
+    }
+}
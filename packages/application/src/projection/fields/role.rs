@@ -8,6 +8,7 @@ pub enum RoleField {
     Name,
     Description,
     Permissions(PermissionField),
+    ParentRoles,
     IsSystemRole,
     CreatedAt,
     Version,
@@ -20,9 +21,17 @@ impl Field for RoleField {
             RoleField::Name => "name",
             RoleField::Description => "description",
             RoleField::Permissions(_) => "permissions",
+            RoleField::ParentRoles => "parent_roles",
             RoleField::IsSystemRole => "is_system_role",
             RoleField::CreatedAt => "created_at",
             RoleField::Version => "version",
         }
     }
+
+    fn path(&self) -> String {
+        match self {
+            RoleField::Permissions(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
 }
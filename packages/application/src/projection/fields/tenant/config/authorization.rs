@@ -1,6 +1,26 @@
+use domain::traits::field::Field;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TenantAuthorizationConfigField {
     AllowCrossTenantAccess,
     RequireReviewerForPublish,
     DefaultRole,
+    AccessRequestApproverRole,
+    MaxAccessGrantDuration,
+}
+
+impl Field for TenantAuthorizationConfigField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantAuthorizationConfigField::AllowCrossTenantAccess => "allow_cross_tenant_access",
+            TenantAuthorizationConfigField::RequireReviewerForPublish => {
+                "require_reviewer_for_publish"
+            }
+            TenantAuthorizationConfigField::DefaultRole => "default_role",
+            TenantAuthorizationConfigField::AccessRequestApproverRole => {
+                "access_request_approver_role"
+            }
+            TenantAuthorizationConfigField::MaxAccessGrantDuration => "max_access_grant_duration",
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use domain::traits::field::Field;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TenantCollaborationConfigField {
     TrustedTenants,
@@ -5,3 +7,14 @@ pub enum TenantCollaborationConfigField {
     Federation,
     AccessLevel,
 }
+
+impl Field for TenantCollaborationConfigField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantCollaborationConfigField::TrustedTenants => "trusted_tenants",
+            TenantCollaborationConfigField::AllowedModes => "allowed_modes",
+            TenantCollaborationConfigField::Federation => "federation",
+            TenantCollaborationConfigField::AccessLevel => "access_level",
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use domain::traits::field::Field;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TenantEnvironmentConfigField {
     AllowedTimeWindow,
@@ -5,3 +7,14 @@ pub enum TenantEnvironmentConfigField {
     RequireManagedDevice,
     MaxRiskScore,
 }
+
+impl Field for TenantEnvironmentConfigField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantEnvironmentConfigField::AllowedTimeWindow => "allowed_time_window",
+            TenantEnvironmentConfigField::AllowedNetworks => "allowed_networks",
+            TenantEnvironmentConfigField::RequireManagedDevice => "require_managed_device",
+            TenantEnvironmentConfigField::MaxRiskScore => "max_risk_score",
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use domain::traits::field::Field;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TenantFeatureConfigField {
     EnabledFeatures,
@@ -5,9 +7,36 @@ pub enum TenantFeatureConfigField {
     Rollout,
 }
 
+impl Field for TenantFeatureConfigField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantFeatureConfigField::EnabledFeatures => "enabled_features",
+            TenantFeatureConfigField::Limits(_) => "limits",
+            TenantFeatureConfigField::Rollout => "rollout",
+        }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            TenantFeatureConfigField::Limits(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FeatureLimitsField {
     MaxProjects,
     MaxUsers,
     ApiRateLimit,
 }
+
+impl Field for FeatureLimitsField {
+    fn name(&self) -> &'static str {
+        match self {
+            FeatureLimitsField::MaxProjects => "max_projects",
+            FeatureLimitsField::MaxUsers => "max_users",
+            FeatureLimitsField::ApiRateLimit => "api_rate_limit",
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use authorization::TenantAuthorizationConfigField;
 use collaboration::TenantCollaborationConfigField;
+use domain::traits::field::Field;
 use environment::TenantEnvironmentConfigField;
 use feature::TenantFeatureConfigField;
 
@@ -15,3 +16,23 @@ pub enum TenantConfigField {
     Collaboration(TenantCollaborationConfigField),
     Features(TenantFeatureConfigField),
 }
+
+impl Field for TenantConfigField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantConfigField::Authorization(_) => "authorization",
+            TenantConfigField::Environment(_) => "environment",
+            TenantConfigField::Collaboration(_) => "collaboration",
+            TenantConfigField::Features(_) => "features",
+        }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            TenantConfigField::Authorization(field) => format!("{}.{}", self.name(), field.path()),
+            TenantConfigField::Environment(field) => format!("{}.{}", self.name(), field.path()),
+            TenantConfigField::Collaboration(field) => format!("{}.{}", self.name(), field.path()),
+            TenantConfigField::Features(field) => format!("{}.{}", self.name(), field.path()),
+        }
+    }
+}
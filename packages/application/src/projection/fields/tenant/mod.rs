@@ -1,13 +1,36 @@
 pub mod config;
 pub mod environment;
 use config::TenantConfigField;
+use domain::traits::field::Field;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TenantField {
     Id,
     Name,
     Description,
     CreatedAt,
     Config(TenantConfigField),
+    Status,
     Version,
 }
+
+impl Field for TenantField {
+    fn name(&self) -> &'static str {
+        match self {
+            TenantField::Id => "id",
+            TenantField::Name => "name",
+            TenantField::Description => "description",
+            TenantField::CreatedAt => "created_at",
+            TenantField::Config(_) => "config",
+            TenantField::Status => "status",
+            TenantField::Version => "version",
+        }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            TenantField::Config(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
+}
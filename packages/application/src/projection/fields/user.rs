@@ -1,5 +1,7 @@
 use domain::traits::field::Field;
 
+use super::FieldCatalog;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UserField {
     Id,
@@ -29,12 +31,21 @@ impl Field for UserField {
             UserField::Version => "version",
         }
     }
+
+    fn path(&self) -> String {
+        match self {
+            UserField::Profile(field) => format!("{}.{}", self.name(), field.path()),
+            UserField::Preferences(field) => format!("{}.{}", self.name(), field.path()),
+            _ => self.name().to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UserProfileField {
     FirstName,
     LastName,
+    Password,
     Bio,
     PhoneNumbers,
     AvatarUrl,
@@ -51,6 +62,7 @@ impl Field for UserProfileField {
         match self {
             UserProfileField::FirstName => "first_name",
             UserProfileField::LastName => "last_name",
+            UserProfileField::Password => "password",
             UserProfileField::Bio => "bio",
             UserProfileField::PhoneNumbers => "phone_numbers",
             UserProfileField::AvatarUrl => "avatar_url",
@@ -59,7 +71,7 @@ impl Field for UserProfileField {
             UserProfileField::Website => "website",
             UserProfileField::IsDeleted => "is_deleted",
             UserProfileField::CreatedAt => "created_at",
-            UserProfileField::UpdatedAt => "update_at",
+            UserProfileField::UpdatedAt => "updated_at",
         }
     }
 }
@@ -82,3 +94,49 @@ impl Field for UserPreferencesField {
         }
     }
 }
+
+impl FieldCatalog for UserField {
+    fn all() -> Vec<Self> {
+        let mut fields = vec![UserField::Id, UserField::Email, UserField::Username];
+        fields.extend(UserProfileField::all().into_iter().map(UserField::Profile));
+        fields.extend(UserPreferencesField::all().into_iter().map(UserField::Preferences));
+        fields.extend([
+            UserField::Status,
+            UserField::FailedLogins,
+            UserField::LockedUntil,
+            UserField::LastLogin,
+            UserField::Version,
+        ]);
+        fields
+    }
+}
+
+impl FieldCatalog for UserProfileField {
+    fn all() -> Vec<Self> {
+        vec![
+            UserProfileField::FirstName,
+            UserProfileField::LastName,
+            UserProfileField::Password,
+            UserProfileField::Bio,
+            UserProfileField::PhoneNumbers,
+            UserProfileField::AvatarUrl,
+            UserProfileField::DateOfBirth,
+            UserProfileField::Addressess,
+            UserProfileField::Website,
+            UserProfileField::IsDeleted,
+            UserProfileField::CreatedAt,
+            UserProfileField::UpdatedAt,
+        ]
+    }
+}
+
+impl FieldCatalog for UserPreferencesField {
+    fn all() -> Vec<Self> {
+        vec![
+            UserPreferencesField::EmailNotifications,
+            UserPreferencesField::PushNotifications,
+            UserPreferencesField::TwoFactorAuth,
+            UserPreferencesField::Language,
+        ]
+    }
+}
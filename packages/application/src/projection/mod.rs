@@ -1,5 +1,7 @@
 pub mod fields;
 pub mod projection_any;
+pub mod projector;
+pub mod relations;
 pub mod traits;
 pub mod vistor;
 
@@ -10,6 +12,7 @@ use projection_any::ProjectionAny;
 use traits::{node::ProjectionNode, projectable::Projectable, relation::Relation};
 use vistor::ProjectionVisitor;
 
+#[derive(Default)]
 pub struct Projection<E: Projectable> {
     fields: Vec<E::Field>,
     relations: HashMap<E::Relation, ProjectionAny>,
@@ -20,7 +23,7 @@ impl<P: Projectable> ProjectionNode for Projection<P> {
         visitor.enter_entity(std::any::type_name::<P>());
 
         for f in &self.fields {
-            visitor.visit_field(f.name());
+            visitor.visit_field(&f.path());
         }
 
         for (rel, proj) in &self.relations {
@@ -46,14 +49,18 @@ impl<P: Projectable> Projection<P> {
         self
     }
 
-    pub fn with<R>(mut self, relation: P::Relation, projection: Projection<R::Target>) -> Self
-    where
-        P::Relation: Relation<Target = R::Target>,
-        R: Relation,
-    {
+    pub fn with(
+        mut self,
+        relation: P::Relation,
+        projection: Projection<<P::Relation as Relation>::Target>,
+    ) -> Self {
         self.relations
             .insert(relation, ProjectionAny::new(projection));
 
         self
     }
+
+    pub fn fields(&self) -> &[P::Field] {
+        &self.fields
+    }
 }
@@ -0,0 +1,110 @@
+//! Field-level read projection. The authorization engine decides which
+//! fields a subject may read through an [`AccessControl`]; a projector turns
+//! the aggregate into its view with every other field left unset, so the
+//! domain and the repositories keep dealing in whole aggregates.
+//!
+//! Nested fields such as `RepotField::Content(ReportContentField::Body)`
+//! grant only that sub-field. A nested view is present when at least one of
+//! its sub-fields is readable.
+
+pub mod report;
+pub mod role;
+pub mod tenant;
+pub mod user;
+
+use std::{collections::HashSet, hash::Hash};
+
+use domain::traits::field::Field;
+
+use crate::{
+    authorization::access_descriptor::AccessControl,
+    error::{AppError, AppResult},
+};
+
+pub trait ReadProjector: Sized {
+    type Field: Field + Hash;
+    type View;
+
+    /// The view of `self` holding only the fields `access` lets the subject
+    /// read. Fails with [`AppError::Forbidden`] when `access` denies reading
+    /// altogether.
+    fn project(self, access: &AccessControl<Self::Field>) -> AppResult<Self::View> {
+        if !access.can_read {
+            return Err(AppError::Forbidden);
+        }
+        Ok(Self::project_fields(
+            self,
+            &ReadMask::new(&access.readable_fields),
+        ))
+    }
+
+    fn project_fields(self, mask: &ReadMask<Self::Field>) -> Self::View;
+}
+
+/// The readable fields at one level of a view.
+#[derive(Debug, Clone)]
+pub struct ReadMask<F: Field + Hash> {
+    fields: HashSet<F>,
+}
+
+impl<F: Field + Hash> ReadMask<F> {
+    pub fn new(fields: &HashSet<F>) -> Self {
+        Self {
+            fields: fields.clone(),
+        }
+    }
+
+    pub fn from_fields(fields: impl IntoIterator<Item = F>) -> Self {
+        Self {
+            fields: fields.into_iter().collect(),
+        }
+    }
+
+    pub fn allows(&self, field: F) -> bool {
+        self.fields.contains(&field)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// `value` if `field` is readable.
+    pub fn keep<T>(&self, field: F, value: Option<T>) -> Option<T> {
+        value.filter(|_| self.allows(field))
+    }
+
+    /// `values` if `field` is readable, otherwise an empty collection.
+    pub fn keep_all<C: Default>(&self, field: F, values: C) -> C {
+        if self.allows(field) {
+            values
+        } else {
+            C::default()
+        }
+    }
+
+    /// The readable sub-fields of a nested field, picked out by `nested`.
+    pub fn nested<S: Field + Hash>(&self, nested: impl Fn(F) -> Option<S>) -> ReadMask<S> {
+        ReadMask {
+            fields: self
+                .fields
+                .iter()
+                .filter_map(|field| nested(*field))
+                .collect(),
+        }
+    }
+
+    /// Projects a nested view with `project` when any of its sub-fields is
+    /// readable.
+    pub fn project_nested<S: Field + Hash, T, V>(
+        &self,
+        value: Option<T>,
+        nested: impl Fn(F) -> Option<S>,
+        project: impl FnOnce(T, &ReadMask<S>) -> V,
+    ) -> Option<V> {
+        let mask = self.nested(nested);
+        if mask.is_empty() {
+            return None;
+        }
+        value.map(|value| project(value, &mask))
+    }
+}
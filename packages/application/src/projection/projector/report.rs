@@ -0,0 +1,103 @@
+use domain::Report;
+
+use crate::{
+    dto::report::view::{ReportContentView, ReportTypeView, ReportView, ReviewCommentView},
+    projection::fields::report::{
+        ReportContentField, ReportTypeField, RepotField, ReviewCommentField,
+    },
+};
+
+use super::{ReadMask, ReadProjector};
+
+impl ReadProjector for Report {
+    type Field = RepotField;
+    type View = ReportView;
+
+    fn project_fields(self, mask: &ReadMask<RepotField>) -> ReportView {
+        ReportView::from(self).project_fields(mask)
+    }
+}
+
+/// Views read back from a repository, e.g. a page of reports, are projected
+/// the same way as the aggregate.
+impl ReadProjector for ReportView {
+    type Field = RepotField;
+    type View = ReportView;
+
+    fn project_fields(self, mask: &ReadMask<RepotField>) -> ReportView {
+        ReportView {
+            id: mask.keep(RepotField::Id, self.id),
+            title: mask.keep(RepotField::Title, self.title),
+            content: mask.project_nested(
+                self.content,
+                |field| match field {
+                    RepotField::Content(field) => Some(field),
+                    _ => None,
+                },
+                project_content,
+            ),
+            report_type: mask.project_nested(
+                self.report_type,
+                |field| match field {
+                    RepotField::ReportType(field) => Some(field),
+                    _ => None,
+                },
+                project_report_type,
+            ),
+            status: mask.keep(RepotField::Status, self.status),
+            author_id: mask.keep(RepotField::AuthorId, self.author_id),
+            owner_tenant: mask.keep(RepotField::OwnerTenant, self.owner_tenant),
+            shared_with_tenants: mask
+                .keep_all(RepotField::SharedWithTenants, self.shared_with_tenants),
+            assigned_reviewer_id: mask
+                .keep_all(RepotField::AssignedReviewerId, self.assigned_reviewer_id),
+            created_at: mask.keep(RepotField::CreatedAt, self.created_at),
+            updated_at: mask.keep(RepotField::UpdatedAt, self.updated_at),
+            due_date: mask.keep(RepotField::DueDate, self.due_date),
+            version: mask.keep(RepotField::Version, self.version),
+        }
+    }
+}
+
+fn project_content(
+    view: ReportContentView,
+    mask: &ReadMask<ReportContentField>,
+) -> ReportContentView {
+    let comments = mask.nested(|field| match field {
+        ReportContentField::ReviewComments(field) => Some(field),
+        _ => None,
+    });
+    ReportContentView {
+        body: mask.keep(ReportContentField::Body, view.body),
+        attachments: mask.keep(ReportContentField::Attachments, view.attachments),
+        review_comments: view.review_comments.filter(|_| !comments.is_empty()).map(
+            |review_comments| {
+                review_comments
+                    .into_iter()
+                    .map(|comment| project_review_comment(comment, &comments))
+                    .collect()
+            },
+        ),
+        rejection_reason: mask.keep(ReportContentField::RejectionReason, view.rejection_reason),
+    }
+}
+
+fn project_review_comment(
+    view: ReviewCommentView,
+    mask: &ReadMask<ReviewCommentField>,
+) -> ReviewCommentView {
+    ReviewCommentView {
+        reviewer_id: mask.keep(ReviewCommentField::ReviewerId, view.reviewer_id),
+        comment: mask.keep(ReviewCommentField::Comment, view.comment),
+        created_at: mask.keep(ReviewCommentField::CreatedAt, view.created_at),
+    }
+}
+
+fn project_report_type(view: ReportTypeView, mask: &ReadMask<ReportTypeField>) -> ReportTypeView {
+    ReportTypeView {
+        id: mask.keep(ReportTypeField::Id, view.id),
+        name: mask.keep(ReportTypeField::Name, view.name),
+        description: mask.keep(ReportTypeField::Description, view.description),
+        created_at: mask.keep(ReportTypeField::CreatedAt, view.created_at),
+    }
+}
@@ -0,0 +1,48 @@
+use domain::Role;
+
+use crate::{
+    dto::role::view::{PermissionView, RoleView},
+    projection::fields::{permissions::PermissionField, role::RoleField},
+};
+
+use super::{ReadMask, ReadProjector};
+
+impl ReadProjector for Role {
+    type Field = RoleField;
+    type View = RoleView;
+
+    fn project_fields(self, mask: &ReadMask<RoleField>) -> RoleView {
+        let view = RoleView::from(self);
+        let permissions = mask.nested(|field| match field {
+            RoleField::Permissions(field) => Some(field),
+            _ => None,
+        });
+        RoleView {
+            id: mask.keep(RoleField::Id, view.id),
+            name: mask.keep(RoleField::Name, view.name),
+            description: mask.keep(RoleField::Description, view.description),
+            permissions: view
+                .permissions
+                .into_iter()
+                .filter(|_| !permissions.is_empty())
+                .map(|permission| project_permission(permission, &permissions))
+                .collect(),
+            parent_roles: mask.keep_all(RoleField::ParentRoles, view.parent_roles),
+            is_system_role: mask.keep(RoleField::IsSystemRole, view.is_system_role),
+            created_at: mask.keep(RoleField::CreatedAt, view.created_at),
+            version: mask.keep(RoleField::Version, view.version),
+        }
+    }
+}
+
+fn project_permission(view: PermissionView, mask: &ReadMask<PermissionField>) -> PermissionView {
+    PermissionView {
+        id: mask.keep(PermissionField::Id, view.id),
+        resource: mask.keep(PermissionField::Resource, view.resource),
+        action: mask.keep(PermissionField::Action, view.action),
+        effect: mask.keep(PermissionField::Effect, view.effect),
+        description: mask.keep(PermissionField::Description, view.description),
+        created_at: mask.keep(PermissionField::CreatedAt, view.created_at),
+        version: mask.keep(PermissionField::Version, view.version),
+    }
+}
@@ -0,0 +1,193 @@
+use domain::Tenant;
+
+use crate::{
+    dto::tenant::view::{
+        config::{
+            authorization::TenantAuthorizationConfigView,
+            collaboration::TenantCollaborationConfigView,
+            environment::TenantEnvironmentConfigView,
+            feature::{FeatureLimitsView, TenantFeatureConfigView},
+            TenantConfigView,
+        },
+        TenantView,
+    },
+    projection::fields::tenant::{
+        config::{
+            authorization::TenantAuthorizationConfigField,
+            collaboration::TenantCollaborationConfigField,
+            environment::TenantEnvironmentConfigField,
+            feature::{FeatureLimitsField, TenantFeatureConfigField},
+            TenantConfigField,
+        },
+        TenantField,
+    },
+};
+
+use super::{ReadMask, ReadProjector};
+
+impl ReadProjector for Tenant {
+    type Field = TenantField;
+    type View = TenantView;
+
+    fn project_fields(self, mask: &ReadMask<TenantField>) -> TenantView {
+        let view = TenantView::from(self);
+        TenantView {
+            id: mask.keep(TenantField::Id, view.id),
+            name: mask.keep(TenantField::Name, view.name),
+            description: mask.keep(TenantField::Description, view.description),
+            created_at: mask.keep(TenantField::CreatedAt, view.created_at),
+            config: mask.project_nested(
+                view.config,
+                |field| match field {
+                    TenantField::Config(field) => Some(field),
+                    _ => None,
+                },
+                project_config,
+            ),
+            status: mask.keep(TenantField::Status, view.status),
+            version: mask.keep(TenantField::Version, view.version),
+        }
+    }
+}
+
+fn project_config(view: TenantConfigView, mask: &ReadMask<TenantConfigField>) -> TenantConfigView {
+    TenantConfigView {
+        authorization: mask.project_nested(
+            view.authorization,
+            |field| match field {
+                TenantConfigField::Authorization(field) => Some(field),
+                _ => None,
+            },
+            project_authorization,
+        ),
+        environment: mask.project_nested(
+            view.environment,
+            |field| match field {
+                TenantConfigField::Environment(field) => Some(field),
+                _ => None,
+            },
+            project_environment,
+        ),
+        collaboration: mask.project_nested(
+            view.collaboration,
+            |field| match field {
+                TenantConfigField::Collaboration(field) => Some(field),
+                _ => None,
+            },
+            project_collaboration,
+        ),
+        features: mask.project_nested(
+            view.features,
+            |field| match field {
+                TenantConfigField::Features(field) => Some(field),
+                _ => None,
+            },
+            project_features,
+        ),
+    }
+}
+
+fn project_authorization(
+    view: TenantAuthorizationConfigView,
+    mask: &ReadMask<TenantAuthorizationConfigField>,
+) -> TenantAuthorizationConfigView {
+    TenantAuthorizationConfigView {
+        allow_cross_tenant_access: mask.keep(
+            TenantAuthorizationConfigField::AllowCrossTenantAccess,
+            view.allow_cross_tenant_access,
+        ),
+        require_reviewer_for_publish: mask.keep(
+            TenantAuthorizationConfigField::RequireReviewerForPublish,
+            view.require_reviewer_for_publish,
+        ),
+        default_role: mask.keep(
+            TenantAuthorizationConfigField::DefaultRole,
+            view.default_role,
+        ),
+        access_request_approver_role: mask.keep(
+            TenantAuthorizationConfigField::AccessRequestApproverRole,
+            view.access_request_approver_role,
+        ),
+        max_access_grant_duration: mask.keep(
+            TenantAuthorizationConfigField::MaxAccessGrantDuration,
+            view.max_access_grant_duration,
+        ),
+    }
+}
+
+fn project_environment(
+    view: TenantEnvironmentConfigView,
+    mask: &ReadMask<TenantEnvironmentConfigField>,
+) -> TenantEnvironmentConfigView {
+    TenantEnvironmentConfigView {
+        allowed_time_window: mask.keep(
+            TenantEnvironmentConfigField::AllowedTimeWindow,
+            view.allowed_time_window,
+        ),
+        allowed_networks: mask.keep(
+            TenantEnvironmentConfigField::AllowedNetworks,
+            view.allowed_networks,
+        ),
+        require_managed_device: mask.keep(
+            TenantEnvironmentConfigField::RequireManagedDevice,
+            view.require_managed_device,
+        ),
+        max_risk_score: mask.keep(
+            TenantEnvironmentConfigField::MaxRiskScore,
+            view.max_risk_score,
+        ),
+    }
+}
+
+fn project_collaboration(
+    view: TenantCollaborationConfigView,
+    mask: &ReadMask<TenantCollaborationConfigField>,
+) -> TenantCollaborationConfigView {
+    TenantCollaborationConfigView {
+        trusted_tenants: mask.keep(
+            TenantCollaborationConfigField::TrustedTenants,
+            view.trusted_tenants,
+        ),
+        allowed_modes: mask.keep(
+            TenantCollaborationConfigField::AllowedModes,
+            view.allowed_modes,
+        ),
+        federation: mask.keep(TenantCollaborationConfigField::Federation, view.federation),
+        access_level: mask.keep(
+            TenantCollaborationConfigField::AccessLevel,
+            view.access_level,
+        ),
+    }
+}
+
+fn project_features(
+    view: TenantFeatureConfigView,
+    mask: &ReadMask<TenantFeatureConfigField>,
+) -> TenantFeatureConfigView {
+    TenantFeatureConfigView {
+        enabled_features: mask.keep(
+            TenantFeatureConfigField::EnabledFeatures,
+            view.enabled_features,
+        ),
+        limits: mask.project_nested(
+            view.limits,
+            |field| match field {
+                TenantFeatureConfigField::Limits(field) => Some(field),
+                _ => None,
+            },
+            project_limits,
+        ),
+        rollout: mask.keep(TenantFeatureConfigField::Rollout, view.rollout),
+    }
+}
+
+fn project_limits(
+    view: FeatureLimitsView,
+    mask: &ReadMask<FeatureLimitsField>,
+) -> FeatureLimitsView {
+    FeatureLimitsView {
+        max_projects: mask.keep(FeatureLimitsField::MaxProjects, view.max_projects),
+        max_users: mask.keep(FeatureLimitsField::MaxUsers, view.max_users),
+        api_rate_limit: mask.keep(FeatureLimitsField::ApiRateLimit, view.api_rate_limit),
+    }
+}
@@ -0,0 +1,87 @@
+use domain::User;
+
+use crate::{
+    dto::user::view::{UserPreferencesView, UserProfileView, UserView},
+    projection::fields::user::{UserField, UserPreferencesField, UserProfileField},
+};
+
+use super::{ReadMask, ReadProjector};
+
+impl ReadProjector for User {
+    type Field = UserField;
+    type View = UserView;
+
+    fn project_fields(self, mask: &ReadMask<UserField>) -> UserView {
+        UserView::from(self).project_fields(mask)
+    }
+}
+
+/// Views read back from a repository, e.g. a user looked up by email, are
+/// projected the same way as the aggregate.
+impl ReadProjector for UserView {
+    type Field = UserField;
+    type View = UserView;
+
+    fn project_fields(self, mask: &ReadMask<UserField>) -> UserView {
+        UserView {
+            id: mask.keep(UserField::Id, self.id),
+            email: mask.keep(UserField::Email, self.email),
+            username: mask.keep(UserField::Username, self.username),
+            profile: mask.project_nested(
+                self.profile,
+                |field| match field {
+                    UserField::Profile(field) => Some(field),
+                    _ => None,
+                },
+                project_profile,
+            ),
+            preferences: mask.project_nested(
+                self.preferences,
+                |field| match field {
+                    UserField::Preferences(field) => Some(field),
+                    _ => None,
+                },
+                project_preferences,
+            ),
+            status: mask.keep(UserField::Status, self.status),
+            failed_logins: mask.keep(UserField::FailedLogins, self.failed_logins),
+            locked_until: mask.keep(UserField::LockedUntil, self.locked_until),
+            last_login: mask.keep(UserField::LastLogin, self.last_login),
+            version: mask.keep(UserField::Version, self.version),
+        }
+    }
+}
+
+fn project_profile(view: UserProfileView, mask: &ReadMask<UserProfileField>) -> UserProfileView {
+    UserProfileView {
+        first_name: mask.keep(UserProfileField::FirstName, view.first_name),
+        last_name: mask.keep(UserProfileField::LastName, view.last_name),
+        bio: mask.keep(UserProfileField::Bio, view.bio),
+        phone_numbers: mask.keep(UserProfileField::PhoneNumbers, view.phone_numbers),
+        avatar_url: mask.keep(UserProfileField::AvatarUrl, view.avatar_url),
+        date_of_birth: mask.keep(UserProfileField::DateOfBirth, view.date_of_birth),
+        addressess: mask.keep(UserProfileField::Addressess, view.addressess),
+        website: mask.keep(UserProfileField::Website, view.website),
+        is_deleted: mask.keep(UserProfileField::IsDeleted, view.is_deleted),
+        created_at: mask.keep(UserProfileField::CreatedAt, view.created_at),
+        updated_at: mask.keep(UserProfileField::UpdatedAt, view.updated_at),
+    }
+}
+
+fn project_preferences(
+    view: UserPreferencesView,
+    mask: &ReadMask<UserPreferencesField>,
+) -> UserPreferencesView {
+    UserPreferencesView {
+        email_notifications: mask.keep(
+            UserPreferencesField::EmailNotifications,
+            view.email_notifications,
+        ),
+        push_notifications: mask.keep(
+            UserPreferencesField::PushNotifications,
+            view.push_notifications,
+        ),
+        two_factor_auth: mask.keep(UserPreferencesField::TwoFactorAuth, view.two_factor_auth),
+        language: mask.keep(UserPreferencesField::Language, view.language),
+    }
+}
@@ -0,0 +1,48 @@
+//! Record links between projectable entities, followed when a projection
+//! selects fields of the linked entity.
+
+use domain::{Report, User};
+
+use super::{
+    fields::{report::RepotField, user::UserField},
+    traits::{projectable::Projectable, relation::Relation},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportRelation {
+    Author,
+    AssignedReviewers,
+}
+
+impl Relation for ReportRelation {
+    type Target = User;
+
+    fn name(&self) -> &'static str {
+        match self {
+            ReportRelation::Author => "author_id",
+            ReportRelation::AssignedReviewers => "assigned_reviewer_id",
+        }
+    }
+}
+
+impl Projectable for Report {
+    type Field = RepotField;
+    type Relation = ReportRelation;
+}
+
+/// Users link to no other entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserRelation {}
+
+impl Relation for UserRelation {
+    type Target = User;
+
+    fn name(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+impl Projectable for User {
+    type Field = UserField;
+    type Relation = UserRelation;
+}
@@ -0,0 +1,194 @@
+//! Engine-agnostic query tree. Nothing here knows how a database spells it.
+
+use domain::{DateTime, ReportId, ReportStatus, TenantId, UserId};
+use serde::{Serialize, Serializer};
+
+use super::schema::FieldPath;
+
+/// A value compared against a field, always sent as a bound parameter.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    /// Sent as seconds since the Unix epoch; adapters turn it back into their
+    /// engine's datetime type.
+    #[serde(serialize_with = "epoch_seconds")]
+    DateTime(DateTime),
+    List(Vec<Value>),
+}
+
+fn epoch_seconds<S: Serializer>(value: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(*value.datetime())
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Int(i64::from(value))
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Int(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<DateTime> for Value {
+    fn from(value: DateTime) -> Self {
+        Value::DateTime(value)
+    }
+}
+
+impl From<&ReportId> for Value {
+    fn from(value: &ReportId) -> Self {
+        Value::String(value.as_str().to_string())
+    }
+}
+
+impl From<&UserId> for Value {
+    fn from(value: &UserId) -> Self {
+        Value::String(value.as_str().to_string())
+    }
+}
+
+impl From<&TenantId> for Value {
+    fn from(value: &TenantId) -> Self {
+        Value::String(value.as_str().to_string())
+    }
+}
+
+impl From<ReportStatus> for Value {
+    fn from(value: ReportStatus) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Compare {
+        field: FieldPath,
+        op: Comparison,
+        value: Value,
+    },
+    /// The field equals one of `values`.
+    In {
+        field: FieldPath,
+        values: Vec<Value>,
+    },
+    /// The array at the field holds `value`, or the string at the field
+    /// contains it.
+    Contains {
+        field: FieldPath,
+        value: Value,
+    },
+    /// True when empty.
+    And(Vec<Expr>),
+    /// False when empty.
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort {
+    pub field: FieldPath,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectAst {
+    pub table: String,
+    /// Every field when empty.
+    pub fields: Vec<FieldPath>,
+    pub filter: Option<Expr>,
+    pub order_by: Vec<Sort>,
+    pub limit: Option<u64>,
+    pub start: Option<u64>,
+}
+
+impl SelectAst {
+    pub fn new(table: &str) -> Self {
+        Self {
+            table: table.to_string(),
+            fields: Vec::new(),
+            filter: None,
+            order_by: Vec::new(),
+            limit: None,
+            start: None,
+        }
+    }
+
+    /// Narrows the rows down to those `filter` also holds for.
+    pub fn restrict(&mut self, filter: Expr) {
+        self.filter = Some(match self.filter.take() {
+            Some(Expr::And(mut exprs)) => {
+                exprs.push(filter);
+                Expr::And(exprs)
+            }
+            Some(current) => Expr::And(vec![current, filter]),
+            None => filter,
+        });
+    }
+}
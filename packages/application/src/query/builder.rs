@@ -0,0 +1,206 @@
+//! Builds a [`SelectAst`] out of an entity's own `Field` enums, so a query
+//! can only name fields the entity has.
+
+use std::{
+    marker::PhantomData,
+    ops::{Bound, Not, RangeBounds},
+};
+
+use domain::traits::field::Field;
+
+use super::{
+    ast::{Comparison, Direction, Expr, SelectAst, Sort, Value},
+    schema::{FieldPath, Queryable},
+};
+
+/// A condition on the fields of `F`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter<F: Field> {
+    expr: Expr,
+    field: PhantomData<F>,
+}
+
+impl<F: Field> Filter<F> {
+    fn new(expr: Expr) -> Self {
+        Self {
+            expr,
+            field: PhantomData,
+        }
+    }
+
+    fn compare(field: F, op: Comparison, value: impl Into<Value>) -> Self {
+        Self::new(Expr::Compare {
+            field: FieldPath::of(field),
+            op,
+            value: value.into(),
+        })
+    }
+
+    pub fn eq(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Eq, value)
+    }
+
+    pub fn ne(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Ne, value)
+    }
+
+    pub fn lt(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Lt, value)
+    }
+
+    pub fn le(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Le, value)
+    }
+
+    pub fn gt(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Gt, value)
+    }
+
+    pub fn ge(field: F, value: impl Into<Value>) -> Self {
+        Self::compare(field, Comparison::Ge, value)
+    }
+
+    /// The field lies within `range`, e.g. `created_at` in `from..to`.
+    pub fn range<V: Into<Value> + Clone>(field: F, range: impl RangeBounds<V>) -> Self {
+        let lower = match range.start_bound() {
+            Bound::Included(value) => Some(Self::ge(field, value.clone())),
+            Bound::Excluded(value) => Some(Self::gt(field, value.clone())),
+            Bound::Unbounded => None,
+        };
+        let upper = match range.end_bound() {
+            Bound::Included(value) => Some(Self::le(field, value.clone())),
+            Bound::Excluded(value) => Some(Self::lt(field, value.clone())),
+            Bound::Unbounded => None,
+        };
+        Self::all(lower.into_iter().chain(upper))
+    }
+
+    pub fn is_in<V: Into<Value>>(field: F, values: impl IntoIterator<Item = V>) -> Self {
+        Self::new(Expr::In {
+            field: FieldPath::of(field),
+            values: values.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    pub fn contains(field: F, value: impl Into<Value>) -> Self {
+        Self::new(Expr::Contains {
+            field: FieldPath::of(field),
+            value: value.into(),
+        })
+    }
+
+    /// Every filter holds; true when there are none.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        Self::new(Expr::And(
+            filters.into_iter().map(Self::into_expr).collect(),
+        ))
+    }
+
+    /// Some filter holds; false when there are none.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Self {
+        Self::new(Expr::Or(filters.into_iter().map(Self::into_expr).collect()))
+    }
+
+    pub fn and(self, other: Self) -> Self {
+        match self.expr {
+            Expr::And(mut exprs) => {
+                exprs.push(other.expr);
+                Self::new(Expr::And(exprs))
+            }
+            expr => Self::new(Expr::And(vec![expr, other.expr])),
+        }
+    }
+
+    pub fn or(self, other: Self) -> Self {
+        match self.expr {
+            Expr::Or(mut exprs) => {
+                exprs.push(other.expr);
+                Self::new(Expr::Or(exprs))
+            }
+            expr => Self::new(Expr::Or(vec![expr, other.expr])),
+        }
+    }
+
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    pub fn into_expr(self) -> Expr {
+        self.expr
+    }
+}
+
+impl<F: Field> Not for Filter<F> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::new(Expr::Not(Box::new(self.expr)))
+    }
+}
+
+/// A select over `E`'s table. The selected fields are kept typed as well, so
+/// adapters that do not compile the AST can still mask what they return.
+pub struct Query<E: Queryable> {
+    ast: SelectAst,
+    fields: Vec<E::Field>,
+}
+
+impl<E: Queryable> Default for Query<E> {
+    fn default() -> Self {
+        Self::select()
+    }
+}
+
+impl<E: Queryable> Query<E> {
+    /// Every field of every row until narrowed down.
+    pub fn select() -> Self {
+        Self {
+            ast: SelectAst::new(E::TABLE),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn field(mut self, field: E::Field) -> Self {
+        self.ast.fields.push(FieldPath::of(field));
+        self.fields.push(field);
+        self
+    }
+
+    /// Further filters are combined with the ones already set.
+    pub fn filter(mut self, filter: Filter<E::Field>) -> Self {
+        self.ast.restrict(filter.into_expr());
+        self
+    }
+
+    pub fn order_by(mut self, field: E::Field, direction: Direction) -> Self {
+        self.ast.order_by.push(Sort {
+            field: FieldPath::of(field),
+            direction,
+        });
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.ast.limit = Some(limit);
+        self
+    }
+
+    pub fn start(mut self, start: u64) -> Self {
+        self.ast.start = Some(start);
+        self
+    }
+
+    /// Page `page` of `page_size` rows, counting from 0.
+    pub fn page(self, page: u32, page_size: u32) -> Self {
+        self.limit(u64::from(page_size))
+            .start(u64::from(page) * u64::from(page_size))
+    }
+
+    pub fn ast(&self) -> &SelectAst {
+        &self.ast
+    }
+
+    pub fn fields(&self) -> &[E::Field] {
+        &self.fields
+    }
+}
@@ -0,0 +1,12 @@
+use super::ast::{SelectAst, Value};
+
+/// Query text for one engine, with the values it refers to by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledQuery {
+    pub text: String,
+    pub binds: Vec<(String, Value)>,
+}
+
+pub trait QueryCompiler {
+    fn compile(&self, query: &SelectAst) -> CompiledQuery;
+}
@@ -0,0 +1,22 @@
+//! Typed queries, replacing hand-written `raw_query` strings.
+//!
+//! The layers stay apart: [`schema`] turns an entity's `Field` enums into
+//! field paths, [`builder`] assembles an engine-agnostic [`ast`] from them,
+//! and each database adapter implements [`compiler::QueryCompiler`] for its
+//! own dialect. Values are always bound as parameters, so nothing a caller
+//! passes ever becomes query text.
+//!
+//! Only [`ReportRepository::query`](crate::ports::ReportRepository::query)
+//! takes typed queries so far, and adapters add the subject's tenant to
+//! them. The user, role and tenant ports still only offer `raw_query`: roles
+//! and tenants have no `Field` enums to name their columns with yet.
+
+pub mod ast;
+pub mod builder;
+pub mod compiler;
+pub mod schema;
+
+pub use ast::{Comparison, Direction, Expr, SelectAst, Sort, Value};
+pub use builder::{Filter, Query};
+pub use compiler::{CompiledQuery, QueryCompiler};
+pub use schema::{FieldPath, PathSegment, Queryable};
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use domain::{traits::field::Field, Report, User};
+
+use crate::projection::traits::projectable::Projectable;
+
+/// An entity stored in its own table, whose `Field` enum names the columns a
+/// query may select, filter and sort by.
+pub trait Queryable: Projectable {
+    const TABLE: &'static str;
+}
+
+impl Queryable for Report {
+    const TABLE: &'static str = "report";
+}
+
+impl Queryable for User {
+    const TABLE: &'static str = "user";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Path from the entity root to a field, e.g. `content.body`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    pub fn of<F: Field>(field: F) -> Self {
+        Self(
+            field
+                .path()
+                .split('.')
+                .map(|segment| PathSegment::Field(segment.to_string()))
+                .collect(),
+        )
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Only the `index`th element of the array at this path.
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Every element of the array at this path.
+    pub fn each(mut self) -> Self {
+        self.0.push(PathSegment::Wildcard);
+        self
+    }
+}
+
+/// `content.review_comments[*].comment`
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if position == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Wildcard => write!(f, "[*]")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+//! Per-tenant API rate limiting. Use cases marked
+//! [`UseCaseDescriptor::RATE_LIMITED`](crate::usecases::usecase_discriptor::UseCaseDescriptor::RATE_LIMITED)
+//! spend a token from the subject's tenant bucket once they are authorized;
+//! the bucket holds `FeatureLimits::api_rate_limit` tokens and refills at
+//! that many per minute. A limit of 0 means unlimited.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use domain::{DateTime, Tenant, TenantId, UserId};
+
+use crate::{
+    error::{AppError, AppResult},
+    SubjectContex,
+};
+
+/// Seconds over which a full bucket refills.
+pub const DEFAULT_WINDOW: i64 = 60;
+
+/// Whether requests are counted per tenant or per user within a tenant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RateLimitScope {
+    #[default]
+    Tenant,
+    TenantUser,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RateLimitKey {
+    pub tenant_id: TenantId,
+    pub user_id: Option<UserId>,
+}
+
+pub trait RateLimiter: Send + Sync {
+    /// Spends one request from `key`'s budget of `limit` per window, or
+    /// fails with [`AppError::RateLimited`] when the budget is exhausted.
+    fn acquire(&self, key: &RateLimitKey, limit: u32, now: DateTime) -> AppResult<()>;
+
+    fn scope(&self) -> RateLimitScope {
+        RateLimitScope::Tenant
+    }
+
+    /// Charges `subject`'s request against the limit of `tenant`, the
+    /// subject's own tenant. Tenants setting a limit of 0 are not limited.
+    fn check(&self, subject: &SubjectContex, tenant: &Tenant, now: DateTime) -> AppResult<()> {
+        let limit = *tenant.config().features().limits().api_rate_limit();
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let key = RateLimitKey {
+            tenant_id: tenant.id().clone(),
+            user_id: match self.scope() {
+                RateLimitScope::Tenant => None,
+                RateLimitScope::TenantUser => Some(subject.user_id()),
+            },
+        };
+        self.acquire(&key, limit, now)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: DateTime,
+}
+
+/// An in-process token bucket per key. Buckets live as long as the limiter,
+/// so deployments with several instances should share a limiter backed by a
+/// common store instead.
+#[derive(Debug)]
+pub struct TokenBucketRateLimiter {
+    scope: RateLimitScope,
+    window: i64,
+    buckets: Mutex<HashMap<RateLimitKey, Bucket>>,
+}
+
+impl TokenBucketRateLimiter {
+    pub fn new(scope: RateLimitScope) -> Self {
+        Self {
+            scope,
+            window: DEFAULT_WINDOW,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Window in seconds.
+    pub fn with_window(mut self, window: i64) -> Self {
+        self.window = window.max(1);
+        self
+    }
+}
+
+impl Default for TokenBucketRateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitScope::default())
+    }
+}
+
+impl RateLimiter for TokenBucketRateLimiter {
+    fn acquire(&self, key: &RateLimitKey, limit: u32, now: DateTime) -> AppResult<()> {
+        let capacity = f64::from(limit);
+        let per_second = capacity / self.window as f64;
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(key.clone()).or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+
+        let elapsed = (now.datetime() - bucket.refilled_at.datetime()).max(0);
+        bucket.tokens = (bucket.tokens + elapsed as f64 * per_second).min(capacity);
+        bucket.refilled_at = bucket.refilled_at.max(now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        let retry_after = ((1.0 - bucket.tokens) / per_second).ceil() as u64;
+        Err(AppError::RateLimited {
+            retry_after: retry_after.max(1),
+        })
+    }
+
+    fn scope(&self) -> RateLimitScope {
+        self.scope
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::tenant::config::{
+        feature::{FeatureLimits, FeatureRolloutPolicy, TenantFeatureConfig},
+        TenantConfig,
+    };
+
+    use super::*;
+    use crate::fixtures::{
+        authorization_config, collaboration_config, environment_config, subject, tenant,
+    };
+
+    fn key(tenant: &str) -> RateLimitKey {
+        RateLimitKey {
+            tenant_id: TenantId::new(tenant),
+            user_id: None,
+        }
+    }
+
+    fn limited(id: &str, api_rate_limit: u32) -> Tenant {
+        let features = TenantFeatureConfig::new(
+            Vec::new(),
+            FeatureLimits::new(10, 10, api_rate_limit),
+            FeatureRolloutPolicy::Stable,
+        )
+        .unwrap();
+        tenant(
+            id,
+            TenantConfig::new(
+                authorization_config(),
+                environment_config(),
+                collaboration_config(),
+                features,
+            ),
+        )
+    }
+
+    fn retry_after(result: AppResult<()>) -> u64 {
+        match result {
+            Err(AppError::RateLimited { retry_after }) => retry_after,
+            other => panic!("expected a rate limit, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn a_full_bucket_allows_a_burst_of_its_capacity() {
+        let limiter = TokenBucketRateLimiter::default();
+        for _ in 0..3 {
+            assert!(limiter.acquire(&key("acme"), 3, DateTime::new(0)).is_ok());
+        }
+        // Three per minute refill one token every 20 seconds.
+        assert_eq!(
+            retry_after(limiter.acquire(&key("acme"), 3, DateTime::new(0))),
+            20
+        );
+    }
+
+    #[test]
+    fn buckets_refill_over_the_window_up_to_their_capacity() {
+        let limiter = TokenBucketRateLimiter::default().with_window(3);
+        for _ in 0..3 {
+            limiter.acquire(&key("acme"), 3, DateTime::new(0)).unwrap();
+        }
+        assert_eq!(
+            retry_after(limiter.acquire(&key("acme"), 3, DateTime::new(0))),
+            1
+        );
+        assert!(limiter.acquire(&key("acme"), 3, DateTime::new(1)).is_ok());
+        assert!(limiter.acquire(&key("acme"), 3, DateTime::new(1)).is_err());
+
+        for _ in 0..3 {
+            assert!(limiter.acquire(&key("acme"), 3, DateTime::new(100)).is_ok());
+        }
+        assert!(limiter
+            .acquire(&key("acme"), 3, DateTime::new(100))
+            .is_err());
+    }
+
+    #[test]
+    fn subjects_spend_from_their_own_bucket() {
+        let acme = limited("acme", 1);
+        let alice = subject("alice", "acme");
+        let bob = subject("bob", "acme");
+        let now = DateTime::new(0);
+
+        let per_user = TokenBucketRateLimiter::new(RateLimitScope::TenantUser);
+        assert!(per_user.check(&alice, &acme, now).is_ok());
+        assert!(per_user.check(&alice, &acme, now).is_err());
+        assert!(per_user.check(&bob, &acme, now).is_ok());
+
+        let per_tenant = TokenBucketRateLimiter::new(RateLimitScope::Tenant);
+        assert!(per_tenant.check(&alice, &acme, now).is_ok());
+        assert!(per_tenant.check(&bob, &acme, now).is_err());
+        let globex = limited("globex", 1);
+        assert!(per_tenant
+            .check(&subject("carol", "globex"), &globex, now)
+            .is_ok());
+    }
+
+    #[test]
+    fn a_limit_of_zero_is_unlimited() {
+        let limiter = TokenBucketRateLimiter::default();
+        let unlimited = limited("acme", 0);
+        for _ in 0..100 {
+            assert!(limiter
+                .check(&subject("alice", "acme"), &unlimited, DateTime::new(0))
+                .is_ok());
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{AccessRequest, AccessRequestId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::access_request::view::AccessRequestView,
+    error::AppResult,
+    ports::{AccessRequestRepository, TemporaryGrantRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::check_approver;
+
+/// Approves an access request and stores the temporary grant it issues,
+/// bounded by the tenant's `max_access_grant_duration`.
+pub struct ApproveAccessRequestUseCase {
+    repo: Arc<dyn AccessRequestRepository>,
+    grants: Arc<dyn TemporaryGrantRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ApproveAccessRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn AccessRequestRepository>,
+        grants: Arc<dyn TemporaryGrantRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            grants,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ApproveAccessRequestUseCase {
+    type Input = AccessRequestId;
+    type Resource = AccessRequest;
+    type Output = AccessRequestView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        id: &AccessRequestId,
+    ) -> AppResult<AccessRequest> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: AccessRequestId,
+        request: Authorized<AccessRequest>,
+    ) -> AppResult<AccessRequestView> {
+        let (mut request, tenant) = request.into_parts()?;
+        check_approver(&tenant, &ctx)?;
+
+        let grant = request.approve(
+            tenant.config().authorization().max_access_grant_duration(),
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        let request = self.repo.save(ctx.clone(), request).await?;
+        self.grants.save(ctx, grant).await?;
+        Ok(request.into())
+    }
+}
+
+impl UseCaseDescriptor for ApproveAccessRequestUseCase {
+    const NAME: &'static str = "approve_access_request";
+
+    const RESOURCE: &'static str = "access_request";
+
+    const ACTION: &'static str = "approve";
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{AccessRequest, AccessRequestId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::access_request::view::AccessRequestView,
+    error::AppResult,
+    ports::{AccessRequestRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Withdraws a pending access request; only its requester may.
+pub struct CancelAccessRequestUseCase {
+    repo: Arc<dyn AccessRequestRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl CancelAccessRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn AccessRequestRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for CancelAccessRequestUseCase {
+    type Input = AccessRequestId;
+    type Resource = AccessRequest;
+    type Output = AccessRequestView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        id: &AccessRequestId,
+    ) -> AppResult<AccessRequest> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: AccessRequestId,
+        request: Authorized<AccessRequest>,
+    ) -> AppResult<AccessRequestView> {
+        let mut request = request.into_inner();
+        request.cancel(&ctx.user_id(), *environment.time().timestamp())?;
+        Ok(self.repo.save(ctx, request).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for CancelAccessRequestUseCase {
+    const NAME: &'static str = "cancel_access_request";
+
+    const RESOURCE: &'static str = "access_request";
+
+    const ACTION: &'static str = "cancel";
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{
+    AccessRequest, AccessRequestId, tenant::environment::Environment, value_objects::Comment,
+};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::access_request::view::AccessRequestView,
+    error::AppResult,
+    ports::{AccessRequestRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::check_approver;
+
+#[derive(Debug, Clone)]
+pub struct DenyAccessRequestInput {
+    pub id: AccessRequestId,
+    pub reason: Comment,
+}
+
+pub struct DenyAccessRequestUseCase {
+    repo: Arc<dyn AccessRequestRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl DenyAccessRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn AccessRequestRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for DenyAccessRequestUseCase {
+    type Input = DenyAccessRequestInput;
+    type Resource = AccessRequest;
+    type Output = AccessRequestView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        input: &DenyAccessRequestInput,
+    ) -> AppResult<AccessRequest> {
+        self.repo.load(ctx.clone(), input.id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: DenyAccessRequestInput,
+        request: Authorized<AccessRequest>,
+    ) -> AppResult<AccessRequestView> {
+        let (mut request, tenant) = request.into_parts()?;
+        check_approver(&tenant, &ctx)?;
+
+        request.deny(
+            input.reason,
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, request).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for DenyAccessRequestUseCase {
+    const NAME: &'static str = "deny_access_request";
+
+    const RESOURCE: &'static str = "access_request";
+
+    const ACTION: &'static str = "deny";
+}
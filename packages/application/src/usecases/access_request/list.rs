@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{AccessRequestStatus, Tenant, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::access_request::view::AccessRequestView,
+    error::AppResult,
+    ports::{AccessRequestRepository, Cursor, Page, SortBy, TenantRepository},
+    rate_limit::RateLimiter,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ListAccessRequestInput {
+    /// Every status when `None`.
+    pub status: Option<AccessRequestStatus>,
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
+
+/// Lists the access requests of the subject's tenant, optionally only those
+/// with a given status, e.g. the pending ones awaiting an approver.
+pub struct ListAccessRequestUseCase {
+    repo: Arc<dyn AccessRequestRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    rate_limiter: Arc<dyn RateLimiter>,
+}
+
+impl ListAccessRequestUseCase {
+    pub fn new(
+        repo: Arc<dyn AccessRequestRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+        rate_limiter: Arc<dyn RateLimiter>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListAccessRequestUseCase {
+    type Input = ListAccessRequestInput;
+    type Resource = ();
+    type Output = Page<AccessRequestView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        Some(self.rate_limiter.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        _ctx: &SubjectContex,
+        _input: &ListAccessRequestInput,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: ListAccessRequestInput,
+        _resource: Authorized<()>,
+    ) -> AppResult<Page<AccessRequestView>> {
+        self.repo
+            .get_by_status_page(
+                ctx,
+                input.status,
+                &input.sort_by,
+                input.after.as_ref(),
+                input.page_size,
+            )
+            .await
+    }
+}
+
+impl UseCaseDescriptor for ListAccessRequestUseCase {
+    const NAME: &'static str = "list_access_request";
+
+    const RESOURCE: &'static str = "access_request";
+
+    const ACTION: &'static str = "list";
+
+    const RATE_LIMITED: bool = true;
+}
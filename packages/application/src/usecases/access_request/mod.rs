@@ -0,0 +1,93 @@
+use domain::Tenant;
+
+use crate::{
+    SubjectContex,
+    error::{AppError, AppResult},
+};
+
+pub mod approve;
+pub mod cancel;
+pub mod deny;
+pub mod list;
+pub mod request;
+
+/// Only holders of the tenant's `access_request_approver_role`, directly or
+/// through a role that inherits from it, decide access requests; a tenant
+/// without one has no approvers.
+pub fn check_approver(tenant: &Tenant, subject: &SubjectContex) -> AppResult<()> {
+    let Some(approver_role) = tenant
+        .config()
+        .authorization()
+        .access_request_approver_role()
+    else {
+        return Err(AppError::PolicyError(
+            "tenant has no access request approver role".to_string(),
+        ));
+    };
+
+    if !subject.role_hierarchy.contains(approver_role) {
+        return Err(AppError::PolicyError(
+            "only access request approvers can decide access requests".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        RoleId, TenantId, UserId, role::RoleHierarchy, tenant::config::TenantConfig,
+        tenant::config::authorization::TenantAuthorizationConfig,
+    };
+
+    use super::*;
+    use crate::fixtures::{collaboration_config, environment_config, feature_config, role, tenant};
+
+    fn approving(role: Option<&str>) -> Tenant {
+        tenant(
+            "acme",
+            TenantConfig::new(
+                TenantAuthorizationConfig::new(false, false, None, role.map(RoleId::new), 3600),
+                environment_config(),
+                collaboration_config(),
+                feature_config(Vec::new()),
+            ),
+        )
+    }
+
+    #[test]
+    fn approvers_hold_the_approver_role_directly_or_inherited() {
+        let approver = role("approver", &[], &[]);
+        let lead = role("lead", &["approver"], &[]);
+        let member = role("member", &[], &[]);
+        let tenant = approving(Some("approver"));
+
+        let direct = SubjectContex::new(
+            UserId::new("ann"),
+            TenantId::new("acme"),
+            std::slice::from_ref(&approver),
+            &[],
+        );
+        assert!(check_approver(&tenant, &direct).is_ok());
+
+        let mut inherited = SubjectContex::new(
+            UserId::new("ben"),
+            TenantId::new("acme"),
+            std::slice::from_ref(&lead),
+            &[],
+        );
+        inherited.set_role_hierarchy(RoleHierarchy::new([lead, approver]));
+        assert!(check_approver(&tenant, &inherited).is_ok());
+
+        let other = SubjectContex::new(UserId::new("cat"), TenantId::new("acme"), &[member], &[]);
+        assert!(matches!(
+            check_approver(&tenant, &other),
+            Err(AppError::PolicyError(_))
+        ));
+        assert!(matches!(
+            check_approver(&approving(None), &direct),
+            Err(AppError::PolicyError(_))
+        ));
+    }
+}
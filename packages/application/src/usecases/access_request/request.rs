@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::access_request::{command::AccessRequestCommand, view::AccessRequestView},
+    error::AppResult,
+    ports::{AccessRequestRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Files an access request on behalf of the subject, in the subject's
+/// tenant.
+pub struct RequestAccessUseCase {
+    repo: Arc<dyn AccessRequestRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl RequestAccessUseCase {
+    pub fn new(
+        repo: Arc<dyn AccessRequestRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for RequestAccessUseCase {
+    type Input = AccessRequestCommand;
+    type Resource = ();
+    type Output = AccessRequestView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(
+        &self,
+        _ctx: &SubjectContex,
+        _request: &AccessRequestCommand,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        mut request: AccessRequestCommand,
+        _resource: Authorized<()>,
+    ) -> AppResult<AccessRequestView> {
+        request.requester_id = Some(ctx.user_id());
+        request.tenant_id = Some(ctx.tenant_id());
+        request
+            .created_at
+            .get_or_insert(*environment.time().timestamp());
+
+        self.repo.create(ctx, request).await
+    }
+}
+
+impl UseCaseDescriptor for RequestAccessUseCase {
+    const NAME: &'static str = "request_access";
+
+    const RESOURCE: &'static str = "access_request";
+
+    const ACTION: &'static str = "create";
+}
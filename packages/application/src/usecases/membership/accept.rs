@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Membership, Tenant, TenantId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::MembershipView,
+    error::AppResult,
+    ports::{MembershipRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Accepts the subject's invitation to the given tenant, acting either from
+/// another tenant or as resolved by
+/// [`SubjectResolver::resolve_invitee`](crate::authorization::subject_resolver::SubjectResolver::resolve_invitee).
+pub struct AcceptMembershipUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl AcceptMembershipUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for AcceptMembershipUseCase {
+    type Input = TenantId;
+    type Resource = Membership;
+    type Output = MembershipView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, tenant_id: &TenantId) -> AppResult<Membership> {
+        self.repo
+            .load(ctx.clone(), tenant_id.clone(), ctx.user_id())
+            .await
+    }
+
+    /// The invitee may act from another tenant, which cannot see the
+    /// inviting one, so the tenant is read as if from inside it; the loaded
+    /// invitation already names the subject.
+    async fn owner_tenant(
+        &self,
+        ctx: &SubjectContex,
+        membership: &Membership,
+    ) -> AppResult<Option<Tenant>> {
+        let invitee = SubjectContex::new(ctx.user_id(), membership.tenet_id().clone(), &[], &[]);
+        let tenant = self
+            .tenants
+            .load(invitee, membership.tenet_id().clone())
+            .await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _tenant_id: TenantId,
+        membership: Authorized<Membership>,
+    ) -> AppResult<MembershipView> {
+        let mut membership = membership.into_inner();
+        membership.accept(&ctx.user_id(), *environment.time().timestamp())?;
+        Ok(self.repo.save(ctx, membership).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for AcceptMembershipUseCase {
+    const NAME: &'static str = "accept_membership";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "accept";
+}
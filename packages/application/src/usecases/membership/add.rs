@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{MembershipStatus, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::{command::MembershipCommand, view::MembershipView},
+    error::{AppError, AppResult},
+    ports::{MembershipRepository, RoleRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::{MembershipScope, enroll};
+
+/// Adds a user to a tenant as an active member.
+pub struct AddMemberUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    roles: Arc<dyn RoleRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl AddMemberUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        roles: Arc<dyn RoleRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            roles,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for AddMemberUseCase {
+    type Input = MembershipCommand;
+    type Resource = MembershipScope;
+    type Output = MembershipView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        membership: &MembershipCommand,
+    ) -> AppResult<MembershipScope> {
+        let user_id = membership
+            .user_id
+            .clone()
+            .ok_or_else(|| AppError::ValidationError("a membership needs a user".to_string()))?;
+        let tenant_id = membership
+            .tenet_id
+            .clone()
+            .unwrap_or_else(|| ctx.tenant_id());
+        Ok(MembershipScope::new(tenant_id, user_id))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        membership: MembershipCommand,
+        scope: Authorized<MembershipScope>,
+    ) -> AppResult<MembershipView> {
+        let (_, tenant) = scope.into_parts()?;
+        enroll(
+            self.repo.as_ref(),
+            self.roles.as_ref(),
+            ctx,
+            &environment,
+            &tenant,
+            membership,
+            MembershipStatus::Active,
+        )
+        .await
+    }
+}
+
+impl UseCaseDescriptor for AddMemberUseCase {
+    const NAME: &'static str = "add_member";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "create";
+}
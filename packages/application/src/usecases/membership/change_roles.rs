@@ -0,0 +1,95 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use domain::{Membership, RoleId, TenantId, UserId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::MembershipView,
+    error::AppResult,
+    ports::{MembershipRepository, RoleRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::check_assignable;
+
+#[derive(Debug, Clone)]
+pub struct ChangeMemberRolesInput {
+    pub tenant_id: TenantId,
+    pub user_id: UserId,
+    pub roles: HashSet<RoleId>,
+}
+
+/// Replaces the roles a member holds in a tenant.
+pub struct ChangeMemberRolesUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    roles: Arc<dyn RoleRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ChangeMemberRolesUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        roles: Arc<dyn RoleRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            roles,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ChangeMemberRolesUseCase {
+    type Input = ChangeMemberRolesInput;
+    type Resource = Membership;
+    type Output = MembershipView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        input: &ChangeMemberRolesInput,
+    ) -> AppResult<Membership> {
+        self.repo
+            .load(ctx.clone(), input.tenant_id.clone(), input.user_id.clone())
+            .await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: ChangeMemberRolesInput,
+        membership: Authorized<Membership>,
+    ) -> AppResult<MembershipView> {
+        check_assignable(self.roles.as_ref(), &ctx, &input.roles).await?;
+        let mut membership = membership.into_inner();
+        membership.set_roles(input.roles, &ctx.user_id(), *environment.time().timestamp());
+        Ok(self.repo.save(ctx, membership).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for ChangeMemberRolesUseCase {
+    const NAME: &'static str = "change_member_roles";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "update";
+}
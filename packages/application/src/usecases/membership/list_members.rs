@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::MembershipView,
+    error::AppResult,
+    ports::{Cursor, MembershipRepository, Page, SortBy, TenantRepository},
+    rate_limit::RateLimiter,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ListMembersInput {
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
+
+/// Lists the members of the subject's tenant, invited ones included.
+pub struct ListMembersUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    rate_limiter: Arc<dyn RateLimiter>,
+}
+
+impl ListMembersUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+        rate_limiter: Arc<dyn RateLimiter>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListMembersUseCase {
+    type Input = ListMembersInput;
+    type Resource = ();
+    type Output = Page<MembershipView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        Some(self.rate_limiter.as_ref())
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &ListMembersInput) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: ListMembersInput,
+        _resource: Authorized<()>,
+    ) -> AppResult<Page<MembershipView>> {
+        let tenant_id = ctx.tenant_id();
+        self.repo
+            .get_members_page(
+                ctx,
+                tenant_id,
+                &input.sort_by,
+                input.after.as_ref(),
+                input.page_size,
+            )
+            .await
+    }
+}
+
+impl UseCaseDescriptor for ListMembersUseCase {
+    const NAME: &'static str = "list_members";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "list";
+
+    const RATE_LIMITED: bool = true;
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{UserId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::{tenant::view::MembershipView, user::view::UserView},
+    error::AppResult,
+    ports::{Cursor, MembershipRepository, Page, SortBy, UserRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ListUserTenantsInput {
+    /// Defaults to the subject.
+    pub user_id: Option<UserId>,
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
+
+/// Lists the tenants a user belongs to or is invited to, one membership per
+/// tenant. Authorized against the user, so policies can let users list
+/// their own tenants through the `self` relation.
+pub struct ListUserTenantsUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    users: Arc<dyn UserRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ListUserTenantsUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        users: Arc<dyn UserRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self { repo, users, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListUserTenantsUseCase {
+    type Input = ListUserTenantsInput;
+    type Resource = UserView;
+    type Output = Page<MembershipView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        input: &ListUserTenantsInput,
+    ) -> AppResult<UserView> {
+        let user_id = input.user_id.clone().unwrap_or_else(|| ctx.user_id());
+        self.users.get_by_id(ctx.clone(), user_id).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: ListUserTenantsInput,
+        _user: Authorized<UserView>,
+    ) -> AppResult<Page<MembershipView>> {
+        let user_id = input.user_id.unwrap_or_else(|| ctx.user_id());
+        self.repo
+            .get_user_tenants_page(
+                ctx,
+                user_id,
+                &input.sort_by,
+                input.after.as_ref(),
+                input.page_size,
+            )
+            .await
+    }
+}
+
+impl UseCaseDescriptor for ListUserTenantsUseCase {
+    const NAME: &'static str = "list_user_tenants";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "list";
+}
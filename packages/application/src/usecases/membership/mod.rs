@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use domain::{
+    MembershipStatus, RoleId, Tenant, TenantId, UserId, tenant::environment::Environment,
+};
+
+use crate::{
+    SubjectContex,
+    dto::tenant::{command::MembershipCommand, view::MembershipView},
+    error::{AppError, AppResult},
+    ports::{MembershipRepository, RoleRepository},
+};
+
+pub mod accept;
+pub mod add;
+pub mod change_roles;
+pub mod invite;
+pub mod list_members;
+pub mod list_tenants;
+pub mod remove;
+
+/// The tenant and user whose membership a use case acts on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipScope {
+    pub tenant_id: TenantId,
+    pub user_id: UserId,
+}
+
+impl MembershipScope {
+    pub fn new(tenant_id: TenantId, user_id: UserId) -> Self {
+        Self { tenant_id, user_id }
+    }
+}
+
+/// The roles a new member starts with: the requested ones, or the tenant's
+/// `default_role` when none are given.
+pub fn initial_roles(tenant: &Tenant, roles: Option<HashSet<RoleId>>) -> HashSet<RoleId> {
+    match roles {
+        Some(roles) if !roles.is_empty() => roles,
+        _ => tenant
+            .config()
+            .authorization()
+            .default_role()
+            .iter()
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Checks that every role in `roles` exists and that the actor holds it,
+/// directly or through inheritance, so nobody hands out more than they have.
+pub async fn check_assignable(
+    repo: &dyn RoleRepository,
+    ctx: &SubjectContex,
+    roles: &HashSet<RoleId>,
+) -> AppResult<()> {
+    for role in roles {
+        repo.load(ctx.clone(), role.clone()).await?;
+        if !ctx.role_hierarchy.contains(role) {
+            return Err(AppError::Forbidden);
+        }
+    }
+    Ok(())
+}
+
+/// Stores a new membership of `tenant` with `status`. Invitations hold a
+/// seat, so the repository counts them towards `max_users` like active
+/// members, in the same write as the new membership.
+async fn enroll(
+    repo: &dyn MembershipRepository,
+    roles: &dyn RoleRepository,
+    ctx: SubjectContex,
+    environment: &Environment,
+    tenant: &Tenant,
+    mut membership: MembershipCommand,
+    status: MembershipStatus,
+) -> AppResult<MembershipView> {
+    let initial = initial_roles(tenant, membership.roles.take());
+    check_assignable(roles, &ctx, &initial).await?;
+
+    membership.tenet_id = Some(tenant.id().clone());
+    membership.roles = Some(initial);
+    membership.status = Some(status);
+    membership
+        .created_at
+        .get_or_insert(*environment.time().timestamp());
+
+    let max_users = *tenant.config().features().limits().max_users();
+    repo.create_within_limit(ctx, membership, max_users).await
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{AggregateRoot, Membership, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    error::AppResult,
+    ports::{MembershipRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::MembershipScope;
+
+/// Removes a member, or withdraws an invitation, from a tenant.
+pub struct RemoveMemberUseCase {
+    repo: Arc<dyn MembershipRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl RemoveMemberUseCase {
+    pub fn new(
+        repo: Arc<dyn MembershipRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for RemoveMemberUseCase {
+    type Input = MembershipScope;
+    type Resource = Membership;
+    type Output = bool;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        scope: &MembershipScope,
+    ) -> AppResult<Membership> {
+        self.repo
+            .load(ctx.clone(), scope.tenant_id.clone(), scope.user_id.clone())
+            .await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _scope: MembershipScope,
+        membership: Authorized<Membership>,
+    ) -> AppResult<bool> {
+        let mut membership = membership.into_inner();
+        membership.mark_deleted(&ctx.user_id(), *environment.time().timestamp());
+        self.repo.delete(ctx, membership).await
+    }
+}
+
+impl UseCaseDescriptor for RemoveMemberUseCase {
+    const NAME: &'static str = "remove_member";
+
+    const RESOURCE: &'static str = "membership";
+
+    const ACTION: &'static str = "delete";
+}
@@ -1,5 +1,10 @@
-// pub mod report;
-// pub mod role;
+pub mod access_request;
+pub mod membership;
+pub mod pipeline;
+pub mod report;
+pub mod temporary_grant;
+pub mod tenant;
+pub mod role;
 pub mod usecase_discriptor;
 pub mod user;
 
@@ -0,0 +1,253 @@
+//! The execution pipeline shared by every use case: load the resource and
+//! its owner tenant, authorize the subject against them, check the feature
+//! gate, charge the rate limit, then run the use case body. Callers only ever reach a use case through
+//! [`UseCase::execute`]: [`UseCase::handle`] takes the resource wrapped in
+//! [`Authorized`], which only the pipeline can build.
+
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use domain::{tenant::environment::Environment, Tenant, TenantId};
+
+use crate::{
+    authorization::{
+        access_descriptor::AccessControl,
+        action::AuthorizationAction,
+        attributes::{AttributeKey, AttributeValue},
+        engine::AuthorizationContext,
+        feature_gate::FeatureGate,
+        ports::AuthorizationService,
+        resource::{subject_attributes, AuthorizationResource},
+        AccessDecision,
+    },
+    error::{AppError, AppResult},
+    ports::TenantRepository,
+    projection::fields::FieldCatalog,
+    rate_limit::RateLimiter,
+    usecases::usecase_discriptor::UseCaseDescriptor,
+    SubjectContex,
+};
+
+/// A resource the subject was allowed to act on, along with the owner
+/// tenant it was authorized against. Only [`UseCase::execute`] creates one,
+/// so a use case body cannot be reached around the pipeline.
+#[derive(Debug)]
+pub struct Authorized<R> {
+    resource: R,
+    owner_tenant: Option<Tenant>,
+}
+
+impl<R> Authorized<R> {
+    pub fn into_inner(self) -> R {
+        self.resource
+    }
+
+    pub fn owner_tenant(&self) -> Option<&Tenant> {
+        self.owner_tenant.as_ref()
+    }
+
+    /// Splits off the owner tenant, for use case bodies that depend on its
+    /// configuration. Fails when the use case reports no owner tenant.
+    pub fn into_parts(self) -> AppResult<(R, Tenant)> {
+        let tenant = self.owner_tenant.ok_or(AppError::Unknown(
+            "no owner tenant was loaded for the resource".to_string(),
+        ))?;
+        Ok((self.resource, tenant))
+    }
+}
+
+impl<R> Deref for Authorized<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.resource
+    }
+}
+
+#[async_trait]
+pub trait UseCase: UseCaseDescriptor + Sized + Send + Sync {
+    type Input: Send + 'static;
+    type Resource: AuthorizationResource + Send + Sync + 'static;
+    type Output: Send;
+
+    fn authorization(&self) -> &dyn AuthorizationService;
+
+    /// The limiter charged when the use case is `RATE_LIMITED`. A rate
+    /// limited use case without one, or without [`UseCase::tenants`] to load
+    /// the subject's tenant from, fails instead of running unlimited.
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        None
+    }
+
+    /// Loads whatever the authorization decision depends on. Runs before
+    /// access is checked, so it must not change anything. When it or
+    /// [`UseCase::owner_tenant`] fails, a subject not allowed to run the use
+    /// case at all sees [`AppError::Forbidden`] instead of the error, so
+    /// missing and forbidden resources look the same.
+    async fn resource(&self, ctx: &SubjectContex, input: &Self::Input)
+        -> AppResult<Self::Resource>;
+
+    /// The repository [`UseCase::owner_tenant`] loads the owner tenant from
+    /// by default. Use cases without one report no owner tenant.
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        None
+    }
+
+    /// The tenant owning `resource`. Use cases acting on tenant-owned
+    /// resources should return it so the tenant's status, environment and
+    /// collaboration rules apply. By default it is the tenant named by the
+    /// resource's `tenant_id` attribute, loaded through
+    /// [`UseCase::tenants`].
+    async fn owner_tenant(
+        &self,
+        ctx: &SubjectContex,
+        resource: &Self::Resource,
+    ) -> AppResult<Option<Tenant>> {
+        let Some(tenants) = self.tenants() else {
+            return Ok(None);
+        };
+        let Some(AttributeValue::String(tenant_id)) = resource
+            .attributes()
+            .get(&AttributeKey::new("tenant_id"))
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        let tenant = tenants.load(ctx.clone(), TenantId::new(&tenant_id)).await?;
+        Ok(Some(tenant))
+    }
+
+    /// The use case body, reached only once the subject was allowed.
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: Self::Input,
+        resource: Authorized<Self::Resource>,
+    ) -> AppResult<Self::Output>;
+
+    async fn execute(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: Self::Input,
+    ) -> AppResult<Self::Output> {
+        let resource = match self.resource(&ctx, &input).await {
+            Ok(resource) => resource,
+            Err(err) => {
+                authorize::<Self>(self.authorization(), &ctx, &(), environment, None)?;
+                return Err(err);
+            }
+        };
+        let owner_tenant = match self.owner_tenant(&ctx, &resource).await {
+            Ok(owner_tenant) => owner_tenant,
+            Err(err) => {
+                authorize::<Self>(self.authorization(), &ctx, &(), environment, None)?;
+                return Err(err);
+            }
+        };
+        authorize::<Self>(
+            self.authorization(),
+            &ctx,
+            &resource,
+            environment.clone(),
+            owner_tenant.clone(),
+        )?;
+        // Only allowed subjects learn whether the feature is enabled.
+        FeatureGate::require_for::<Self>(owner_tenant.as_ref())?;
+        if Self::RATE_LIMITED {
+            charge_rate_limit(self, &ctx, owner_tenant.as_ref(), &environment).await?;
+        }
+        let resource = Authorized {
+            resource,
+            owner_tenant,
+        };
+        self.handle(ctx, environment, input, resource).await
+    }
+}
+
+/// Spends one request of `subject`'s tenant on `usecase`. The owner tenant
+/// is reused when it is the subject's own; otherwise the subject's tenant is
+/// loaded, so requests against other tenants' resources count too.
+async fn charge_rate_limit<U: UseCase>(
+    usecase: &U,
+    subject: &SubjectContex,
+    owner_tenant: Option<&Tenant>,
+    environment: &Environment,
+) -> AppResult<()> {
+    let limiter = usecase.rate_limiter().ok_or(AppError::Unknown(format!(
+        "{} is rate limited but has no rate limiter",
+        U::NAME
+    )))?;
+    let loaded;
+    let tenant = match owner_tenant.filter(|tenant| tenant.id() == &subject.tenant_id) {
+        Some(tenant) => tenant,
+        None => {
+            let tenants = usecase.tenants().ok_or(AppError::Unknown(format!(
+                "{} is rate limited but cannot load the subject's tenant",
+                U::NAME
+            )))?;
+            loaded = tenants.load(subject.clone(), subject.tenant_id()).await?;
+            &loaded
+        }
+    };
+    limiter.check(subject, tenant, *environment.time().timestamp())
+}
+
+/// The fields of `resource` that `subject` may read and write, as the field
+/// policies of `auth` decide for reading `U::RESOURCE` and for writing it:
+/// creating use cases write under `create` policies, every other one under
+/// `update` policies. Only called once `subject` was allowed to run `U`, so
+/// reading is allowed.
+pub fn field_access<U: UseCaseDescriptor, F: FieldCatalog>(
+    auth: &dyn AuthorizationService,
+    subject: &SubjectContex,
+    resource: &impl AuthorizationResource,
+    environment: Environment,
+    owner_tenant: Option<&Tenant>,
+) -> AccessControl<F> {
+    let mut ctx = AuthorizationContext::from_usecase::<U>(
+        subject,
+        subject_attributes(subject),
+        resource.attributes(),
+        resource.relations(subject),
+        environment,
+    );
+    ctx.owner_tenant = owner_tenant.cloned();
+
+    ctx.action = AuthorizationAction::new("read");
+    let readable = auth.field_grant(&ctx);
+    ctx.action = AuthorizationAction::new(if U::ACTION == "create" {
+        "create"
+    } else {
+        "update"
+    });
+    let writable = auth.field_grant(&ctx);
+    AccessControl::from_grants(true, &readable, &writable)
+}
+
+/// Asks `auth` whether `subject` may run `U` against `resource`; a denial
+/// becomes [`AppError::Forbidden`].
+pub fn authorize<U: UseCaseDescriptor>(
+    auth: &dyn AuthorizationService,
+    subject: &SubjectContex,
+    resource: &impl AuthorizationResource,
+    environment: Environment,
+    owner_tenant: Option<Tenant>,
+) -> AppResult<()> {
+    let mut ctx = AuthorizationContext::from_usecase::<U>(
+        subject,
+        subject_attributes(subject),
+        resource.attributes(),
+        resource.relations(subject),
+        environment,
+    );
+    if let Some(tenant) = owner_tenant {
+        ctx.set_owner_tenant(tenant);
+    }
+
+    match auth.authorize(&ctx) {
+        AccessDecision::Allow => Ok(()),
+        AccessDecision::Deny => Err(AppError::Forbidden),
+    }
+}
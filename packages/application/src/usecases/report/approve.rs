@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, ReportId, Tenant, UserId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::{AppError, AppResult},
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+pub struct ApproveReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ApproveReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ApproveReportUseCase {
+    type Input = ReportId;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, report_id: &ReportId) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), report_id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _report_id: ReportId,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let (mut report, tenant) = report.into_parts()?;
+
+        let approver = ctx.user_id();
+        check_publish_policy(&tenant, &report, &approver)?;
+
+        report.approve(&approver, *environment.time().timestamp())?;
+        Ok(self.repo.save(ctx, report).await?.into())
+    }
+}
+
+/// Enforces `TenantAuthorizationConfig::require_reviewer_for_publish` for the
+/// tenant owning the report: the author cannot approve it, and an assigned
+/// reviewer must have commented on it first. Reviewers are assigned and
+/// comment through use cases gated by `Feature::AdvancedReports`, so a
+/// tenant requiring reviewers needs that feature to publish at all.
+pub fn check_publish_policy(tenant: &Tenant, report: &Report, approver: &UserId) -> AppResult<()> {
+    if !tenant
+        .config()
+        .authorization()
+        .require_reviewer_for_publish()
+    {
+        return Ok(());
+    }
+
+    if report.is_author(approver) {
+        return Err(AppError::PolicyError(
+            "the author of a report cannot approve it".to_string(),
+        ));
+    }
+
+    let reviewed = report
+        .content()
+        .review_comments()
+        .iter()
+        .any(|comment| report.is_reviewer(comment.reviewer_id()));
+    if !reviewed {
+        return Err(AppError::PolicyError(
+            "report needs a review comment from an assigned reviewer before approval".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+impl UseCaseDescriptor for ApproveReportUseCase {
+    const NAME: &'static str = "approve_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "approve";
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        Body, Comment, DateTime, ReportContent, ReportId, TenantId, UserId,
+        tenant::config::TenantConfig, tenant::config::authorization::TenantAuthorizationConfig,
+        tenant::config::feature::Feature,
+    };
+
+    use super::*;
+    use crate::fixtures::{collaboration_config, environment_config, feature_config, tenant};
+
+    fn publishing_tenant(require_reviewer: bool, features: Vec<Feature>) -> Tenant {
+        tenant(
+            "acme",
+            TenantConfig::new(
+                TenantAuthorizationConfig::new(false, require_reviewer, None, None, 3600),
+                environment_config(),
+                collaboration_config(),
+                feature_config(features),
+            ),
+        )
+    }
+
+    fn report() -> Report {
+        let mut content = ReportContent::new();
+        content.set_body(Body::new("Quarterly figures").unwrap());
+        let mut builder = Report::new(
+            ReportId::new("r1"),
+            UserId::new("author"),
+            TenantId::new("acme"),
+        );
+        builder.set_content(content.build().unwrap());
+        builder.build("Quarterly_report", DateTime::new(0)).unwrap()
+    }
+
+    fn comment() -> Comment {
+        Comment::new("Looks good to me").unwrap()
+    }
+
+    #[test]
+    fn nothing_is_required_when_the_tenant_does_not_ask_for_reviewers() {
+        let tenant = publishing_tenant(false, vec![Feature::AdvancedReports]);
+        assert!(check_publish_policy(&tenant, &report(), &UserId::new("author")).is_ok());
+    }
+
+    #[test]
+    fn the_author_cannot_approve() {
+        let tenant = publishing_tenant(true, vec![Feature::AdvancedReports]);
+        let result = check_publish_policy(&tenant, &report(), &UserId::new("author"));
+        assert!(matches!(result, Err(AppError::PolicyError(_))));
+    }
+
+    #[test]
+    fn an_assigned_reviewer_must_have_commented() {
+        let tenant = publishing_tenant(true, vec![Feature::AdvancedReports]);
+        let approver = UserId::new("approver");
+        let mut report = report();
+        report
+            .assign_reviewer(UserId::new("reviewer"), &approver, DateTime::new(1))
+            .unwrap();
+        assert!(matches!(
+            check_publish_policy(&tenant, &report, &approver),
+            Err(AppError::PolicyError(_))
+        ));
+
+        report
+            .add_review_comment(&UserId::new("reviewer"), comment(), DateTime::new(2))
+            .unwrap();
+        assert!(check_publish_policy(&tenant, &report, &approver).is_ok());
+    }
+
+    #[test]
+    fn a_review_comment_is_required_without_advanced_reports() {
+        let tenant = publishing_tenant(true, Vec::new());
+        let approver = UserId::new("approver");
+        let mut report = report();
+        report
+            .assign_reviewer(UserId::new("reviewer"), &approver, DateTime::new(1))
+            .unwrap();
+        assert!(matches!(
+            check_publish_policy(&tenant, &report, &approver),
+            Err(AppError::PolicyError(_))
+        ));
+    }
+
+    #[test]
+    fn only_assigned_reviewers_can_comment() {
+        let mut report = report();
+        assert!(
+            report
+                .add_review_comment(&UserId::new("author"), comment(), DateTime::new(1))
+                .is_err()
+        );
+        assert!(
+            report
+                .assign_reviewer(
+                    UserId::new("author"),
+                    &UserId::new("author"),
+                    DateTime::new(1)
+                )
+                .is_err()
+        );
+        assert!(report.content().review_comments().is_empty());
+    }
+}
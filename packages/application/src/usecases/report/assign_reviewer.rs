@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, tenant::config::feature::Feature, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::ReportReviewer;
+
+/// Assigns a reviewer to a report. An assigned reviewer's comment is what
+/// [`check_publish_policy`](super::approve::check_publish_policy) looks for
+/// before a report may be approved.
+pub struct AssignReportReviewerUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl AssignReportReviewerUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for AssignReportReviewerUseCase {
+    type Input = ReportReviewer;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        assignment: &ReportReviewer,
+    ) -> AppResult<Report> {
+        self.repo
+            .load(ctx.clone(), assignment.report_id.clone())
+            .await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        assignment: ReportReviewer,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let mut report = report.into_inner();
+        report.assign_reviewer(
+            assignment.reviewer_id,
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, report).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for AssignReportReviewerUseCase {
+    const NAME: &'static str = "assign_report_reviewer";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "assign_reviewer";
+
+    const FEATURE: Option<Feature> = Some(Feature::AdvancedReports);
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, tenant::config::feature::Feature, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::ReportReview;
+
+/// Adds a review comment to a report on behalf of the subject, who must be
+/// one of its assigned reviewers.
+pub struct CommentOnReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl CommentOnReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for CommentOnReportUseCase {
+    type Input = ReportReview;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, review: &ReportReview) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), review.report_id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        review: ReportReview,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let mut report = report.into_inner();
+        report.add_review_comment(
+            &ctx.user_id(),
+            review.comment,
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, report).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for CommentOnReportUseCase {
+    const NAME: &'static str = "comment_on_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "comment";
+
+    const FEATURE: Option<Feature> = Some(Feature::AdvancedReports);
+}
@@ -1,20 +1,88 @@
-use domain::Report;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, tenant::environment::Environment};
 
 use crate::{
-    SubjectContex, dto::report_dto::{
-        input::CreateReportInput,
-        output::PreivilegeReportOutput
-    }, error::AppResult, ports::ReportRepository
+    SubjectContex,
+    authorization::{ports::AuthorizationService, write_guard::WriteGuard},
+    dto::report::{command::ReportCommand, view::ReportView},
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    projection::fields::report::RepotField,
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
 };
 
-pub struct CreateReportUseCase<R: ReportRepository> {
-    repo: R,
+/// Creates a report owned by the subject's tenant. Every field the command
+/// sets must be writable for the subject. Reviewers and review comments are
+/// not part of the command; they are added through
+/// [`AssignReportReviewerUseCase`](super::assign_reviewer::AssignReportReviewerUseCase)
+/// and [`CommentOnReportUseCase`](super::comment::CommentOnReportUseCase).
+pub struct CreateReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl CreateReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
 }
 
-impl<R: ReportRepository> CreateReportUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, input: CreateReportInput) -> AppResult<PreivilegeReportOutput> {
-        let report = Report::try_from(input)?;
-        self.repo.create(ctx, report.clone()).await?;
-        Ok(PreivilegeReportOutput::from(report))
+#[async_trait]
+impl UseCase for CreateReportUseCase {
+    type Input = ReportCommand;
+    type Resource = ();
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &ReportCommand) -> AppResult<()> {
+        Ok(())
     }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: ReportCommand,
+        resource: Authorized<()>,
+    ) -> AppResult<ReportView> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*resource,
+            environment,
+            resource.owner_tenant(),
+        );
+        WriteGuard::check(&input, &access)?;
+        self.repo.create(ctx, input).await
+    }
+}
+
+impl UseCaseDescriptor for CreateReportUseCase {
+    const NAME: &'static str = "create_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "create";
 }
@@ -1,14 +1,72 @@
-use domain::ReportId;
+use std::sync::Arc;
 
-use crate::{SubjectContex, error::AppResult, ports::ReportRepository};
+use async_trait::async_trait;
+use domain::{Report, ReportId, tenant::environment::Environment};
 
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
 
-pub struct DeleteReportUseCase<R: ReportRepository> {
-    repo: R,
+pub struct DeleteReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
 }
 
-impl<R: ReportRepository> DeleteReportUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, report_id: ReportId) -> AppResult<bool> {
-        self.repo.delete(ctx, report_id.clone()).await
+impl DeleteReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
     }
 }
+
+#[async_trait]
+impl UseCase for DeleteReportUseCase {
+    type Input = ReportId;
+    type Resource = Report;
+    type Output = bool;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, report_id: &ReportId) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), report_id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        report_id: ReportId,
+        _report: Authorized<Report>,
+    ) -> AppResult<bool> {
+        self.repo.delete(ctx, report_id).await
+    }
+}
+
+impl UseCaseDescriptor for DeleteReportUseCase {
+    const NAME: &'static str = "delete_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "delete";
+}
@@ -1,4 +1,33 @@
+pub mod approve;
+pub mod assign_reviewer;
+pub mod comment;
 pub mod create;
 pub mod delete;
 pub mod read;
+pub mod share;
+pub mod unshare;
 pub mod update;
+
+use domain::{Comment, ReportId, TenantId, UserId};
+
+/// A report together with the tenant it is shared with or unshared from.
+#[derive(Debug, Clone)]
+pub struct ReportShare {
+    pub report_id: ReportId,
+    pub tenant_id: TenantId,
+}
+
+/// A report together with the user assigned to review it.
+#[derive(Debug, Clone)]
+pub struct ReportReviewer {
+    pub report_id: ReportId,
+    pub reviewer_id: UserId,
+}
+
+/// A review comment on a report. The reviewer is always the subject
+/// running the use case.
+#[derive(Debug, Clone)]
+pub struct ReportReview {
+    pub report_id: ReportId,
+    pub comment: Comment,
+}
@@ -1,3 +0,0 @@
-pub mod auther;
-pub mod id;
-pub mod title;
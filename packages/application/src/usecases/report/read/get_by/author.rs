@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, UserId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, SortBy, TenantRepository},
+    projection::{
+        fields::report::RepotField,
+        projector::{ReadMask, ReadProjector},
+    },
+    rate_limit::RateLimiter,
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct GetReportsByAuthorInput {
+    pub author_id: UserId,
+    pub sort_by: Vec<SortBy>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+/// Lists the reports of one author that the subject's tenant can see.
+pub struct GetReportsByAuthorUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    rate_limiter: Arc<dyn RateLimiter>,
+}
+
+impl GetReportsByAuthorUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+        rate_limiter: Arc<dyn RateLimiter>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for GetReportsByAuthorUseCase {
+    type Input = GetReportsByAuthorInput;
+    type Resource = ();
+    type Output = Vec<ReportView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        Some(self.rate_limiter.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        _ctx: &SubjectContex,
+        _input: &GetReportsByAuthorInput,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: GetReportsByAuthorInput,
+        resource: Authorized<()>,
+    ) -> AppResult<Vec<ReportView>> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*resource,
+            environment,
+            resource.owner_tenant(),
+        );
+        access.check_sortable(&input.sort_by)?;
+        let reports = self
+            .repo
+            .get_by_author_id(
+                ctx,
+                &input.sort_by,
+                input.page,
+                input.page_size,
+                input.author_id,
+            )
+            .await?;
+        let mask = ReadMask::new(&access.readable_fields);
+        Ok(reports
+            .into_iter()
+            .map(|report| report.project_fields(&mask))
+            .collect())
+    }
+}
+
+impl UseCaseDescriptor for GetReportsByAuthorUseCase {
+    const NAME: &'static str = "get_reports_by_author";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "list";
+
+    const RATE_LIMITED: bool = true;
+}
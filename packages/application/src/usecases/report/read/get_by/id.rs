@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, ReportId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    projection::{fields::report::RepotField, projector::ReadProjector},
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+pub struct GetReportByIdUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl GetReportByIdUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for GetReportByIdUseCase {
+    type Input = ReportId;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, id: &ReportId) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: ReportId,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*report,
+            environment,
+            report.owner_tenant(),
+        );
+        report.into_inner().project(&access)
+    }
+}
+
+impl UseCaseDescriptor for GetReportByIdUseCase {
+    const NAME: &'static str = "get_report_by_id";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "read";
+}
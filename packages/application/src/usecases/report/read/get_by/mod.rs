@@ -1,3 +1,3 @@
-pub mod auther;
-pub mod general;
-pub mod privilege;
+pub mod author;
+pub mod id;
+pub mod title;
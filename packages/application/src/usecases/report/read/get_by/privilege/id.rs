@@ -1,15 +0,0 @@
-
-use domain::ReportId;
-
-use crate::{SubjectContex, dto::report_dto::output::PreivilegeReportOutput, error::AppResult, ports::ReportRepository};
-
-
-
-pub struct GetReportByIdPrivilegeUseCase<R: ReportRepository> {
-    repo: R,
-}
-
-impl<R: ReportRepository> GetReportByIdPrivilegeUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, report_id: ReportId) -> AppResult<PreivilegeReportOutput> {
-        Ok(self.repo.get_by_id( ctx,report_id.clone()).await?.into())    }
-}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, Title, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::{AppError, AppResult},
+    ports::{ReportRepository, TenantRepository},
+    projection::{fields::report::RepotField, projector::ReadProjector},
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+pub struct GetReportByTitleUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl GetReportByTitleUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for GetReportByTitleUseCase {
+    type Input = Title;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    /// Looks the report up by title, then loads the aggregate the decision
+    /// is made on.
+    async fn resource(&self, ctx: &SubjectContex, title: &Title) -> AppResult<Report> {
+        let view = self.repo.get_by_title(ctx.clone(), title.clone()).await?;
+        let id = view
+            .id
+            .ok_or(AppError::Repository("Report not found".to_string()))?;
+        self.repo.load(ctx.clone(), id).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _title: Title,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*report,
+            environment,
+            report.owner_tenant(),
+        );
+        report.into_inner().project(&access)
+    }
+}
+
+impl UseCaseDescriptor for GetReportByTitleUseCase {
+    const NAME: &'static str = "get_report_by_title";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "read";
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{Cursor, Page, ReportRepository, SortBy, TenantRepository},
+    projection::{
+        fields::report::RepotField,
+        projector::{ReadMask, ReadProjector},
+    },
+    rate_limit::RateLimiter,
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct ListReportInput {
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
+
+/// Lists the reports the subject's tenant owns or has been shared.
+pub struct ListReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    rate_limiter: Arc<dyn RateLimiter>,
+}
+
+impl ListReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+        rate_limiter: Arc<dyn RateLimiter>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListReportUseCase {
+    type Input = ListReportInput;
+    type Resource = ();
+    type Output = Page<ReportView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        Some(self.rate_limiter.as_ref())
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &ListReportInput) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn owner_tenant(&self, ctx: &SubjectContex, _resource: &()) -> AppResult<Option<Tenant>> {
+        let tenant = self.tenants.load(ctx.clone(), ctx.tenant_id()).await?;
+        Ok(Some(tenant))
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: ListReportInput,
+        resource: Authorized<()>,
+    ) -> AppResult<Page<ReportView>> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*resource,
+            environment,
+            resource.owner_tenant(),
+        );
+        access.check_sortable(&input.sort_by)?;
+        let page = self
+            .repo
+            .get_reports_page(ctx, &input.sort_by, input.after.as_ref(), input.page_size)
+            .await?;
+        let mask = ReadMask::new(&access.readable_fields);
+        Ok(page.map(|report| report.project_fields(&mask)))
+    }
+}
+
+impl UseCaseDescriptor for ListReportUseCase {
+    const NAME: &'static str = "list_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "list";
+
+    const RATE_LIMITED: bool = true;
+}
@@ -1,2 +0,0 @@
-pub mod auther;
-pub mod reviewer;
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, tenant::config::feature::Feature, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::ReportShare;
+
+/// Shares a report with another tenant. Whether members of that tenant can
+/// then act on it is still up to the owner tenant's collaboration rules.
+pub struct ShareReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ShareReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ShareReportUseCase {
+    type Input = ReportShare;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, share: &ReportShare) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), share.report_id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        share: ReportShare,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let mut report = report.into_inner();
+        report.share_with(
+            share.tenant_id,
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, report).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for ShareReportUseCase {
+    const NAME: &'static str = "share_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "share";
+
+    const FEATURE: Option<Feature> = Some(Feature::CrossTenantSharing);
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Report, tenant::config::feature::Feature, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::report::view::ReportView,
+    error::AppResult,
+    ports::{ReportRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::ReportShare;
+
+/// Withdraws a report from a tenant it was shared with.
+pub struct UnshareReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl UnshareReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for UnshareReportUseCase {
+    type Input = ReportShare;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, share: &ReportShare) -> AppResult<Report> {
+        self.repo.load(ctx.clone(), share.report_id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        share: ReportShare,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let mut report = report.into_inner();
+        report.unshare_with(
+            &share.tenant_id,
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, report).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for UnshareReportUseCase {
+    const NAME: &'static str = "unshare_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "unshare";
+
+    const FEATURE: Option<Feature> = Some(Feature::CrossTenantSharing);
+}
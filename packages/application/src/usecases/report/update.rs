@@ -1,15 +1,88 @@
-use domain::Report;
+use std::sync::Arc;
 
-use crate::{ SubjectContex, dto::report_dto::{input::UpdateReportInput, output::PreivilegeReportOutput}, error::AppResult, ports::ReportRepository};
+use async_trait::async_trait;
+use domain::{Report, tenant::environment::Environment};
 
-pub struct UpdateReportUseCase<R: ReportRepository> {
-    repo: R,
+use crate::{
+    SubjectContex,
+    authorization::{ports::AuthorizationService, write_guard::WriteGuard},
+    dto::report::{command::ReportCommand, view::ReportView},
+    error::{AppError, AppResult},
+    ports::{ReportRepository, TenantRepository},
+    projection::fields::report::RepotField,
+    usecases::{
+        pipeline::{Authorized, UseCase, field_access},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Edits a report's fields. Status changes go through the lifecycle use
+/// cases instead, and every field the command sets must be writable for the
+/// subject.
+pub struct UpdateReportUseCase {
+    repo: Arc<dyn ReportRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl UpdateReportUseCase {
+    pub fn new(
+        repo: Arc<dyn ReportRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
 }
 
-impl<R: ReportRepository> UpdateReportUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, input: UpdateReportInput) -> AppResult<PreivilegeReportOutput> {
-        let report = Report::try_from(input)?; 
-        self.repo.update( ctx, report.clone()).await?;
-        Ok(PreivilegeReportOutput::from(report))
+#[async_trait]
+impl UseCase for UpdateReportUseCase {
+    type Input = ReportCommand;
+    type Resource = Report;
+    type Output = ReportView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
     }
+
+    async fn resource(&self, ctx: &SubjectContex, input: &ReportCommand) -> AppResult<Report> {
+        let id = input.id.clone().ok_or(AppError::ValidationError(
+            "report id is required".to_string(),
+        ))?;
+        self.repo.load(ctx.clone(), id).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        input: ReportCommand,
+        report: Authorized<Report>,
+    ) -> AppResult<ReportView> {
+        let access = field_access::<Self, RepotField>(
+            self.auth.as_ref(),
+            &ctx,
+            &*report,
+            environment,
+            report.owner_tenant(),
+        );
+        WriteGuard::check(&input, &access)?;
+        self.repo.update(ctx, input).await
+    }
+}
+
+impl UseCaseDescriptor for UpdateReportUseCase {
+    const NAME: &'static str = "update_report";
+
+    const RESOURCE: &'static str = "report";
+
+    const ACTION: &'static str = "update";
 }
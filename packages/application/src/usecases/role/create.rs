@@ -1,15 +1,71 @@
-use domain::Role;
-use crate::{SubjectContex, dto::role_dto::{input::CreateRoleInput, output::PrivilegeRoleOutput}, error::AppResult, ports::RoleRepository};
+use std::sync::Arc;
 
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
 
-pub struct CreateRoleUseCase<R: RoleRepository> {
-    repo: R,
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::role::{command::RoleCommand, view::RoleView},
+    error::{AppError, AppResult},
+    ports::RoleRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::check_parent_roles;
+
+/// Creates a role shared by every tenant. The id is required so the parent
+/// roles can be checked against it before anything is stored.
+pub struct CreateRoleUseCase {
+    repo: Arc<dyn RoleRepository>,
+    auth: Arc<dyn AuthorizationService>,
 }
 
-impl<R: RoleRepository> CreateRoleUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, input: CreateRoleInput) -> AppResult<PrivilegeRoleOutput> {
-        let role = Role::try_from(input)?;
-        self.repo.create(ctx, role.clone()).await?;
-        Ok(PrivilegeRoleOutput::from(role))
+impl CreateRoleUseCase {
+    pub fn new(repo: Arc<dyn RoleRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for CreateRoleUseCase {
+    type Input = RoleCommand;
+    type Resource = ();
+    type Output = RoleView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &RoleCommand) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: RoleCommand,
+        _resource: Authorized<()>,
+    ) -> AppResult<RoleView> {
+        let id = input
+            .id
+            .as_ref()
+            .ok_or(AppError::ValidationError("role id is required".to_string()))?;
+        if let Some(parents) = &input.parent_roles {
+            check_parent_roles(self.repo.clone(), &ctx, id, parents).await?;
+        }
+        self.repo.create(ctx, input).await
     }
 }
+
+impl UseCaseDescriptor for CreateRoleUseCase {
+    const NAME: &'static str = "create_role";
+
+    const RESOURCE: &'static str = "role";
+
+    const ACTION: &'static str = "create";
+}
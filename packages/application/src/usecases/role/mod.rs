@@ -1,4 +1,56 @@
+use std::{collections::HashSet, sync::Arc};
+
+use domain::{DomainError, RoleId, error::RoleError};
+
+use crate::{
+    SubjectContex,
+    authorization::effective_permissions::EffectivePermissionResolver,
+    error::{AppError, AppResult},
+    ports::RoleRepository,
+};
+
 pub mod create;
-pub mod delete;
-pub mod read;
+// pub mod delete;
+// pub mod read;
 pub mod update;
+
+/// Checks that every role in `parents` exists and that none of them inherits
+/// from `id`, so the role `id` can be saved with these parents without making
+/// the hierarchy cyclic. Repositories store roles as they are given; this is
+/// where the whole hierarchy is walked before a write.
+pub async fn check_parent_roles(
+    repo: Arc<dyn RoleRepository>,
+    ctx: &SubjectContex,
+    id: &RoleId,
+    parents: &HashSet<RoleId>,
+) -> AppResult<()> {
+    let cyclic = || {
+        AppError::from(DomainError::from(RoleError::CyclicHierarchy(
+            id.as_str().into(),
+        )))
+    };
+    if parents.contains(id) {
+        return Err(cyclic());
+    }
+
+    let mut loaded = Vec::new();
+    for parent in parents {
+        match repo.load(ctx.clone(), parent.clone()).await {
+            Ok(role) => loaded.push(role),
+            Err(AppError::Repository(_)) => {
+                return Err(DomainError::from(RoleError::UnknownParentRole(
+                    parent.as_str().into(),
+                ))
+                .into());
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    let hierarchy = EffectivePermissionResolver::new(repo)
+        .hierarchy(ctx, &loaded)
+        .await?;
+    if hierarchy.contains(id) {
+        return Err(cyclic());
+    }
+    Ok(())
+}
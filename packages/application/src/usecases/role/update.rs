@@ -1,16 +1,71 @@
-use domain::Role;
+use std::sync::Arc;
 
-use crate::{ SubjectContex, dto::role_dto::{input::UpdateRoleInput, output::PrivilegeRoleOutput}, error::AppResult, ports::RoleRepository};
+use async_trait::async_trait;
+use domain::{Role, tenant::environment::Environment};
 
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::role::{command::RoleCommand, view::RoleView},
+    error::{AppError, AppResult},
+    ports::RoleRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
 
-pub struct UpdateRoleUseCase<R: RoleRepository> {
-    repo: R,
+use super::check_parent_roles;
+
+/// Edits a role. New parent roles are checked against the stored hierarchy
+/// first; a command that keeps the stored parents is not checked again.
+pub struct UpdateRoleUseCase {
+    repo: Arc<dyn RoleRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl UpdateRoleUseCase {
+    pub fn new(repo: Arc<dyn RoleRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
 }
 
-impl<R: RoleRepository> UpdateRoleUseCase<R> {
-    pub async fn execute(&self, ctx: SubjectContex, input: UpdateRoleInput) -> AppResult<PrivilegeRoleOutput> {
-        let role = Role::try_from(input)?;
-        self.repo.update( ctx, role.clone()).await?;
-        Ok(PrivilegeRoleOutput::from(role))
+#[async_trait]
+impl UseCase for UpdateRoleUseCase {
+    type Input = RoleCommand;
+    type Resource = Role;
+    type Output = RoleView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, input: &RoleCommand) -> AppResult<Role> {
+        let id = input
+            .id
+            .clone()
+            .ok_or(AppError::ValidationError("role id is required".to_string()))?;
+        self.repo.load(ctx.clone(), id).await
     }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: RoleCommand,
+        role: Authorized<Role>,
+    ) -> AppResult<RoleView> {
+        if let Some(parents) = &input.parent_roles {
+            check_parent_roles(self.repo.clone(), &ctx, role.id(), parents).await?;
+        }
+        self.repo.update(ctx, input).await
+    }
+}
+
+impl UseCaseDescriptor for UpdateRoleUseCase {
+    const NAME: &'static str = "update_role";
+
+    const RESOURCE: &'static str = "role";
+
+    const ACTION: &'static str = "update";
 }
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::{
+        command::temporary_grant::TemporaryGrantCommand, view::temporary_grant::TemporaryGrantView,
+    },
+    error::{AppError, AppResult},
+    ports::{TemporaryGrantRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::TemporaryGrantScope;
+
+pub struct IssueTemporaryGrantUseCase {
+    repo: Arc<dyn TemporaryGrantRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl IssueTemporaryGrantUseCase {
+    pub fn new(
+        repo: Arc<dyn TemporaryGrantRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for IssueTemporaryGrantUseCase {
+    type Input = TemporaryGrantCommand;
+    type Resource = TemporaryGrantScope;
+    type Output = TemporaryGrantView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        _ctx: &SubjectContex,
+        grant: &TemporaryGrantCommand,
+    ) -> AppResult<TemporaryGrantScope> {
+        match (&grant.user_id, &grant.tenant_id) {
+            (Some(user_id), Some(tenant_id)) => {
+                Ok(TemporaryGrantScope::new(user_id.clone(), tenant_id.clone()))
+            }
+            _ => Err(AppError::ValidationError(
+                "a temporary grant needs a user and a tenant".to_string(),
+            )),
+        }
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        mut grant: TemporaryGrantCommand,
+        _scope: Authorized<TemporaryGrantScope>,
+    ) -> AppResult<TemporaryGrantView> {
+        if grant.resource.is_none() || grant.action.is_none() {
+            return Err(AppError::ValidationError(
+                "a temporary grant needs a resource and an action".to_string(),
+            ));
+        }
+
+        let now = *environment.time().timestamp();
+        match &grant.expires_at {
+            Some(expires_at) if now.is_before(expires_at) => {}
+            Some(_) => {
+                return Err(AppError::ValidationError(
+                    "a temporary grant must expire in the future".to_string(),
+                ));
+            }
+            None => {
+                return Err(AppError::ValidationError(
+                    "a temporary grant needs an expiry".to_string(),
+                ));
+            }
+        }
+        grant.created_at.get_or_insert(now);
+
+        self.repo.issue(ctx, grant).await
+    }
+}
+
+impl UseCaseDescriptor for IssueTemporaryGrantUseCase {
+    const NAME: &'static str = "issue_temporary_grant";
+
+    const RESOURCE: &'static str = "temporary_grant";
+
+    const ACTION: &'static str = "issue";
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::temporary_grant::TemporaryGrantView,
+    error::AppResult,
+    ports::{Cursor, Page, SortBy, TemporaryGrantRepository, TenantRepository},
+    rate_limit::RateLimiter,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::TemporaryGrantScope;
+
+#[derive(Debug, Clone)]
+pub struct ListTemporaryGrantInput {
+    pub scope: TemporaryGrantScope,
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
+
+/// Lists every grant of a user in a tenant, expired ones included.
+pub struct ListTemporaryGrantUseCase {
+    repo: Arc<dyn TemporaryGrantRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    rate_limiter: Arc<dyn RateLimiter>,
+}
+
+impl ListTemporaryGrantUseCase {
+    pub fn new(
+        repo: Arc<dyn TemporaryGrantRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+        rate_limiter: Arc<dyn RateLimiter>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+            rate_limiter,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListTemporaryGrantUseCase {
+    type Input = ListTemporaryGrantInput;
+    type Resource = TemporaryGrantScope;
+    type Output = Page<TemporaryGrantView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    fn rate_limiter(&self) -> Option<&dyn RateLimiter> {
+        Some(self.rate_limiter.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        _ctx: &SubjectContex,
+        input: &ListTemporaryGrantInput,
+    ) -> AppResult<TemporaryGrantScope> {
+        Ok(input.scope.clone())
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        input: ListTemporaryGrantInput,
+        _resource: Authorized<TemporaryGrantScope>,
+    ) -> AppResult<Page<TemporaryGrantView>> {
+        let scope = input.scope;
+        self.repo
+            .get_by_user_page(
+                ctx,
+                scope.user_id,
+                scope.tenant_id,
+                &input.sort_by,
+                input.after.as_ref(),
+                input.page_size,
+            )
+            .await
+    }
+}
+
+impl UseCaseDescriptor for ListTemporaryGrantUseCase {
+    const NAME: &'static str = "list_temporary_grant";
+
+    const RESOURCE: &'static str = "temporary_grant";
+
+    const ACTION: &'static str = "list";
+
+    const RATE_LIMITED: bool = true;
+}
@@ -0,0 +1,18 @@
+use domain::{TenantId, UserId};
+
+pub mod issue;
+pub mod list;
+pub mod revoke;
+
+/// The user and tenant whose grants a use case acts on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemporaryGrantScope {
+    pub user_id: UserId,
+    pub tenant_id: TenantId,
+}
+
+impl TemporaryGrantScope {
+    pub fn new(user_id: UserId, tenant_id: TenantId) -> Self {
+        Self { user_id, tenant_id }
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{TemporaryGrant, TemporaryGrantId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    error::AppResult,
+    ports::{TemporaryGrantRepository, TenantRepository},
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+pub struct RevokeTemporaryGrantUseCase {
+    repo: Arc<dyn TemporaryGrantRepository>,
+    tenants: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl RevokeTemporaryGrantUseCase {
+    pub fn new(
+        repo: Arc<dyn TemporaryGrantRepository>,
+        tenants: Arc<dyn TenantRepository>,
+        auth: Arc<dyn AuthorizationService>,
+    ) -> Self {
+        Self {
+            repo,
+            tenants,
+            auth,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase for RevokeTemporaryGrantUseCase {
+    type Input = TemporaryGrantId;
+    type Resource = TemporaryGrant;
+    type Output = bool;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.tenants.as_ref())
+    }
+
+    async fn resource(
+        &self,
+        ctx: &SubjectContex,
+        id: &TemporaryGrantId,
+    ) -> AppResult<TemporaryGrant> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        _environment: Environment,
+        id: TemporaryGrantId,
+        _grant: Authorized<TemporaryGrant>,
+    ) -> AppResult<bool> {
+        self.repo.revoke(ctx, id).await
+    }
+}
+
+impl UseCaseDescriptor for RevokeTemporaryGrantUseCase {
+    const NAME: &'static str = "revoke_temporary_grant";
+
+    const RESOURCE: &'static str = "temporary_grant";
+
+    const ACTION: &'static str = "revoke";
+}
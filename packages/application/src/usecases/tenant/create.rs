@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::{command::TenantCommand, view::TenantView},
+    error::AppResult,
+    ports::TenantRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Creates an active tenant.
+pub struct CreateTenantUseCase {
+    repo: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl CreateTenantUseCase {
+    pub fn new(repo: Arc<dyn TenantRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for CreateTenantUseCase {
+    type Input = TenantCommand;
+    type Resource = ();
+    type Output = TenantView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _tenant: &TenantCommand) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        mut tenant: TenantCommand,
+        _resource: Authorized<()>,
+    ) -> AppResult<TenantView> {
+        tenant
+            .created_at
+            .get_or_insert(*environment.time().timestamp());
+        self.repo.create(ctx, tenant).await
+    }
+}
+
+impl UseCaseDescriptor for CreateTenantUseCase {
+    const NAME: &'static str = "create_tenant";
+
+    const RESOURCE: &'static str = "tenant";
+
+    const ACTION: &'static str = "create";
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, TenantId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::TenantView,
+    error::AppResult,
+    ports::TenantRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+use super::DEFAULT_DELETION_GRACE_PERIOD;
+
+/// Schedules a tenant for deletion. Access is denied right away, but the
+/// tenant's data is only archived by [`super::purge::PurgeTenantsUseCase`]
+/// once the grace period is over.
+pub struct DeleteTenantUseCase {
+    repo: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+    grace_period: i64,
+}
+
+impl DeleteTenantUseCase {
+    pub fn new(repo: Arc<dyn TenantRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self {
+            repo,
+            auth,
+            grace_period: DEFAULT_DELETION_GRACE_PERIOD,
+        }
+    }
+
+    /// Grace period in seconds.
+    pub fn with_grace_period(mut self, grace_period: i64) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+}
+
+#[async_trait]
+impl UseCase for DeleteTenantUseCase {
+    type Input = TenantId;
+    type Resource = Tenant;
+    type Output = TenantView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.repo.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, id: &TenantId) -> AppResult<Tenant> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: TenantId,
+        tenant: Authorized<Tenant>,
+    ) -> AppResult<TenantView> {
+        let mut tenant = tenant.into_inner();
+        tenant.schedule_deletion(
+            self.grace_period,
+            &ctx.user_id(),
+            *environment.time().timestamp(),
+        )?;
+        Ok(self.repo.save(ctx, tenant).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for DeleteTenantUseCase {
+    const NAME: &'static str = "delete_tenant";
+
+    const RESOURCE: &'static str = "tenant";
+
+    const ACTION: &'static str = "delete";
+}
@@ -0,0 +1,19 @@
+//! Tenant lifecycle. Use cases acting on a tenant authorize against the
+//! tenant itself, which is also their owner tenant; the `TenantStatusPolicy`
+//! guard lets reactivation and deletion through so administrators are not
+//! locked out of a suspended tenant.
+
+pub mod create;
+pub mod delete;
+pub mod purge;
+pub mod reactivate;
+pub mod suspend;
+
+/// The tenant of the platform's own subjects, such as the scheduled job
+/// running [`PurgeTenantsUseCase`](purge::PurgeTenantsUseCase). Only its
+/// subjects act on tenants other than their own.
+pub const PLATFORM_TENANT: &str = "platform";
+
+/// Thirty days, the grace period a tenant pending deletion can still be
+/// reactivated in.
+pub const DEFAULT_DELETION_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::TenantView,
+    error::AppResult,
+    ports::TenantRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        tenant::PLATFORM_TENANT,
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// The deletion workflow's last step, meant to run periodically: every
+/// tenant whose grace period has ended becomes `Deleted`, and its reports
+/// and memberships are archived. A subject of the [`PLATFORM_TENANT`] purges
+/// every tenant that is due; any other subject only its own tenant.
+pub struct PurgeTenantsUseCase {
+    repo: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl PurgeTenantsUseCase {
+    pub fn new(repo: Arc<dyn TenantRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for PurgeTenantsUseCase {
+    type Input = ();
+    type Resource = ();
+    type Output = Vec<TenantView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &()) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _input: (),
+        _resource: Authorized<()>,
+    ) -> AppResult<Vec<TenantView>> {
+        let now = *environment.time().timestamp();
+        let mut purged = Vec::new();
+        let platform = ctx.tenant_id.as_str() == PLATFORM_TENANT;
+        let due = self.repo.get_due_for_purge(ctx.clone(), now).await?;
+        for mut tenant in due
+            .into_iter()
+            .filter(|tenant| platform || tenant.id() == &ctx.tenant_id)
+        {
+            tenant.complete_deletion(&ctx.user_id(), now)?;
+            purged.push(self.repo.purge(ctx.clone(), tenant, now).await?.into());
+        }
+        Ok(purged)
+    }
+}
+
+impl UseCaseDescriptor for PurgeTenantsUseCase {
+    const NAME: &'static str = "purge_tenants";
+
+    const RESOURCE: &'static str = "tenant";
+
+    const ACTION: &'static str = "purge";
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, TenantId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::TenantView,
+    error::AppResult,
+    ports::TenantRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Reactivates a suspended tenant, or cancels a deletion still in its grace
+/// period.
+pub struct ReactivateTenantUseCase {
+    repo: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl ReactivateTenantUseCase {
+    pub fn new(repo: Arc<dyn TenantRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for ReactivateTenantUseCase {
+    type Input = TenantId;
+    type Resource = Tenant;
+    type Output = TenantView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.repo.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, id: &TenantId) -> AppResult<Tenant> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: TenantId,
+        tenant: Authorized<Tenant>,
+    ) -> AppResult<TenantView> {
+        let mut tenant = tenant.into_inner();
+        tenant.reactivate(&ctx.user_id(), *environment.time().timestamp())?;
+        Ok(self.repo.save(ctx, tenant).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for ReactivateTenantUseCase {
+    const NAME: &'static str = "reactivate_tenant";
+
+    const RESOURCE: &'static str = "tenant";
+
+    const ACTION: &'static str = "reactivate";
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{Tenant, TenantId, tenant::environment::Environment};
+
+use crate::{
+    SubjectContex,
+    authorization::ports::AuthorizationService,
+    dto::tenant::view::TenantView,
+    error::AppResult,
+    ports::TenantRepository,
+    usecases::{
+        pipeline::{Authorized, UseCase},
+        usecase_discriptor::UseCaseDescriptor,
+    },
+};
+
+/// Suspends a tenant, denying all access to its resources until it is
+/// reactivated.
+pub struct SuspendTenantUseCase {
+    repo: Arc<dyn TenantRepository>,
+    auth: Arc<dyn AuthorizationService>,
+}
+
+impl SuspendTenantUseCase {
+    pub fn new(repo: Arc<dyn TenantRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for SuspendTenantUseCase {
+    type Input = TenantId;
+    type Resource = Tenant;
+    type Output = TenantView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    fn tenants(&self) -> Option<&dyn TenantRepository> {
+        Some(self.repo.as_ref())
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, id: &TenantId) -> AppResult<Tenant> {
+        self.repo.load(ctx.clone(), id.clone()).await
+    }
+
+    async fn handle(
+        &self,
+        ctx: SubjectContex,
+        environment: Environment,
+        _id: TenantId,
+        tenant: Authorized<Tenant>,
+    ) -> AppResult<TenantView> {
+        let mut tenant = tenant.into_inner();
+        tenant.suspend(&ctx.user_id(), *environment.time().timestamp())?;
+        Ok(self.repo.save(ctx, tenant).await?.into())
+    }
+}
+
+impl UseCaseDescriptor for SuspendTenantUseCase {
+    const NAME: &'static str = "suspend_tenant";
+
+    const RESOURCE: &'static str = "tenant";
+
+    const ACTION: &'static str = "suspend";
+}
@@ -1,3 +1,5 @@
+use domain::tenant::config::feature::Feature;
+
 pub trait UseCaseDescriptor {
     /// Stable identity
     const NAME: &'static str;
@@ -10,6 +12,11 @@ pub trait UseCaseDescriptor {
     const AUDIT: bool = true;
     const METRICS: bool = true;
 
+    /// Feature the owner tenant must have rolled out, checked by
+    /// [`FeatureGate`](crate::authorization::feature_gate::FeatureGate)
+    /// once the subject was authorized.
+    const FEATURE: Option<Feature> = None;
+
     /// Operational hints
     const RATE_LIMITED: bool = false;
     const CACHEABLE: bool = false;
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
 
-use crate::{ SubjectContex, authorization::ports::AuthorizationService, dto::{user::{command::UserCommand, view::UserView}, user_dto::{input::CreateUserInput, output::OwnerUserOutput}}, error::AppResult, ports::UserRepository};
+use crate::{ SubjectContex, authorization::ports::AuthorizationService, dto::{user::{command::UserCommand, view::UserView}}, error::AppResult, ports::UserRepository,
+             usecases::{pipeline::{Authorized, UseCase}, usecase_discriptor::UseCaseDescriptor}};
 
 
 pub struct CreateUserUseCase
@@ -11,8 +14,34 @@ pub struct CreateUserUseCase
 }
 
 impl CreateUserUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, input: UserCommand) -> AppResult<UserView> {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for CreateUserUseCase {
+    type Input = UserCommand;
+    type Resource = ();
+    type Output = UserView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &UserCommand) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn handle(&self, ctx: SubjectContex, _environment: Environment, input: UserCommand, _resource: Authorized<()>) -> AppResult<UserView> {
         self.repo.create(ctx, input).await
     }
 }
 
+impl UseCaseDescriptor for CreateUserUseCase {
+    const NAME: &'static str = "create_user";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "create";
+}
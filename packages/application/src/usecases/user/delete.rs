@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
-use domain::UserId;
+use async_trait::async_trait;
+use domain::{User, UserId, tenant::environment::Environment};
 
-use crate::{SubjectContex, authorization::ports::AuthorizationService, error::AppResult, ports::UserRepository};
+use crate::{SubjectContex, authorization::ports::AuthorizationService, error::AppResult, ports::UserRepository,
+            usecases::{pipeline::{Authorized, UseCase}, usecase_discriptor::UseCaseDescriptor}};
 
 
 pub struct DeleteUserUseCase {
@@ -11,7 +13,34 @@ pub struct DeleteUserUseCase {
 }
 
 impl DeleteUserUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, user_id: UserId) -> AppResult<bool> {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for DeleteUserUseCase {
+    type Input = UserId;
+    type Resource = User;
+    type Output = bool;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, user_id: &UserId) -> AppResult<User> {
+        self.repo.load(ctx.clone(), user_id.clone()).await
+    }
+
+    async fn handle(&self, ctx: SubjectContex, _environment: Environment, user_id: UserId, _resource: Authorized<User>) -> AppResult<bool> {
         self.repo.delete(ctx, user_id).await
     }
 }
+
+impl UseCaseDescriptor for DeleteUserUseCase {
+    const NAME: &'static str = "delete_user";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "delete";
+}
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
-use domain::Email;
+use async_trait::async_trait;
+use domain::{Email, tenant::environment::Environment};
 
-use crate::{ SubjectContex, authorization::ports::AuthorizationService,  error::AppResult, ports::UserRepository};
+use crate::{ SubjectContex, authorization::ports::AuthorizationService, dto::user::view::UserView, error::AppResult, ports::UserRepository,
+             projection::{fields::user::UserField, projector::ReadProjector},
+             usecases::{pipeline::{Authorized, UseCase, field_access}, usecase_discriptor::UseCaseDescriptor}};
 
 pub struct GetUserByEmailUseCase {
     repo: Arc<dyn UserRepository>,
@@ -10,7 +13,35 @@ pub struct GetUserByEmailUseCase {
 }
 
 impl GetUserByEmailUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, email: Email ) -> AppResult<UserView> {
-        self.repo.get_by_email(ctx, email.clone()).await
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
     }
 }
+
+#[async_trait]
+impl UseCase for GetUserByEmailUseCase {
+    type Input = Email;
+    type Resource = UserView;
+    type Output = UserView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, email: &Email) -> AppResult<UserView> {
+        self.repo.get_by_email(ctx.clone(), email.clone()).await
+    }
+
+    async fn handle(&self, ctx: SubjectContex, environment: Environment, _email: Email, user: Authorized<UserView>) -> AppResult<UserView> {
+        let access = field_access::<Self, UserField>(self.auth.as_ref(), &ctx, &*user, environment, user.owner_tenant());
+        user.into_inner().project(&access)
+    }
+}
+
+impl UseCaseDescriptor for GetUserByEmailUseCase {
+    const NAME: &'static str = "get_user_by_email";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "read";
+}
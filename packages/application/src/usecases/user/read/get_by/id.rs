@@ -1,18 +1,48 @@
-
 use std::sync::Arc;
 
-use domain::UserId;
+use async_trait::async_trait;
+use domain::{User, UserId, tenant::environment::Environment};
 
-use crate::{SubjectContex, authorization::ports::AuthorizationService, dto::user_dto::output::PrivilegeUserOutput, error::AppResult, ports::UserRepository};
+use crate::{SubjectContex, authorization::ports::AuthorizationService, dto::user::view::UserView, error::AppResult, ports::UserRepository,
+            projection::{fields::user::UserField, projector::ReadProjector},
+            usecases::{pipeline::{Authorized, UseCase, field_access}, usecase_discriptor::UseCaseDescriptor}};
 
 
-pub struct GetUserByIdPrivilegeUseCase {
+pub struct GetUserByIdUseCase {
     repo: Arc<dyn UserRepository>,
     auth: Arc<dyn AuthorizationService>
 }
 
-impl GetUserByIdPrivilegeUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, id: UserId) -> AppResult<PrivilegeUserOutput> {
-            Ok(self.repo.get_by_id(ctx, id.clone()).await?.into())
+impl GetUserByIdUseCase {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for GetUserByIdUseCase {
+    type Input = UserId;
+    type Resource = User;
+    type Output = UserView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, id: &UserId) -> AppResult<User> {
+        self.repo.load(ctx.clone(), id.clone()).await
     }
+
+    async fn handle(&self, ctx: SubjectContex, environment: Environment, _id: UserId, user: Authorized<User>) -> AppResult<UserView> {
+        let access = field_access::<Self, UserField>(self.auth.as_ref(), &ctx, &*user, environment, user.owner_tenant());
+        user.into_inner().project(&access)
+    }
+}
+
+impl UseCaseDescriptor for GetUserByIdUseCase {
+    const NAME: &'static str = "get_user_by_id";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "read";
 }
@@ -1,16 +1,47 @@
 use std::sync::Arc;
 
-use domain::Username;
+use async_trait::async_trait;
+use domain::{Username, tenant::environment::Environment};
 
-use crate::{SubjectContex, authorization::ports::AuthorizationService, dto::user_dto::output::PrivilegeUserOutput, error::AppResult, ports::UserRepository};
+use crate::{ SubjectContex, authorization::ports::AuthorizationService, dto::user::view::UserView, error::AppResult, ports::UserRepository,
+             projection::{fields::user::UserField, projector::ReadProjector},
+             usecases::{pipeline::{Authorized, UseCase, field_access}, usecase_discriptor::UseCaseDescriptor}};
 
-pub struct GetUserByUsernamePrivilegeUseCase {
+pub struct GetUserByUsernameUseCase {
     repo: Arc<dyn UserRepository>,
     auth: Arc<dyn AuthorizationService>
 }
 
-impl GetUserByUsernamePrivilegeUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, username: Username) -> AppResult<PrivilegeUserOutput> {
-        Ok(self.repo.get_by_username(ctx, username.clone()).await?.into())
+impl GetUserByUsernameUseCase {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
     }
 }
+
+#[async_trait]
+impl UseCase for GetUserByUsernameUseCase {
+    type Input = Username;
+    type Resource = UserView;
+    type Output = UserView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, username: &Username) -> AppResult<UserView> {
+        self.repo.get_by_username(ctx.clone(), username.clone()).await
+    }
+
+    async fn handle(&self, ctx: SubjectContex, environment: Environment, _username: Username, user: Authorized<UserView>) -> AppResult<UserView> {
+        let access = field_access::<Self, UserField>(self.auth.as_ref(), &ctx, &*user, environment, user.owner_tenant());
+        user.into_inner().project(&access)
+    }
+}
+
+impl UseCaseDescriptor for GetUserByUsernameUseCase {
+    const NAME: &'static str = "get_user_by_username";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "read";
+}
@@ -1,7 +1,20 @@
 use std::sync::Arc;
 
-use crate::{SubjectContex, authorization::ports::AuthorizationService, dto::user::view::UserView, error::{AppError, AppResult}, ports::{SortBy, UserRepository}};
+use async_trait::async_trait;
+use domain::tenant::environment::Environment;
 
+use crate::{SubjectContex, authorization::ports::AuthorizationService, dto::user::view::UserView, error::{AppError, AppResult}, ports::{Cursor, Page, SortBy, UserRepository},
+            projection::{fields::user::UserField, projector::{ReadMask, ReadProjector}},
+            usecases::{pipeline::{Authorized, UseCase, field_access}, usecase_discriptor::UseCaseDescriptor}};
+
+
+#[derive(Debug, Clone)]
+pub struct ListUserInput {
+    pub sort_by: Vec<SortBy>,
+    /// Cursor of the previous page; `None` for the first one.
+    pub after: Option<Cursor>,
+    pub page_size: u32,
+}
 
 pub struct ListUserUseCase {
     repo: Arc<dyn UserRepository>,
@@ -9,13 +22,41 @@ pub struct ListUserUseCase {
 }
 
 impl ListUserUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<UserView>> {
-        let result = self.repo.get_users_paginated(ctx, sort_by,page, page_size).await?;
-        if !result.is_empty() {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for ListUserUseCase {
+    type Input = ListUserInput;
+    type Resource = ();
+    type Output = Page<UserView>;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, _ctx: &SubjectContex, _input: &ListUserInput) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn handle(&self, ctx: SubjectContex, environment: Environment, input: ListUserInput, resource: Authorized<()>) -> AppResult<Page<UserView>> {
+        let access = field_access::<Self, UserField>(self.auth.as_ref(), &ctx, &*resource, environment, resource.owner_tenant());
+        let result = self.repo.get_users_page(ctx, &input.sort_by, input.after.as_ref(), input.page_size).await?;
+        if result.items.is_empty() {
             Err(AppError::Repository("Users not found".to_string()))
         } else {
-            Ok(result)
+            let mask = ReadMask::new(&access.readable_fields);
+            Ok(result.map(|user| user.project_fields(&mask)))
         }
     }
 }
 
+impl UseCaseDescriptor for ListUserUseCase {
+    const NAME: &'static str = "list_user";
+
+    const RESOURCE: &'static str= "user";
+
+    const ACTION: &'static str = "list";
+}
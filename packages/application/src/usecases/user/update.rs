@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use domain::User;
+use async_trait::async_trait;
+use domain::{User, tenant::environment::Environment};
 
-use crate::{ SubjectContex, dto::{user::{command::UserCommand, view::UserView}},
-                                   error::AppResult, ports::UserRepository, usecases::usecase_discriptor::UseCaseDescriptor;
+use crate::{ SubjectContex, authorization::write_guard::WriteGuard, dto::{user::{command::UserCommand, view::UserView}},
+                                   error::{AppError, AppResult}, ports::UserRepository, projection::fields::user::UserField, usecases::{pipeline::{Authorized, UseCase, field_access}, usecase_discriptor::UseCaseDescriptor}};
 use crate::authorization::ports::AuthorizationService;
 
 
@@ -13,7 +14,29 @@ pub struct UpdateUserUseCase{
 }
 
 impl UpdateUserUseCase {
-    pub async fn execute(&self, ctx: SubjectContex, input: UserCommand) -> AppResult<UserView> {
+    pub fn new(repo: Arc<dyn UserRepository>, auth: Arc<dyn AuthorizationService>) -> Self {
+        Self { repo, auth }
+    }
+}
+
+#[async_trait]
+impl UseCase for UpdateUserUseCase {
+    type Input = UserCommand;
+    type Resource = User;
+    type Output = UserView;
+
+    fn authorization(&self) -> &dyn AuthorizationService {
+        self.auth.as_ref()
+    }
+
+    async fn resource(&self, ctx: &SubjectContex, input: &UserCommand) -> AppResult<User> {
+        let id = input.id.clone().ok_or(AppError::ValidationError("user id is required".to_string()))?;
+        self.repo.load(ctx.clone(), id).await
+    }
+
+    async fn handle(&self, ctx: SubjectContex, environment: Environment, input: UserCommand, user: Authorized<User>) -> AppResult<UserView> {
+        let access = field_access::<Self, UserField>(self.auth.as_ref(), &ctx, &*user, environment, user.owner_tenant());
+        WriteGuard::check(&input, &access)?;
         self.repo.update( ctx, input).await
     }
 }
@@ -0,0 +1,40 @@
+use crate::traits::field::Field;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessRequestField {
+    Id,
+    RequesterId,
+    TenantId,
+    Resource,
+    Action,
+    Justification,
+    RequestedDuration,
+    Status,
+    DecidedBy,
+    DecisionReason,
+    GrantId,
+    CreatedAt,
+    UpdatedAt,
+    Version,
+}
+
+impl Field for AccessRequestField {
+    fn name(&self) -> &'static str {
+        match self {
+            AccessRequestField::Id => "id",
+            AccessRequestField::RequesterId => "requester_id",
+            AccessRequestField::TenantId => "tenant_id",
+            AccessRequestField::Resource => "resource",
+            AccessRequestField::Action => "action",
+            AccessRequestField::Justification => "justification",
+            AccessRequestField::RequestedDuration => "requested_duration",
+            AccessRequestField::Status => "status",
+            AccessRequestField::DecidedBy => "decided_by",
+            AccessRequestField::DecisionReason => "decision_reason",
+            AccessRequestField::GrantId => "grant_id",
+            AccessRequestField::CreatedAt => "created_at",
+            AccessRequestField::UpdatedAt => "updated_at",
+            AccessRequestField::Version => "version",
+        }
+    }
+}
@@ -0,0 +1,419 @@
+pub mod fields;
+pub mod status;
+
+pub use status::AccessRequestStatus;
+
+use crate::error::{AccessRequestError, DomainResult};
+use crate::events::{AggregateRoot, PendingEvents, Table};
+use crate::value_objects::{Action, Comment, DateTime, Description, Resource};
+use crate::{DomainError, Event, Permission, PermissionId, TemporaryGrant, TemporaryGrantId};
+use crate::{TenantId, UserId};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AccessRequestId(String);
+
+impl AccessRequestId {
+    pub fn new(id: &str) -> Self {
+        Self(id.into())
+    }
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for AccessRequestId {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for AccessRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A user's request for just-in-time access to `action` on `resource` in
+/// their tenant. Approving it issues a [`TemporaryGrant`] whose lifetime is
+/// the requested duration, capped by the tenant.
+#[derive(Debug, Clone)]
+pub struct AccessRequest {
+    id: AccessRequestId,
+    requester_id: UserId,
+    tenant_id: TenantId,
+    resource: Resource,
+    action: Action,
+    justification: Comment,
+    requested_duration: i64,
+    status: AccessRequestStatus,
+    decided_by: Option<UserId>,
+    decision_reason: Option<Comment>,
+    grant_id: Option<TemporaryGrantId>,
+    created_at: DateTime,
+    updated_at: DateTime,
+    version: u64,
+    events: PendingEvents<AccessRequest>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessRequestParts {
+    pub id: AccessRequestId,
+    pub requester_id: UserId,
+    pub tenant_id: TenantId,
+    pub resource: Resource,
+    pub action: Action,
+    pub justification: Comment,
+    pub requested_duration: i64,
+    pub status: AccessRequestStatus,
+    pub decided_by: Option<UserId>,
+    pub decision_reason: Option<Comment>,
+    pub grant_id: Option<TemporaryGrantId>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub version: u64,
+}
+
+impl AccessRequest {
+    pub fn new(
+        id: AccessRequestId,
+        requester_id: UserId,
+        tenant_id: TenantId,
+    ) -> AccessRequestBuilder {
+        AccessRequestBuilder::new(id, requester_id, tenant_id)
+    }
+
+    pub fn into_parts(self) -> AccessRequestParts {
+        let Self {
+            id,
+            requester_id,
+            tenant_id,
+            resource,
+            action,
+            justification,
+            requested_duration,
+            status,
+            decided_by,
+            decision_reason,
+            grant_id,
+            created_at,
+            updated_at,
+            version,
+            events: _,
+        } = self;
+        AccessRequestParts {
+            id,
+            requester_id,
+            tenant_id,
+            resource,
+            action,
+            justification,
+            requested_duration,
+            status,
+            decided_by,
+            decision_reason,
+            grant_id,
+            created_at,
+            updated_at,
+            version,
+        }
+    }
+
+    pub fn belongs_to(&self, tenant: &TenantId) -> bool {
+        &self.tenant_id == tenant
+    }
+
+    pub fn is_requester(&self, user: &UserId) -> bool {
+        &self.requester_id == user
+    }
+
+    // Lifecycle
+    /// Approves the request and returns the grant it issues, live from `now`
+    /// for the requested duration but never longer than `max_duration`.
+    pub fn approve(
+        &mut self,
+        max_duration: i64,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<TemporaryGrant> {
+        if self.is_requester(actor) {
+            return Err(AccessRequestError::SelfDecision.into());
+        }
+        if max_duration <= 0 {
+            return Err(AccessRequestError::InvalidDuration.into());
+        }
+
+        let grant = self.issue_grant(self.requested_duration.min(max_duration), now)?;
+        let before = self.snapshot();
+        self.transition_to(AccessRequestStatus::Approved, now)?;
+        self.decided_by = Some(actor.clone());
+        self.grant_id = Some(grant.id().clone());
+        self.record_event("approved", actor, now, before);
+        Ok(grant)
+    }
+
+    pub fn deny(&mut self, reason: Comment, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        if self.is_requester(actor) {
+            return Err(AccessRequestError::SelfDecision.into());
+        }
+
+        let before = self.snapshot();
+        self.transition_to(AccessRequestStatus::Denied, now)?;
+        self.decided_by = Some(actor.clone());
+        self.decision_reason = Some(reason);
+        self.record_event("denied", actor, now, before);
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        if !self.is_requester(actor) {
+            return Err(AccessRequestError::NotRequester.into());
+        }
+
+        let before = self.snapshot();
+        self.transition_to(AccessRequestStatus::Cancelled, now)?;
+        self.record_event("cancelled", actor, now, before);
+        Ok(())
+    }
+
+    fn transition_to(&mut self, next: AccessRequestStatus, now: DateTime) -> DomainResult<()> {
+        if !self.status.can_transition_to(&next) {
+            return Err(AccessRequestError::InvalidStatusTransition {
+                from: self.status,
+                to: next,
+            }
+            .into());
+        }
+        self.status = next;
+        self.updated_at = now;
+        self.version += 1;
+        Ok(())
+    }
+
+    fn issue_grant(&self, duration: i64, now: DateTime) -> DomainResult<TemporaryGrant> {
+        let description = Description::new(self.justification.comment())?;
+        let permission = Permission::new(
+            PermissionId::new(&format!("{}:{}", self.resource, self.action)),
+            self.resource.clone(),
+            self.action.clone(),
+            description.clone(),
+            now,
+            0,
+        );
+
+        let mut grant = TemporaryGrant::new(
+            TemporaryGrantId::new(self.id.as_str()),
+            self.requester_id.clone(),
+            self.tenant_id.clone(),
+        );
+        grant
+            .set_description(description)
+            .add_permission(permission)
+            .set_expires_at(now.plus_seconds(duration))
+            .set_created_at(now);
+        grant.build()
+    }
+
+    // Geters
+    pub fn id(&self) -> &AccessRequestId {
+        &self.id
+    }
+
+    pub fn requester_id(&self) -> &UserId {
+        &self.requester_id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+
+    pub fn justification(&self) -> &Comment {
+        &self.justification
+    }
+
+    /// Requested grant lifetime in seconds.
+    pub fn requested_duration(&self) -> i64 {
+        self.requested_duration
+    }
+
+    pub fn status(&self) -> &AccessRequestStatus {
+        &self.status
+    }
+
+    pub fn decided_by(&self) -> &Option<UserId> {
+        &self.decided_by
+    }
+
+    pub fn decision_reason(&self) -> &Option<Comment> {
+        &self.decision_reason
+    }
+
+    pub fn grant_id(&self) -> &Option<TemporaryGrantId> {
+        &self.grant_id
+    }
+
+    pub fn created_at(&self) -> &DateTime {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime {
+        &self.updated_at
+    }
+
+    pub fn version(&self) -> &u64 {
+        &self.version
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessRequestBuilder {
+    id: AccessRequestId,
+    requester_id: UserId,
+    tenant_id: TenantId,
+    resource: Option<Resource>,
+    action: Option<Action>,
+    justification: Option<Comment>,
+    requested_duration: Option<i64>,
+    status: Option<AccessRequestStatus>,
+    decided_by: Option<UserId>,
+    decision_reason: Option<Comment>,
+    grant_id: Option<TemporaryGrantId>,
+    created_at: Option<DateTime>,
+    version: u64,
+}
+
+impl AccessRequestBuilder {
+    pub fn new(id: AccessRequestId, requester_id: UserId, tenant_id: TenantId) -> Self {
+        Self {
+            id,
+            requester_id,
+            tenant_id,
+            resource: None,
+            action: None,
+            justification: None,
+            requested_duration: None,
+            status: None,
+            decided_by: None,
+            decision_reason: None,
+            grant_id: None,
+            created_at: None,
+            version: 1,
+        }
+    }
+
+    pub fn set_resource(&mut self, resource: Resource) -> &mut Self {
+        self.resource = Some(resource);
+        self
+    }
+    pub fn set_action(&mut self, action: Action) -> &mut Self {
+        self.action = Some(action);
+        self
+    }
+    pub fn set_justification(&mut self, justification: Comment) -> &mut Self {
+        self.justification = Some(justification);
+        self
+    }
+    pub fn set_requested_duration(&mut self, requested_duration: i64) -> &mut Self {
+        self.requested_duration = Some(requested_duration);
+        self
+    }
+    pub fn set_status(&mut self, status: AccessRequestStatus) -> &mut Self {
+        self.status = Some(status);
+        self
+    }
+    pub fn set_decided_by(&mut self, decided_by: UserId) -> &mut Self {
+        self.decided_by = Some(decided_by);
+        self
+    }
+    pub fn set_decision_reason(&mut self, decision_reason: Comment) -> &mut Self {
+        self.decision_reason = Some(decision_reason);
+        self
+    }
+    pub fn set_grant_id(&mut self, grant_id: TemporaryGrantId) -> &mut Self {
+        self.grant_id = Some(grant_id);
+        self
+    }
+    pub fn set_created_at(&mut self, created_at: DateTime) -> &mut Self {
+        self.created_at = Some(created_at);
+        self
+    }
+    pub fn set_version(&mut self, version: u64) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    pub fn build(self, updated_at: DateTime) -> DomainResult<AccessRequest> {
+        let requested_duration = self.requested_duration.ok_or(DomainError::ValidationError(
+            "Requested Duration not found".into(),
+        ))?;
+        if requested_duration <= 0 {
+            return Err(AccessRequestError::InvalidDuration.into());
+        }
+
+        Ok(AccessRequest {
+            id: self.id,
+            requester_id: self.requester_id,
+            tenant_id: self.tenant_id,
+            resource: self
+                .resource
+                .ok_or(DomainError::ValidationError("Resource not found".into()))?,
+            action: self
+                .action
+                .ok_or(DomainError::ValidationError("Action not found".into()))?,
+            justification: self.justification.ok_or(DomainError::ValidationError(
+                "Justification not found".into(),
+            ))?,
+            requested_duration,
+            status: self.status.unwrap_or_default(),
+            decided_by: self.decided_by,
+            decision_reason: self.decision_reason,
+            grant_id: self.grant_id,
+            created_at: self.created_at.unwrap_or(updated_at),
+            updated_at,
+            version: self.version,
+            events: PendingEvents::new(),
+        })
+    }
+}
+
+impl Event for AccessRequest {
+    fn get_type(&self) -> &str {
+        "ACCESSREQUEST"
+    }
+}
+
+impl AggregateRoot for AccessRequest {
+    const TABLE: Table = Table::AccessRequest;
+
+    fn aggregate_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
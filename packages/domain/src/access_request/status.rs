@@ -0,0 +1,33 @@
+use std::fmt;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessRequestStatus {
+    #[default]
+    Pending,
+    Approved,
+    Denied,
+    Cancelled,
+}
+
+impl AccessRequestStatus {
+    /// A request is decided once; every status but `Pending` is final.
+    pub fn can_transition_to(&self, next: &AccessRequestStatus) -> bool {
+        matches!(self, AccessRequestStatus::Pending)
+            && !matches!(next, AccessRequestStatus::Pending)
+    }
+
+    pub fn is_final(&self) -> bool {
+        !matches!(self, AccessRequestStatus::Pending)
+    }
+}
+
+impl fmt::Display for AccessRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessRequestStatus::Pending => write!(f, "pending"),
+            AccessRequestStatus::Approved => write!(f, "approved"),
+            AccessRequestStatus::Denied => write!(f, "denied"),
+            AccessRequestStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
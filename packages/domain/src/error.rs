@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::SharedStr;
+use crate::{tenant::TenantStatus, AccessRequestStatus, ReportStatus, SharedStr};
 
 #[derive(Error, Debug)]
 pub enum DomainError {
@@ -16,6 +16,15 @@ pub enum DomainError {
     #[error("Permission error: {0}")]
     PermissionError(#[from] PermissionError),
 
+    #[error("Access request error: {0}")]
+    AccessRequestError(#[from] AccessRequestError),
+
+    #[error("Membership error: {0}")]
+    MembershipError(#[from] MembershipError),
+
+    #[error("Tenant error: {0}")]
+    TenantError(#[from] TenantError),
+
     #[error("Validation error: {0}")]
     ValidationError(SharedStr),
 
@@ -34,6 +43,12 @@ pub enum RoleError {
     #[error("Invalid role ID: {0}")]
     InvalidRoleId(SharedStr),
 
+    #[error("Role {0} would inherit from itself")]
+    CyclicHierarchy(SharedStr),
+
+    #[error("Unknown parent role: {0}")]
+    UnknownParentRole(SharedStr),
+
     #[error("Permission: {0}")]
     PermissionError(#[from] PermissionError),
 }
@@ -49,6 +64,84 @@ pub enum ReportError {
 
     #[error("Invalid report ID: {0}")]
     InvalidReportId(SharedStr),
+
+    #[error("Invalid report status transition from {from} to {to}")]
+    InvalidStatusTransition {
+        from: ReportStatus,
+        to: ReportStatus,
+    },
+
+    #[error("A report cannot be shared with the tenant that owns it")]
+    ShareWithOwner,
+
+    #[error("Report is not shared with tenant {0}")]
+    NotShared(SharedStr),
+
+    #[error("The author of a report cannot review it")]
+    AuthorAsReviewer,
+
+    #[error("User {0} is not an assigned reviewer of the report")]
+    NotReviewer(SharedStr),
+}
+
+#[derive(Error, Debug)]
+pub enum AccessRequestError {
+    #[error("Access request not found")]
+    NotFound,
+
+    #[error("Requested access duration must be positive")]
+    InvalidDuration,
+
+    #[error("An access request cannot be decided by its requester")]
+    SelfDecision,
+
+    #[error("Only the requester can cancel an access request")]
+    NotRequester,
+
+    #[error("Invalid access request status transition from {from} to {to}")]
+    InvalidStatusTransition {
+        from: AccessRequestStatus,
+        to: AccessRequestStatus,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum MembershipError {
+    #[error("Membership not found")]
+    NotFound,
+
+    #[error("User {user} is already a member of tenant {tenant}")]
+    AlreadyMember { user: SharedStr, tenant: SharedStr },
+
+    #[error("Tenant has reached its limit of {0} members")]
+    MemberLimitReached(u32),
+
+    #[error("Membership is not an open invitation")]
+    NotInvited,
+
+    #[error("Only the invited user can accept an invitation")]
+    NotInvitee,
+}
+
+#[derive(Error, Debug)]
+pub enum TenantError {
+    #[error("Tenant not found")]
+    NotFound,
+
+    #[error("Deletion grace period cannot be negative")]
+    InvalidGracePeriod,
+
+    #[error("Tenant deletion grace period has not ended")]
+    DeletionNotDue,
+
+    #[error("Canary rollout percentage must be at most 100, got {0}")]
+    InvalidCanaryPercentage(u8),
+
+    #[error("Invalid tenant status transition from {from} to {to}")]
+    InvalidStatusTransition {
+        from: TenantStatus,
+        to: TenantStatus,
+    },
 }
 
 #[derive(Error, Debug)]
@@ -2,11 +2,33 @@ use std::ops::Deref;
 
 use crate::{DateTime, UserId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Table {
     User,
     Role,
     Report,
+    Tenant,
+    Membership,
+    AccessRequest,
+}
+
+impl Table {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Table::User => "user",
+            Table::Role => "role",
+            Table::Report => "report",
+            Table::Tenant => "tenant",
+            Table::Membership => "membership",
+            Table::AccessRequest => "access_request",
+        }
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -88,3 +110,103 @@ impl<T: Clone + Event> DomainEvent<T> {
         &self.after
     }
 }
+
+/// Events recorded by an aggregate since it was loaded, waiting to be written
+/// to the outbox together with the aggregate itself.
+#[derive(Debug, Clone)]
+pub struct PendingEvents<T: Clone + Event>(Vec<DomainEvent<T>>);
+
+impl<T: Clone + Event> Default for PendingEvents<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: Clone + Event> PendingEvents<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, event: DomainEvent<T>) {
+        self.0.push(event);
+    }
+
+    pub fn take(&mut self) -> Vec<DomainEvent<T>> {
+        std::mem::take(&mut self.0)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn as_slice(&self) -> &[DomainEvent<T>] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// An aggregate whose mutations are recorded as [`DomainEvent`]s.
+pub trait AggregateRoot: Event {
+    const TABLE: Table;
+
+    /// Identifies the aggregate within its table; part of every event id.
+    fn aggregate_id(&self) -> String;
+    fn aggregate_version(&self) -> u64;
+    fn bump_version(&mut self);
+    fn events(&self) -> &PendingEvents<Self>;
+    fn events_mut(&mut self) -> &mut PendingEvents<Self>;
+
+    fn pending_events(&self) -> &[DomainEvent<Self>] {
+        self.events().as_slice()
+    }
+
+    fn take_pending_events(&mut self) -> Vec<DomainEvent<Self>> {
+        self.events_mut().take()
+    }
+
+    /// The aggregate without its pending events, as stored in an event's
+    /// before and after state.
+    fn snapshot(&self) -> Self {
+        let mut snapshot = self.clone();
+        snapshot.events_mut().clear();
+        snapshot
+    }
+
+    /// Records `action` with the state `before` it and the current state
+    /// after it. Callers bump the version first, so event ids stay unique.
+    fn record_event(&mut self, action: &str, actor: &UserId, now: DateTime, before: Self) {
+        let id = format!(
+            "{}:{}:{}:{}",
+            Self::TABLE,
+            self.aggregate_id(),
+            self.aggregate_version(),
+            action
+        );
+        let event = DomainEvent::new(
+            &id,
+            Self::TABLE,
+            action.into(),
+            actor.clone(),
+            now,
+            before,
+            self.snapshot(),
+        );
+        self.events_mut().record(event);
+    }
+
+    fn mark_created(&mut self, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.record_event("created", actor, now, before);
+    }
+
+    /// Deleting moves the version on like any other change, so the save
+    /// that writes the event passes the optimistic version check.
+    fn mark_deleted(&mut self, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.bump_version();
+        self.record_event("deleted", actor, now, before);
+    }
+}
@@ -1,4 +1,5 @@
-#![allow(dead_code)]
+#![allow(dead_code, clippy::new_ret_no_self)]
+pub mod access_request;
 pub mod error;
 pub mod events;
 pub mod membership;
@@ -14,15 +15,18 @@ pub mod user;
 pub mod value_objects;
 
 // Re-export main types
+pub use access_request::{
+    AccessRequest, AccessRequestId, AccessRequestParts, AccessRequestStatus,
+};
 pub use error::DomainError;
-pub use events::{DomainEvent, DomainEventId, Event, Table};
-pub use membership::{Membership, MembershipParts};
-pub use permissions::{Permission, PermissionId, PermissionParts};
+pub use events::{AggregateRoot, DomainEvent, DomainEventId, Event, PendingEvents, Table};
+pub use membership::{Membership, MembershipParts, MembershipStatus};
+pub use permissions::{Permission, PermissionEffect, PermissionId, PermissionParts};
 pub use report::{Report, ReportContent, ReportId, ReportStatus, ReportType};
 pub use role::{Role, RoleId};
 pub use shared::shared_str::SharedStr;
-pub use temporary_grant::{TemporaryGrant, TemporaryGrantParts};
-pub use tenant::{Tenant, TenantId};
+pub use temporary_grant::{TemporaryGrant, TemporaryGrantId, TemporaryGrantParts};
+pub use tenant::{Tenant, TenantId, TenantStatus};
 pub use traits::{AndSpecification, Specification};
 pub use user::{User, UserId, UserProfile};
 pub use value_objects::{
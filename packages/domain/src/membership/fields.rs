@@ -5,6 +5,7 @@ pub enum MembershipField {
     UserId,
     TenetId,
     Roles,
+    Status,
     CreatedAt,
     Version,
 }
@@ -15,6 +16,7 @@ impl Field for MembershipField {
             MembershipField::UserId => "user_id",
             MembershipField::TenetId => "tenant_id",
             MembershipField::Roles => "roles",
+            MembershipField::Status => "status",
             MembershipField::CreatedAt => "created_at",
             MembershipField::Version => "version",
         }
@@ -1,6 +1,12 @@
 pub mod fields;
+pub mod status;
+
 use std::collections::HashSet;
 
+pub use status::MembershipStatus;
+
+use crate::error::{DomainResult, MembershipError};
+use crate::events::{AggregateRoot, PendingEvents, Table};
 use crate::{DateTime, Event, RoleId, UserId};
 
 use super::TenantId;
@@ -10,8 +16,10 @@ pub struct Membership {
     user_id: UserId,
     tenet_id: TenantId,
     roles: HashSet<RoleId>,
+    status: MembershipStatus,
     created_at: DateTime,
     version: u64,
+    events: PendingEvents<Membership>,
 }
 
 #[derive(Debug, Clone)]
@@ -19,6 +27,7 @@ pub struct MembershipParts {
     pub user_id: UserId,
     pub tenet_id: TenantId,
     pub roles: HashSet<RoleId>,
+    pub status: MembershipStatus,
     pub created_at: DateTime,
     pub version: u64,
 }
@@ -28,6 +37,7 @@ impl Membership {
         user_id: UserId,
         tenet_id: TenantId,
         roles: HashSet<RoleId>,
+        status: MembershipStatus,
         created_at: DateTime,
         version: u64,
     ) -> Self {
@@ -35,8 +45,10 @@ impl Membership {
             user_id,
             tenet_id,
             roles,
+            status,
             created_at,
             version,
+            events: PendingEvents::new(),
         }
     }
 
@@ -45,13 +57,16 @@ impl Membership {
             user_id,
             tenet_id,
             roles,
+            status,
             created_at,
             version,
+            events: _,
         } = self;
         MembershipParts {
             user_id,
             tenet_id,
             roles,
+            status,
             created_at,
             version,
         }
@@ -61,6 +76,69 @@ impl Membership {
         self.roles.iter().any(|p| p == role_id)
     }
 
+    pub fn belongs_to(&self, tenant: &TenantId) -> bool {
+        &self.tenet_id == tenant
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == MembershipStatus::Active
+    }
+
+    // Mutations
+    pub fn assign_role(&mut self, role_id: RoleId, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        if self.roles.insert(role_id) {
+            self.version += 1;
+            self.record_event("role_assigned", actor, now, before);
+        }
+    }
+
+    pub fn revoke_role(&mut self, role_id: &RoleId, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        if self.roles.remove(role_id) {
+            self.version += 1;
+            self.record_event("role_revoked", actor, now, before);
+        }
+    }
+
+    /// Replaces the member's roles with `roles`.
+    pub fn set_roles(&mut self, roles: HashSet<RoleId>, actor: &UserId, now: DateTime) {
+        if self.roles == roles {
+            return;
+        }
+        let before = self.snapshot();
+        self.roles = roles;
+        self.version += 1;
+        self.record_event("roles_changed", actor, now, before);
+    }
+
+    /// Turns an invitation into an active membership. Only the invited user
+    /// can accept it.
+    pub fn accept(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        if actor != &self.user_id {
+            return Err(MembershipError::NotInvitee.into());
+        }
+        if self.status != MembershipStatus::Invited {
+            return Err(MembershipError::NotInvited.into());
+        }
+
+        let before = self.snapshot();
+        self.status = MembershipStatus::Active;
+        self.version += 1;
+        self.record_event("accepted", actor, now, before);
+        Ok(())
+    }
+
+    pub fn archive(&mut self, actor: &UserId, now: DateTime) {
+        if self.status == MembershipStatus::Archived {
+            return;
+        }
+        let before = self.snapshot();
+        self.status = MembershipStatus::Archived;
+        self.version += 1;
+        self.record_event("archived", actor, now, before);
+    }
+
     pub fn tenet_id(&self) -> &TenantId {
         &self.tenet_id
     }
@@ -72,6 +150,10 @@ impl Membership {
         &self.roles
     }
 
+    pub fn status(&self) -> &MembershipStatus {
+        &self.status
+    }
+
     pub fn created_at(&self) -> &DateTime {
         &self.created_at
     }
@@ -86,3 +168,27 @@ impl Event for Membership {
         "MEMBERSHIP"
     }
 }
+
+impl AggregateRoot for Membership {
+    const TABLE: Table = Table::Membership;
+
+    fn aggregate_id(&self) -> String {
+        format!("{}:{}", self.tenet_id, self.user_id)
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
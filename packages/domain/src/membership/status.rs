@@ -0,0 +1,21 @@
+use std::fmt;
+
+/// An invited member holds a seat in the tenant but only becomes active once
+/// they accept the invitation. Memberships of a deleted tenant are archived.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MembershipStatus {
+    Invited,
+    #[default]
+    Active,
+    Archived,
+}
+
+impl fmt::Display for MembershipStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MembershipStatus::Invited => write!(f, "invited"),
+            MembershipStatus::Active => write!(f, "active"),
+            MembershipStatus::Archived => write!(f, "archived"),
+        }
+    }
+}
@@ -35,11 +35,30 @@ impl std::fmt::Display for PermissionId {
     }
 }
 
+/// Whether a permission grants what it matches or explicitly takes it away.
+/// A matching deny always wins over a matching allow; see [`permits`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PermissionEffect {
+    #[default]
+    Allow,
+    Deny,
+}
+
+impl std::fmt::Display for PermissionEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionEffect::Allow => write!(f, "allow"),
+            PermissionEffect::Deny => write!(f, "deny"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct Permission {
     id: PermissionId,
     resource: Resource,
     action: Action,
+    effect: PermissionEffect,
     description: Description,
     created_at: DateTime,
     version: u64,
@@ -50,6 +69,7 @@ pub struct PermissionParts {
     pub id: PermissionId,
     pub resource: Resource,
     pub action: Action,
+    pub effect: PermissionEffect,
     pub description: Description,
     pub created_at: DateTime,
     pub version: u64,
@@ -67,6 +87,7 @@ impl Permission {
         Self {
             resource,
             action,
+            effect: PermissionEffect::Allow,
             description,
             created_at,
             version,
@@ -78,6 +99,7 @@ impl Permission {
         let Self {
             resource,
             action,
+            effect,
             description,
             created_at,
             version,
@@ -86,6 +108,7 @@ impl Permission {
         PermissionParts {
             resource,
             action,
+            effect,
             description,
             created_at,
             version,
@@ -93,8 +116,21 @@ impl Permission {
         }
     }
 
+    /// Turns the permission into an explicit deny, or back into an allow.
+    pub fn with_effect(mut self, effect: PermissionEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Whether the permission applies to `action` on `resource`, whatever
+    /// its effect. Its resource and action are patterns; see
+    /// [`Resource::covers`] and [`Action::covers`].
     pub fn matches(&self, resource: &Resource, action: &Action) -> bool {
-        &self.resource == resource && &self.action == action
+        self.resource.covers(resource) && self.action.covers(action)
+    }
+
+    pub fn is_deny(&self) -> bool {
+        self.effect == PermissionEffect::Deny
     }
 
     pub fn id(&self) -> &PermissionId {
@@ -107,6 +143,10 @@ impl Permission {
     pub fn action(&self) -> &Action {
         &self.action
     }
+
+    pub fn effect(&self) -> PermissionEffect {
+        self.effect
+    }
     pub fn description(&self) -> &Description {
         &self.description
     }
@@ -120,8 +160,104 @@ impl Permission {
     }
 }
 
+/// Whether `permissions` together grant `action` on `resource`: at least one
+/// allow matches and no deny does.
+pub fn permits<'a>(
+    permissions: impl IntoIterator<Item = &'a Permission>,
+    resource: &Resource,
+    action: &Action,
+) -> bool {
+    effect(permissions, resource, action) == Some(PermissionEffect::Allow)
+}
+
+/// What `permissions` together say about `action` on `resource`: deny when
+/// any matching permission denies, allow when only allows match, and
+/// nothing when none matches.
+pub fn effect<'a>(
+    permissions: impl IntoIterator<Item = &'a Permission>,
+    resource: &Resource,
+    action: &Action,
+) -> Option<PermissionEffect> {
+    let mut effect = None;
+    for permission in permissions {
+        if permission.matches(resource, action) {
+            if permission.is_deny() {
+                return Some(PermissionEffect::Deny);
+            }
+            effect = Some(PermissionEffect::Allow);
+        }
+    }
+    effect
+}
+
 impl Event for Permission {
     fn get_type(&self) -> &str {
         "PERMISSION"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn permission(resource: &str, action: &str, effect: PermissionEffect) -> Permission {
+        Permission::new(
+            PermissionId::new(&format!("{resource}:{action}:{effect}")),
+            Resource::new(resource).unwrap(),
+            Action::new(action).unwrap(),
+            Description::new("permission").unwrap(),
+            DateTime::new(0),
+            1,
+        )
+        .with_effect(effect)
+    }
+
+    fn allows(permissions: &[Permission], resource: &str, action: &str) -> bool {
+        permits(
+            permissions,
+            &Resource::new(resource).unwrap(),
+            &Action::new(action).unwrap(),
+        )
+    }
+
+    #[test]
+    fn nothing_is_permitted_without_a_matching_allow() {
+        assert!(!allows(&[], "report", "read"));
+        let read = [permission("report", "read", PermissionEffect::Allow)];
+        assert!(!allows(&read, "report", "update"));
+        assert!(!allows(&read, "user", "read"));
+    }
+
+    #[test]
+    fn wildcards_and_dotted_hierarchy() {
+        let all = [permission("*", "*", PermissionEffect::Allow)];
+        assert!(allows(&all, "report.content", "delete"));
+
+        let report = [permission("report", "*", PermissionEffect::Allow)];
+        assert!(allows(&report, "report", "update"));
+        assert!(allows(&report, "report.content.comments", "read"));
+        assert!(!allows(&report, "user", "read"));
+
+        let below = [permission("report.*", "read", PermissionEffect::Allow)];
+        assert!(allows(&below, "report.content", "read"));
+        assert!(!allows(&below, "report", "read"));
+    }
+
+    #[test]
+    fn deny_wins() {
+        let permissions = [
+            permission("report", "*", PermissionEffect::Allow),
+            permission("report.content", "delete", PermissionEffect::Deny),
+        ];
+        assert!(allows(&permissions, "report.content", "read"));
+        assert!(!allows(&permissions, "report.content", "delete"));
+        assert!(!allows(&permissions, "report.content.comments", "delete"));
+        assert!(allows(&permissions, "report", "delete"));
+
+        let deny_first = [
+            permission("*", "*", PermissionEffect::Deny),
+            permission("report", "read", PermissionEffect::Allow),
+        ];
+        assert!(!allows(&deny_first, "report", "read"));
+    }
+}
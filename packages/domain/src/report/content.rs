@@ -105,9 +105,17 @@ impl ReportContent {
     pub fn rejection_reason(&self) -> &Option<Comment> {
         &self.rejection_reason
     }
+
+    pub(crate) fn set_rejection_reason(&mut self, rejection_reason: Option<Comment>) {
+        self.rejection_reason = rejection_reason;
+    }
+
+    pub(crate) fn add_review_comment(&mut self, review_comment: ReviewComment) {
+        self.review_comments.insert(review_comment);
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ReportContentBuilder {
     body: Option<Body>,
     attachments: HashSet<Url>, // URLs or paths to attachments
@@ -67,7 +67,7 @@ impl Field for ReportContentField {
         match self {
             ReportContentField::Body => "body",
             ReportContentField::Attachments => "attachments",
-            ReportContentField::ReviewComments(_) => "review_comment",
+            ReportContentField::ReviewComments(_) => "review_comments",
             ReportContentField::RejectionReason => "rejection_reason",
         }
     }
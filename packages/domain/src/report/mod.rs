@@ -10,7 +10,10 @@ pub use content::ReportContent;
 pub use report_type::ReportType;
 pub use status::ReportStatus;
 
-use crate::error::DomainResult;
+use crate::error::{DomainResult, ReportError};
+use crate::events::{AggregateRoot, PendingEvents, Table};
+use crate::report::content::ReviewComment;
+use crate::value_objects::Comment;
 use crate::value_objects::DateTime;
 use crate::value_objects::Title;
 use crate::Event;
@@ -66,6 +69,7 @@ pub struct Report {
     updated_at: DateTime,
     due_date: Option<DateTime>,
     version: u64,
+    events: PendingEvents<Report>,
 }
 
 #[derive(Debug, Clone)]
@@ -105,6 +109,7 @@ impl Report {
             updated_at,
             due_date,
             version,
+            events: _,
         } = self;
         ReportParts {
             id,
@@ -139,6 +144,142 @@ impl Report {
         self.assigned_reviewer_id.contains(user)
     }
 
+    // Lifecycle
+    pub fn submit(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(ReportStatus::Submitted, now)?;
+        self.content.set_rejection_reason(None);
+        self.record_event("submitted", actor, now, before);
+        Ok(())
+    }
+
+    pub fn start_review(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(ReportStatus::InReview, now)?;
+        self.record_event("review_started", actor, now, before);
+        Ok(())
+    }
+
+    pub fn approve(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(ReportStatus::Approved, now)?;
+        self.record_event("approved", actor, now, before);
+        Ok(())
+    }
+
+    pub fn reject(&mut self, reason: Comment, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(ReportStatus::Rejected, now)?;
+        self.content.set_rejection_reason(Some(reason));
+        self.record_event("rejected", actor, now, before);
+        Ok(())
+    }
+
+    pub fn archive(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(ReportStatus::Archived, now)?;
+        self.record_event("archived", actor, now, before);
+        Ok(())
+    }
+
+    // Sharing
+    pub fn share_with(
+        &mut self,
+        tenant: TenantId,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if self.belongs_to(&tenant) {
+            return Err(ReportError::ShareWithOwner.into());
+        }
+        if self.is_shared_with(&tenant) {
+            return Ok(());
+        }
+
+        let before = self.snapshot();
+        self.shared_with_tenants.insert(tenant);
+        self.updated_at = now;
+        self.version += 1;
+        self.record_event("shared", actor, now, before);
+        Ok(())
+    }
+
+    pub fn unshare_with(
+        &mut self,
+        tenant: &TenantId,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if !self.is_shared_with(tenant) {
+            return Err(ReportError::NotShared(tenant.as_str().into()).into());
+        }
+
+        let before = self.snapshot();
+        self.shared_with_tenants.remove(tenant);
+        self.updated_at = now;
+        self.version += 1;
+        self.record_event("unshared", actor, now, before);
+        Ok(())
+    }
+
+    // Review
+    pub fn assign_reviewer(
+        &mut self,
+        reviewer: UserId,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if self.is_author(&reviewer) {
+            return Err(ReportError::AuthorAsReviewer.into());
+        }
+        if self.is_reviewer(&reviewer) {
+            return Ok(());
+        }
+
+        let before = self.snapshot();
+        self.assigned_reviewer_id.insert(reviewer);
+        self.updated_at = now;
+        self.version += 1;
+        self.record_event("reviewer_assigned", actor, now, before);
+        Ok(())
+    }
+
+    /// Records `comment` as written by `reviewer`, who must be assigned to
+    /// the report. The reviewer is the acting subject, never a value taken
+    /// from a command.
+    pub fn add_review_comment(
+        &mut self,
+        reviewer: &UserId,
+        comment: Comment,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if !self.is_reviewer(reviewer) {
+            return Err(ReportError::NotReviewer(reviewer.as_str().into()).into());
+        }
+
+        let before = self.snapshot();
+        self.content
+            .add_review_comment(ReviewComment::new(reviewer.clone(), comment, now));
+        self.updated_at = now;
+        self.version += 1;
+        self.record_event("review_commented", reviewer, now, before);
+        Ok(())
+    }
+
+    fn transition_to(&mut self, next: ReportStatus, now: DateTime) -> DomainResult<()> {
+        if !self.status.can_transition_to(&next) {
+            return Err(ReportError::InvalidStatusTransition {
+                from: self.status,
+                to: next,
+            }
+            .into());
+        }
+        self.status = next;
+        self.updated_at = now;
+        self.version += 1;
+        Ok(())
+    }
+
     // Geters
     pub fn id(&self) -> &ReportId {
         &self.id
@@ -268,8 +409,8 @@ impl ReportBuilder {
             id: self.id,
             title: Title::new(title)?,
             content: self.content.unwrap_or_default(),
-            report_type: self.report_type.unwrap_or(ReportType::default()),
-            status: self.status.unwrap_or(ReportStatus::default()),
+            report_type: self.report_type.unwrap_or_default(),
+            status: self.status.unwrap_or_default(),
             author_id: self.author_id,
             assigned_reviewer_id: self.reviewer_id,
             created_at: self.created_at.unwrap_or(updated_at),
@@ -278,6 +419,7 @@ impl ReportBuilder {
             version: self.version,
             owner_tenant: self.owner_tenant,
             shared_with_tenants: self.shared_with_tenants,
+            events: PendingEvents::new(),
         })
     }
 }
@@ -286,3 +428,27 @@ impl Event for Report {
         "REPORT"
     }
 }
+
+impl AggregateRoot for Report {
+    const TABLE: Table = Table::Report;
+
+    fn aggregate_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ReportStatus {
     Draft,
     #[default]
@@ -11,6 +11,22 @@ pub enum ReportStatus {
     Archived,
 }
 
+impl ReportStatus {
+    pub fn can_transition_to(&self, next: &ReportStatus) -> bool {
+        match (self, next) {
+            (ReportStatus::Draft | ReportStatus::Rejected, ReportStatus::Submitted) => true,
+            (ReportStatus::Submitted, ReportStatus::InReview) => true,
+            (ReportStatus::InReview, ReportStatus::Approved | ReportStatus::Rejected) => true,
+            (from, ReportStatus::Archived) => !from.is_final(),
+            _ => false,
+        }
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self, ReportStatus::Archived)
+    }
+}
+
 impl fmt::Display for ReportStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -23,3 +39,42 @@ impl fmt::Display for ReportStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ReportStatus::{self, *};
+
+    const ALL: [ReportStatus; 6] = [Draft, Submitted, InReview, Approved, Rejected, Archived];
+
+    #[test]
+    fn transition_table() {
+        let allowed = [
+            (Draft, Submitted),
+            (Rejected, Submitted),
+            (Submitted, InReview),
+            (InReview, Approved),
+            (InReview, Rejected),
+            (Draft, Archived),
+            (Submitted, Archived),
+            (InReview, Archived),
+            (Approved, Archived),
+            (Rejected, Archived),
+        ];
+        for from in ALL {
+            for next in ALL {
+                assert_eq!(
+                    from.can_transition_to(&next),
+                    allowed.contains(&(from, next)),
+                    "{from} -> {next}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn only_archived_is_final() {
+        for status in ALL {
+            assert_eq!(status.is_final(), status == Archived, "{status}");
+        }
+    }
+}
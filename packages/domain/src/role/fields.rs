@@ -6,6 +6,7 @@ pub enum RoleField {
     Name,
     Description,
     Permissions(crate::permissions::fields::PermissionField),
+    ParentRoles,
     IsSystemRole,
     CreatedAt,
     Version,
@@ -18,6 +19,7 @@ impl Field for RoleField {
             RoleField::Name => "name",
             RoleField::Description => "description",
             RoleField::Permissions(_) => "permissions",
+            RoleField::ParentRoles => "parent_roles",
             RoleField::IsSystemRole => "is_system_role",
             RoleField::CreatedAt => "created_at",
             RoleField::Version => "version",
@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::permissions::effect;
+use crate::value_objects::{Action, Resource};
+use crate::{Permission, PermissionEffect};
+
+use super::{Role, RoleId};
+
+/// A set of roles indexed by id, used to walk `parent_roles`. Walks visit
+/// each role once, so a stored cycle never loops forever, and skip parents
+/// that are not part of the hierarchy.
+#[derive(Debug, Clone, Default)]
+pub struct RoleHierarchy {
+    roles: HashMap<RoleId, Role>,
+}
+
+impl RoleHierarchy {
+    pub fn new(roles: impl IntoIterator<Item = Role>) -> Self {
+        Self {
+            roles: roles
+                .into_iter()
+                .map(|role| (role.id().clone(), role))
+                .collect(),
+        }
+    }
+
+    pub fn insert(&mut self, role: Role) {
+        self.roles.insert(role.id().clone(), role);
+    }
+
+    pub fn get(&self, id: &RoleId) -> Option<&Role> {
+        self.roles.get(id)
+    }
+
+    pub fn contains(&self, id: &RoleId) -> bool {
+        self.roles.contains_key(id)
+    }
+
+    /// Every role `role` inherits from, nearest first. `role` itself is not
+    /// included.
+    pub fn ancestors(&self, role: &Role) -> Vec<&Role> {
+        let mut visited = HashSet::from([role.id().clone()]);
+        let mut queue: VecDeque<&RoleId> = role.parent_roles().iter().collect();
+        let mut ancestors = Vec::new();
+
+        while let Some(id) = queue.pop_front() {
+            if !visited.insert(id.clone()) {
+                continue;
+            }
+            if let Some(parent) = self.roles.get(id) {
+                queue.extend(parent.parent_roles().iter());
+                ancestors.push(parent);
+            }
+        }
+        ancestors
+    }
+
+    /// The permissions of `role` together with those of all its ancestors.
+    pub fn effective_permissions(&self, role: &Role) -> HashSet<Permission> {
+        let mut permissions = role.permissions().clone();
+        for parent in self.ancestors(role) {
+            permissions.extend(parent.permissions().iter().cloned());
+        }
+        permissions
+    }
+
+    /// Whether `roles` together, directly or through inheritance, grant
+    /// `action` on `resource`. A deny from any of them wins.
+    pub fn has_permission<'a>(
+        &self,
+        roles: impl IntoIterator<Item = &'a Role>,
+        resource: &Resource,
+        action: &Action,
+    ) -> bool {
+        self.effect(roles, resource, action) == Some(PermissionEffect::Allow)
+    }
+
+    /// What `roles` together, directly or through inheritance, say about
+    /// `action` on `resource`; see [`effect`].
+    pub fn effect<'a>(
+        &self,
+        roles: impl IntoIterator<Item = &'a Role>,
+        resource: &Resource,
+        action: &Action,
+    ) -> Option<PermissionEffect> {
+        let permissions: HashSet<Permission> = roles
+            .into_iter()
+            .flat_map(|role| self.effective_permissions(role))
+            .collect();
+        effect(&permissions, resource, action)
+    }
+}
@@ -1,14 +1,18 @@
 pub mod fields;
+pub mod hierarchy;
 pub mod relations;
 
 use std::collections::HashSet;
 use std::ops::{Deref, DerefMut};
 
+use super::permissions::permits;
 pub use super::permissions::Permission;
+pub use hierarchy::RoleHierarchy;
 
-use crate::error::DomainResult;
+use crate::error::{DomainResult, RoleError};
+use crate::events::{AggregateRoot, PendingEvents, Table};
 use crate::value_objects::{Action, DateTime, Description, Resource};
-use crate::{DomainError, Event, Name};
+use crate::{DomainError, Event, Name, PermissionId, UserId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RoleId(String);
@@ -45,9 +49,11 @@ pub struct Role {
     name: Name,
     description: Description,
     permissions: HashSet<Permission>,
+    parent_roles: HashSet<RoleId>,
     is_system_role: bool,
     created_at: DateTime,
     version: u64,
+    events: PendingEvents<Role>,
 }
 #[derive(Debug, Clone)]
 pub struct RoleParts {
@@ -55,6 +61,7 @@ pub struct RoleParts {
     pub name: Name,
     pub description: Description,
     pub permissions: HashSet<Permission>,
+    pub parent_roles: HashSet<RoleId>,
     pub is_system_role: bool,
     pub created_at: DateTime,
     pub version: u64,
@@ -71,28 +78,103 @@ impl Role {
             name,
             description,
             permissions,
+            parent_roles,
             is_system_role,
             created_at,
             version,
+            events: _,
         } = self;
         RoleParts {
             id,
             name,
             description,
             permissions,
+            parent_roles,
             is_system_role,
             created_at,
             version,
         }
     }
 
-    pub fn has_permission(&self, resource: &Resource, action: &Action) -> bool {
-        for permission in self.permissions.iter() {
-            if permission.matches(&resource, &action) {
-                return true;
-            }
+    /// Whether the role or any role it inherits from grants `action` on
+    /// `resource`. A deny anywhere in the hierarchy wins; parents missing
+    /// from `hierarchy` grant nothing.
+    pub fn has_permission(
+        &self,
+        resource: &Resource,
+        action: &Action,
+        hierarchy: &RoleHierarchy,
+    ) -> bool {
+        permits(&hierarchy.effective_permissions(self), resource, action)
+    }
+
+    /// Whether the role itself grants `action` on `resource`, ignoring the
+    /// roles it inherits from.
+    pub fn has_direct_permission(&self, resource: &Resource, action: &Action) -> bool {
+        permits(&self.permissions, resource, action)
+    }
+
+    pub fn inherits_from(&self, parent: &RoleId) -> bool {
+        self.parent_roles.contains(parent)
+    }
+
+    // Mutations
+    pub fn grant_permission(&mut self, permission: Permission, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        if self.permissions.insert(permission) {
+            self.version += 1;
+            self.record_event("permission_granted", actor, now, before);
+        }
+    }
+
+    pub fn revoke_permission(
+        &mut self,
+        permission_id: &PermissionId,
+        actor: &UserId,
+        now: DateTime,
+    ) {
+        let before = self.snapshot();
+        let count = self.permissions.len();
+        self.permissions.retain(|p| p.id() != permission_id);
+        if self.permissions.len() != count {
+            self.version += 1;
+            self.record_event("permission_revoked", actor, now, before);
         }
-        false
+    }
+
+    /// Makes the role inherit the permissions of `parent`. Longer cycles can
+    /// only be seen with the whole hierarchy, which the role use cases check
+    /// before saving.
+    pub fn add_parent_role(
+        &mut self,
+        parent: RoleId,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if parent == self.id {
+            return Err(RoleError::CyclicHierarchy(self.id.as_str().into()).into());
+        }
+        let before = self.snapshot();
+        if self.parent_roles.insert(parent) {
+            self.version += 1;
+            self.record_event("parent_role_added", actor, now, before);
+        }
+        Ok(())
+    }
+
+    pub fn remove_parent_role(&mut self, parent: &RoleId, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        if self.parent_roles.remove(parent) {
+            self.version += 1;
+            self.record_event("parent_role_removed", actor, now, before);
+        }
+    }
+
+    pub fn rename(&mut self, name: Name, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.name = name;
+        self.version += 1;
+        self.record_event("renamed", actor, now, before);
     }
 
     pub fn id(&self) -> &RoleId {
@@ -110,6 +192,10 @@ impl Role {
         &self.permissions
     }
 
+    pub fn parent_roles(&self) -> &HashSet<RoleId> {
+        &self.parent_roles
+    }
+
     pub fn is_system_role(&self) -> bool {
         self.is_system_role
     }
@@ -128,6 +214,7 @@ pub struct RoleBuilder {
     name: Option<Name>,
     description: Option<Description>,
     permissions: HashSet<Permission>,
+    parent_roles: HashSet<RoleId>,
     is_system_role: Option<bool>,
     created_at: Option<DateTime>,
     version: u64,
@@ -140,6 +227,7 @@ impl RoleBuilder {
             name: None,
             description: None,
             permissions: HashSet::new(),
+            parent_roles: HashSet::new(),
             is_system_role: None,
             created_at: None,
             version: 0,
@@ -164,6 +252,11 @@ impl RoleBuilder {
         self
     }
 
+    pub fn add_parent_role(&mut self, parent: RoleId) -> &mut Self {
+        self.parent_roles.insert(parent);
+        self
+    }
+
     pub fn set_is_system_role(&mut self, is_system_role: bool) -> &mut Self {
         self.is_system_role = Some(is_system_role);
         self
@@ -175,6 +268,9 @@ impl RoleBuilder {
     }
 
     pub fn build(self) -> DomainResult<Role> {
+        if self.parent_roles.contains(&self.id) {
+            return Err(RoleError::CyclicHierarchy(self.id.as_str().into()).into());
+        }
         Ok(Role {
             id: self.id,
             name: self
@@ -184,6 +280,7 @@ impl RoleBuilder {
                 .description
                 .ok_or(DomainError::ValidationError("Description not found".into()))?,
             permissions: self.permissions,
+            parent_roles: self.parent_roles,
             is_system_role: self.is_system_role.ok_or(DomainError::ValidationError(
                 "Is System Role not found".into(),
             ))?,
@@ -191,6 +288,7 @@ impl RoleBuilder {
                 .created_at
                 .ok_or(DomainError::ValidationError("Created At not found".into()))?,
             version: self.version,
+            events: PendingEvents::new(),
         })
     }
 }
@@ -200,3 +298,27 @@ impl Event for Role {
         "ROLE"
     }
 }
+
+impl AggregateRoot for Role {
+    const TABLE: Table = Table::Role;
+
+    fn aggregate_id(&self) -> String {
+        self.id.as_str().to_string()
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 pub enum RoleRelation {
     Permissions,
+    ParentRoles,
 }
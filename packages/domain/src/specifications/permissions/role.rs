@@ -2,8 +2,10 @@ use crate::{Role, Specification};
 
 use super::HasPermission;
 
+/// Checks the role's own permissions only; inherited ones need a
+/// [`crate::role::RoleHierarchy`].
 impl Specification<Role> for HasPermission {
     fn is_satisfied_by(&self, role: &Role) -> bool {
-        role.has_permission(&self.resource, &self.action)
+        role.has_direct_permission(&self.resource, &self.action)
     }
 }
@@ -2,7 +2,9 @@ use crate::traits::field::Field;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TemporaryGrantField {
+    Id,
     UserId,
+    TenantId,
     Description,
     Resource,
     Action,
@@ -14,7 +16,9 @@ pub enum TemporaryGrantField {
 impl Field for TemporaryGrantField {
     fn name(&self) -> &'static str {
         match self {
+            TemporaryGrantField::Id => "id",
             TemporaryGrantField::UserId => "user_id",
+            TemporaryGrantField::TenantId => "tenant_id",
             TemporaryGrantField::Description => "description",
             TemporaryGrantField::Resource => "resource",
             TemporaryGrantField::Action => "action",
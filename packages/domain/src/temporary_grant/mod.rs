@@ -2,13 +2,49 @@ pub mod fields;
 use std::collections::HashSet;
 
 use crate::{
+    error::DomainResult,
+    permissions::permits,
     value_objects::{Action, Resource},
-    DateTime, Description, Event, Permission, UserId,
+    DateTime, Description, DomainError, Event, Permission, TenantId, UserId,
 };
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TemporaryGrantId(String);
+
+impl TemporaryGrantId {
+    pub fn new(id: &str) -> Self {
+        Self(id.into())
+    }
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for TemporaryGrantId {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for TemporaryGrantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Extra permissions for one user within one tenant, valid until
+/// `expires_at`.
 #[derive(Debug, Clone)]
 pub struct TemporaryGrant {
+    id: TemporaryGrantId,
     user_id: UserId,
+    tenant_id: TenantId,
     description: Description,
     permissions: HashSet<Permission>,
     expires_at: DateTime,
@@ -18,7 +54,9 @@ pub struct TemporaryGrant {
 
 #[derive(Debug, Clone)]
 pub struct TemporaryGrantParts {
+    pub id: TemporaryGrantId,
     pub user_id: UserId,
+    pub tenant_id: TenantId,
     pub description: Description,
     pub permissions: HashSet<Permission>,
     pub expires_at: DateTime,
@@ -28,26 +66,18 @@ pub struct TemporaryGrantParts {
 
 impl TemporaryGrant {
     pub fn new(
+        id: TemporaryGrantId,
         user_id: UserId,
-        description: Description,
-        permissions: HashSet<Permission>,
-        expires_at: DateTime,
-        created_at: DateTime,
-        version: u64,
-    ) -> Self {
-        Self {
-            user_id,
-            expires_at,
-            description,
-            created_at,
-            permissions,
-            version,
-        }
+        tenant_id: TenantId,
+    ) -> TemporaryGrantBuilder {
+        TemporaryGrantBuilder::new(id, user_id, tenant_id)
     }
 
     pub fn into_parts(self) -> TemporaryGrantParts {
         let Self {
+            id,
             user_id,
+            tenant_id,
             description,
             permissions,
             expires_at,
@@ -55,7 +85,9 @@ impl TemporaryGrant {
             version,
         } = self;
         TemporaryGrantParts {
+            id,
             user_id,
+            tenant_id,
             description,
             permissions,
             expires_at,
@@ -65,17 +97,32 @@ impl TemporaryGrant {
     }
 
     pub fn has_permission(&self, resource: &Resource, action: &Action) -> bool {
-        for permission in self.permissions.iter() {
-            if permission.matches(&resource, &action) {
-                return true;
-            }
-        }
-        false
+        permits(&self.permissions, resource, action)
+    }
+
+    /// A grant is live strictly before `expires_at`.
+    pub fn is_active_at(&self, now: DateTime) -> bool {
+        now.is_before(&self.expires_at)
     }
 
+    /// Whether the grant is live at `now` and covers `action` on `resource`.
+    pub fn allows(&self, resource: &Resource, action: &Action, now: DateTime) -> bool {
+        self.is_active_at(now) && self.has_permission(resource, action)
+    }
+
+    pub fn belongs_to(&self, tenant: &TenantId) -> bool {
+        &self.tenant_id == tenant
+    }
+
+    pub fn id(&self) -> &TemporaryGrantId {
+        &self.id
+    }
     pub fn user_id(&self) -> &UserId {
         &self.user_id
     }
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
     pub fn description(&self) -> &Description {
         &self.description
     }
@@ -94,6 +141,77 @@ impl TemporaryGrant {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct TemporaryGrantBuilder {
+    id: TemporaryGrantId,
+    user_id: UserId,
+    tenant_id: TenantId,
+    description: Option<Description>,
+    permissions: HashSet<Permission>,
+    expires_at: Option<DateTime>,
+    created_at: Option<DateTime>,
+    version: u64,
+}
+
+impl TemporaryGrantBuilder {
+    pub fn new(id: TemporaryGrantId, user_id: UserId, tenant_id: TenantId) -> Self {
+        Self {
+            id,
+            user_id,
+            tenant_id,
+            description: None,
+            permissions: HashSet::new(),
+            expires_at: None,
+            created_at: None,
+            version: 0,
+        }
+    }
+
+    pub fn set_description(&mut self, description: Description) -> &mut Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn add_permission(&mut self, permission: Permission) -> &mut Self {
+        self.permissions.insert(permission);
+        self
+    }
+
+    pub fn set_expires_at(&mut self, expires_at: DateTime) -> &mut Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime) -> &mut Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn set_version(&mut self, version: u64) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    pub fn build(self) -> DomainResult<TemporaryGrant> {
+        Ok(TemporaryGrant {
+            id: self.id,
+            user_id: self.user_id,
+            tenant_id: self.tenant_id,
+            description: self
+                .description
+                .ok_or(DomainError::ValidationError("Description not found".into()))?,
+            permissions: self.permissions,
+            expires_at: self
+                .expires_at
+                .ok_or(DomainError::ValidationError("Expires At not found".into()))?,
+            created_at: self
+                .created_at
+                .ok_or(DomainError::ValidationError("Created At not found".into()))?,
+            version: self.version,
+        })
+    }
+}
+
 impl Event for TemporaryGrant {
     fn get_type(&self) -> &str {
         "TEMPORARYGRANT"
@@ -5,6 +5,8 @@ pub struct TenantAuthorizationConfig {
     allow_cross_tenant_access: bool,
     require_reviewer_for_publish: bool,
     default_role: Option<RoleId>,
+    access_request_approver_role: Option<RoleId>,
+    max_access_grant_duration: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -12,6 +14,8 @@ pub struct TenantAuthorizationConfigParts {
     pub allow_cross_tenant_access: bool,
     pub require_reviewer_for_publish: bool,
     pub default_role: Option<RoleId>,
+    pub access_request_approver_role: Option<RoleId>,
+    pub max_access_grant_duration: i64,
 }
 
 impl TenantAuthorizationConfig {
@@ -19,11 +23,15 @@ impl TenantAuthorizationConfig {
         allow_cross_tenant_access: bool,
         require_reviewer_for_publish: bool,
         default_role: Option<RoleId>,
+        access_request_approver_role: Option<RoleId>,
+        max_access_grant_duration: i64,
     ) -> Self {
         Self {
             allow_cross_tenant_access,
             require_reviewer_for_publish,
             default_role,
+            access_request_approver_role,
+            max_access_grant_duration,
         }
     }
 
@@ -32,11 +40,15 @@ impl TenantAuthorizationConfig {
             allow_cross_tenant_access,
             require_reviewer_for_publish,
             default_role,
+            access_request_approver_role,
+            max_access_grant_duration,
         } = self;
         TenantAuthorizationConfigParts {
             allow_cross_tenant_access,
             require_reviewer_for_publish,
             default_role,
+            access_request_approver_role,
+            max_access_grant_duration,
         }
     }
 
@@ -49,4 +61,14 @@ impl TenantAuthorizationConfig {
     pub fn default_role(&self) -> &Option<RoleId> {
         &self.default_role
     }
+    /// The role whose members approve access requests. Without one, access
+    /// requests can be filed but never approved.
+    pub fn access_request_approver_role(&self) -> &Option<RoleId> {
+        &self.access_request_approver_role
+    }
+    /// Upper bound, in seconds, on the lifetime of a grant issued by
+    /// approving an access request.
+    pub fn max_access_grant_duration(&self) -> i64 {
+        self.max_access_grant_duration
+    }
 }
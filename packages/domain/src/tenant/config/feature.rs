@@ -1,3 +1,8 @@
+use crate::{
+    error::{DomainResult, TenantError},
+    TenantId,
+};
+
 #[derive(Debug, Clone)]
 pub struct TenantFeatureConfig {
     enabled_features: Vec<Feature>,
@@ -17,12 +22,18 @@ impl TenantFeatureConfig {
         enabled_features: Vec<Feature>,
         limits: FeatureLimits,
         rollout: FeatureRolloutPolicy,
-    ) -> Self {
-        Self {
+    ) -> DomainResult<Self> {
+        if let FeatureRolloutPolicy::Canary { percentage } = rollout
+            && percentage > 100
+        {
+            return Err(TenantError::InvalidCanaryPercentage(percentage).into());
+        }
+
+        Ok(Self {
             enabled_features,
             limits,
             rollout,
-        }
+        })
     }
 
     pub fn into_parts(self) -> TenantFeatureConfigParts {
@@ -52,6 +63,41 @@ impl TenantFeatureConfig {
     pub fn is_enabled(&self, feature: Feature) -> bool {
         self.enabled_features.contains(&feature)
     }
+
+    /// Whether `feature` is live for `tenant` under the rollout policy.
+    /// `Stable` withholds beta features even when they are enabled, `Beta`
+    /// serves everything enabled and `Canary` serves an enabled feature
+    /// only to tenants whose rollout bucket falls below its percentage.
+    pub fn is_enabled_for(&self, tenant: &TenantId, feature: Feature) -> bool {
+        if !self.is_enabled(feature) {
+            return false;
+        }
+        match self.rollout {
+            FeatureRolloutPolicy::Stable => !feature.is_beta(),
+            FeatureRolloutPolicy::Beta => true,
+            FeatureRolloutPolicy::Canary { percentage } => {
+                rollout_bucket(tenant, feature) < percentage
+            }
+        }
+    }
+}
+
+/// The tenant's bucket in `0..100` for `feature`. Uses FNV-1a rather than
+/// `DefaultHasher`, whose output may change between Rust releases, so a
+/// tenant stays in or out of a canary across restarts and deployments.
+pub fn rollout_bucket(tenant: &TenantId, feature: Feature) -> u8 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = tenant
+        .as_str()
+        .bytes()
+        .chain([b':'])
+        .chain(feature.as_str().bytes())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        });
+    (hash % 100) as u8
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -63,6 +109,30 @@ pub enum Feature {
     RiskScoring,
     BetaDashboard,
 }
+
+impl Feature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Feature::AdvancedReports => "advanced_reports",
+            Feature::CrossTenantSharing => "cross_tenant_sharing",
+            Feature::AuditLogs => "audit_logs",
+            Feature::ApiAccess => "api_access",
+            Feature::RiskScoring => "risk_scoring",
+            Feature::BetaDashboard => "beta_dashboard",
+        }
+    }
+
+    /// Beta features are only served under the `Beta` and `Canary` rollouts.
+    pub fn is_beta(&self) -> bool {
+        matches!(self, Feature::BetaDashboard)
+    }
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[derive(Debug, Clone)]
 pub struct FeatureLimits {
     max_projects: u32,
@@ -105,14 +175,70 @@ impl FeatureLimits {
     pub fn max_users(&self) -> &u32 {
         &self.max_users
     }
+    /// Requests per minute for the tenant's members; 0 means unlimited.
     pub fn api_rate_limit(&self) -> &u32 {
         &self.api_rate_limit
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FeatureRolloutPolicy {
     Stable,
     Beta,
-    Canary,
+    /// Serves enabled features to roughly `percentage` percent of tenants;
+    /// at most 100.
+    Canary {
+        percentage: u8,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(rollout: FeatureRolloutPolicy) -> TenantFeatureConfig {
+        TenantFeatureConfig::new(
+            vec![Feature::AuditLogs],
+            FeatureLimits::new(1, 1, 1),
+            rollout,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rollout_bucket_is_stable_and_below_100() {
+        let tenant = TenantId::new("acme");
+        let bucket = rollout_bucket(&tenant, Feature::AuditLogs);
+        assert!(bucket < 100);
+        assert_eq!(bucket, rollout_bucket(&tenant, Feature::AuditLogs));
+        // FNV-1a of "acme:audit_logs"; changing the hash moves tenants in
+        // and out of running canaries.
+        assert_eq!(bucket, 4);
+    }
+
+    #[test]
+    fn canary_serves_tenants_below_its_percentage() {
+        let tenant = TenantId::new("acme");
+        let bucket = rollout_bucket(&tenant, Feature::AuditLogs);
+
+        let below = config(FeatureRolloutPolicy::Canary { percentage: bucket });
+        assert!(!below.is_enabled_for(&tenant, Feature::AuditLogs));
+        let above = config(FeatureRolloutPolicy::Canary {
+            percentage: bucket + 1,
+        });
+        assert!(above.is_enabled_for(&tenant, Feature::AuditLogs));
+
+        let none = config(FeatureRolloutPolicy::Canary { percentage: 0 });
+        assert!(!none.is_enabled_for(&tenant, Feature::AuditLogs));
+        let all = config(FeatureRolloutPolicy::Canary { percentage: 100 });
+        assert!(all.is_enabled_for(&tenant, Feature::AuditLogs));
+    }
+
+    #[test]
+    fn canary_percentage_is_at_most_100() {
+        let rollout = FeatureRolloutPolicy::Canary { percentage: 101 };
+        assert!(
+            TenantFeatureConfig::new(Vec::new(), FeatureLimits::new(1, 1, 1), rollout).is_err()
+        );
+    }
 }
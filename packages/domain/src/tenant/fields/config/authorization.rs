@@ -3,4 +3,6 @@ pub enum TenantAuthorizationConfigField {
     AllowCrossTenantAccess,
     RequireReviewerForPublish,
     DefaultRole,
+    AccessRequestApproverRole,
+    MaxAccessGrantDuration,
 }
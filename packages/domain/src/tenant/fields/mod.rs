@@ -9,5 +9,6 @@ pub enum TenantField {
     Description,
     CreatedAt,
     Config(TenantConfigField),
+    Status,
     Version,
 }
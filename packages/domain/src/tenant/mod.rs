@@ -1,12 +1,16 @@
 pub mod config;
 pub mod environment;
 pub mod fields;
+pub mod status;
 
-use config::TenantConfig;
+use config::{feature::Feature, TenantConfig};
+pub use status::TenantStatus;
 
 use std::ops::DerefMut;
 
-use crate::{DateTime, Description, Event, Name};
+use crate::error::{DomainResult, TenantError};
+use crate::events::{AggregateRoot, PendingEvents, Table};
+use crate::{DateTime, Description, Event, Name, UserId};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct TenantId(String);
@@ -51,7 +55,9 @@ pub struct Tenant {
     description: Description,
     created_at: DateTime,
     config: TenantConfig,
+    status: TenantStatus,
     version: u64,
+    events: PendingEvents<Tenant>,
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +67,7 @@ pub struct TenantParts {
     pub description: Description,
     pub created_at: DateTime,
     pub config: TenantConfig,
+    pub status: TenantStatus,
     pub version: u64,
 }
 
@@ -71,6 +78,7 @@ impl Tenant {
         description: Description,
         created_at: DateTime,
         config: TenantConfig,
+        status: TenantStatus,
         version: u64,
     ) -> Self {
         Self {
@@ -79,7 +87,9 @@ impl Tenant {
             description,
             created_at,
             config,
+            status,
             version,
+            events: PendingEvents::new(),
         }
     }
 
@@ -90,7 +100,9 @@ impl Tenant {
             description,
             created_at,
             config,
+            status,
             version,
+            events: _,
         } = self;
         TenantParts {
             id,
@@ -98,10 +110,107 @@ impl Tenant {
             description,
             created_at,
             config,
+            status,
             version,
         }
     }
 
+    // Mutations
+    pub fn rename(&mut self, name: Name, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.name = name;
+        self.version += 1;
+        self.record_event("renamed", actor, now, before);
+    }
+
+    pub fn change_description(&mut self, description: Description, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.description = description;
+        self.version += 1;
+        self.record_event("description_changed", actor, now, before);
+    }
+
+    pub fn update_config(&mut self, config: TenantConfig, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.config = config;
+        self.version += 1;
+        self.record_event("config_updated", actor, now, before);
+    }
+
+    /// Whether `feature` is enabled and rolled out to this tenant.
+    pub fn has_feature(&self, feature: Feature) -> bool {
+        self.config.features().is_enabled_for(&self.id, feature)
+    }
+
+    // Lifecycle
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+
+    pub fn suspend(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(TenantStatus::Suspended)?;
+        self.record_event("suspended", actor, now, before);
+        Ok(())
+    }
+
+    /// Lifts a suspension, or cancels a deletion that is still in its grace
+    /// period.
+    pub fn reactivate(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        let before = self.snapshot();
+        self.transition_to(TenantStatus::Active)?;
+        self.record_event("reactivated", actor, now, before);
+        Ok(())
+    }
+
+    /// Starts the deletion workflow; the tenant's data is archived once
+    /// `grace_period` seconds have passed.
+    pub fn schedule_deletion(
+        &mut self,
+        grace_period: i64,
+        actor: &UserId,
+        now: DateTime,
+    ) -> DomainResult<()> {
+        if grace_period < 0 {
+            return Err(TenantError::InvalidGracePeriod.into());
+        }
+        let before = self.snapshot();
+        self.transition_to(TenantStatus::PendingDeletion {
+            purge_at: now.plus_seconds(grace_period),
+        })?;
+        self.record_event("deletion_scheduled", actor, now, before);
+        Ok(())
+    }
+
+    pub fn is_purge_due(&self, now: &DateTime) -> bool {
+        matches!(self.status, TenantStatus::PendingDeletion { purge_at } if !now.is_before(&purge_at))
+    }
+
+    /// Ends the deletion workflow once the grace period is over. Archiving
+    /// the tenant's reports and memberships is up to the caller.
+    pub fn complete_deletion(&mut self, actor: &UserId, now: DateTime) -> DomainResult<()> {
+        if !self.is_purge_due(&now) {
+            return Err(TenantError::DeletionNotDue.into());
+        }
+        let before = self.snapshot();
+        self.transition_to(TenantStatus::Deleted)?;
+        self.record_event("deleted", actor, now, before);
+        Ok(())
+    }
+
+    fn transition_to(&mut self, next: TenantStatus) -> DomainResult<()> {
+        if !self.status.can_transition_to(&next) {
+            return Err(TenantError::InvalidStatusTransition {
+                from: self.status,
+                to: next,
+            }
+            .into());
+        }
+        self.status = next;
+        self.version += 1;
+        Ok(())
+    }
+
     pub fn id(&self) -> &TenantId {
         &self.id
     }
@@ -119,6 +228,12 @@ impl Tenant {
     pub fn config(&self) -> &TenantConfig {
         &self.config
     }
+    pub fn status(&self) -> &TenantStatus {
+        &self.status
+    }
+    pub fn version(&self) -> &u64 {
+        &self.version
+    }
 }
 
 impl Event for Tenant {
@@ -126,3 +241,27 @@ impl Event for Tenant {
         "TENANT"
     }
 }
+
+impl AggregateRoot for Tenant {
+    const TABLE: Table = Table::Tenant;
+
+    fn aggregate_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::DateTime;
+
+/// Only an `Active` tenant's resources can be accessed. A tenant pending
+/// deletion can still be reactivated until `purge_at`, when its data is
+/// archived and it becomes `Deleted` for good.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TenantStatus {
+    #[default]
+    Active,
+    Suspended,
+    PendingDeletion {
+        purge_at: DateTime,
+    },
+    Deleted,
+}
+
+impl TenantStatus {
+    pub fn can_transition_to(&self, next: &TenantStatus) -> bool {
+        use TenantStatus::*;
+        matches!(
+            (self, next),
+            (Active, Suspended)
+                | (Suspended, Active)
+                | (Active | Suspended, PendingDeletion { .. })
+                | (PendingDeletion { .. }, Active)
+                | (PendingDeletion { .. }, Deleted)
+        )
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, TenantStatus::Active)
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self, TenantStatus::Deleted)
+    }
+}
+
+impl fmt::Display for TenantStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TenantStatus::Active => write!(f, "active"),
+            TenantStatus::Suspended => write!(f, "suspended"),
+            TenantStatus::PendingDeletion { .. } => write!(f, "pending_deletion"),
+            TenantStatus::Deleted => write!(f, "deleted"),
+        }
+    }
+}
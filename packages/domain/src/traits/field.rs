@@ -1,4 +1,10 @@
 pub trait Field: Copy + Eq + Send + Sync + 'static {
     /// logical field name
     fn name(&self) -> &'static str;
+
+    /// dotted path from the entity root, e.g. `content.body`; fields wrapping
+    /// a nested field override it
+    fn path(&self) -> String {
+        self.name().to_string()
+    }
 }
@@ -59,7 +59,7 @@ impl Field for UserProfileField {
             UserProfileField::Website => "website",
             UserProfileField::IsDeleted => "is_deleted",
             UserProfileField::CreatedAt => "created_at",
-            UserProfileField::UpdatedAt => "update_at",
+            UserProfileField::UpdatedAt => "updated_at",
         }
     }
 }
@@ -6,6 +6,7 @@ pub use preferences::UserPreferences;
 pub use profile::UserProfile;
 
 use crate::error::DomainResult;
+use crate::events::{AggregateRoot, PendingEvents, Table};
 use crate::{DateTime, DomainError, Email, Event, Username};
 
 use std::ops::DerefMut;
@@ -58,6 +59,7 @@ pub struct User {
     locked_until: Option<DateTime>,
     last_login: Option<DateTime>,
     version: u64,
+    events: PendingEvents<User>,
 }
 #[derive(Debug, Default, Clone)]
 pub struct UserParts {
@@ -93,6 +95,7 @@ impl User {
             locked_until,
             last_login,
             version,
+            events: _,
         } = self;
         UserParts {
             id,
@@ -107,6 +110,29 @@ impl User {
             version,
         }
     }
+
+    // Mutations
+    pub fn change_status(&mut self, status: UserStatus, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.status = status;
+        self.version += 1;
+        self.record_event("status_changed", actor, now, before);
+    }
+
+    pub fn change_email(&mut self, email: Email, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.email = email;
+        self.version += 1;
+        self.record_event("email_changed", actor, now, before);
+    }
+
+    pub fn change_username(&mut self, username: Username, actor: &UserId, now: DateTime) {
+        let before = self.snapshot();
+        self.username = username;
+        self.version += 1;
+        self.record_event("username_changed", actor, now, before);
+    }
+
     // Basic getters - return references to avoid cloning
     pub fn id(&self) -> &UserId {
         &self.id
@@ -159,6 +185,17 @@ pub enum UserStatus {
     Banned,
 }
 
+impl std::fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserStatus::Active => write!(f, "active"),
+            UserStatus::Suspended => write!(f, "suspended"),
+            UserStatus::Inactive => write!(f, "inactive"),
+            UserStatus::Banned => write!(f, "banned"),
+        }
+    }
+}
+
 // Builder pattern for complex object creation
 pub struct UserBuilder {
     id: UserId,
@@ -248,6 +285,7 @@ impl UserBuilder {
             locked_until: self.locked_until,
             last_login: self.last_login,
             version: self.version,
+            events: PendingEvents::new(),
         })
     }
 }
@@ -257,3 +295,27 @@ impl Event for User {
         "USER"
     }
 }
+
+impl AggregateRoot for User {
+    const TABLE: Table = Table::User;
+
+    fn aggregate_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.version
+    }
+
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    fn events(&self) -> &PendingEvents<Self> {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut PendingEvents<Self> {
+        &mut self.events
+    }
+}
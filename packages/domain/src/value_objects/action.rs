@@ -1,17 +1,24 @@
 use std::{
-    ops::{Deref, DerefMut},
+    ops::Deref,
     str::FromStr,
 };
 
 use crate::{error::DomainResult, DomainError, SharedStr};
 
+/// An action name, or `*` for every action.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Action(SharedStr);
 
 impl Action {
+    pub const WILDCARD: &'static str = "*";
+
     pub fn new(action: &str) -> DomainResult<Self> {
         let action = action.trim();
 
+        if action == Self::WILDCARD {
+            return Ok(Self(action.into()));
+        }
+
         if action.len() < 3 {
             return Err(DomainError::ValidationError(
                 "Action must be at least 3 characters".into(),
@@ -45,6 +52,15 @@ impl Action {
     pub fn action(&self) -> &str {
         &self.0
     }
+
+    pub fn is_wildcard(&self) -> bool {
+        &*self.0 == Self::WILDCARD
+    }
+
+    /// Whether this action, read as a pattern, covers `other`.
+    pub fn covers(&self, other: &Action) -> bool {
+        self.is_wildcard() || self == other
+    }
 }
 
 impl Deref for Action {
@@ -68,3 +84,23 @@ impl FromStr for Action {
         Self::new(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Action;
+
+    fn covers(pattern: &str, action: &str) -> bool {
+        Action::new(pattern)
+            .unwrap()
+            .covers(&Action::new(action).unwrap())
+    }
+
+    #[test]
+    fn covers_only_itself_unless_wildcard() {
+        assert!(covers("read", "read"));
+        assert!(!covers("read", "update"));
+        assert!(covers("*", "read"));
+        assert!(covers("*", "delete"));
+        assert!(!covers("read", "*"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     collections::HashSet,
-    ops::{Deref, DerefMut},
+    ops::Deref,
 };
 
 use crate::{error::DomainResult, DomainError, SharedStr};
@@ -104,18 +104,21 @@ impl AddressBuilder {
         self
     }
     pub fn build(self) -> DomainResult<Address> {
-        let title = self.title.clone();
-        if title.is_none() {
-            return Err(DomainError::ValidationError(
-                "Title of address is empty".into(),
-            ));
-        } else if title.unwrap().len() > 60 {
+        let title = match self.title {
+            Some(title) => title,
+            None => {
+                return Err(DomainError::ValidationError(
+                    "Title of address is empty".into(),
+                ))
+            }
+        };
+        if title.len() > 60 {
             return Err(DomainError::ValidationError(
                 "Title of address must be < 30 char".into(),
             ));
         }
         Ok(Address {
-            title: self.title.unwrap().trim().into(),
+            title: title.trim().into(),
             street: self.street.unwrap_or("".into()),
             city: self.city.unwrap_or("".into()),
             state: self.state.unwrap_or("".into()),
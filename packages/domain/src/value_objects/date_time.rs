@@ -34,6 +34,8 @@ impl Weekday {
     }
 }
 
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
 #[derive(Debug, Clone, Copy, Hash, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DateTime(i64);
 
@@ -56,6 +58,26 @@ impl DateTime {
     pub fn between(&self, start: &DateTime, end: &DateTime) -> bool {
         self.0 >= start.0 && self.0 <= end.0
     }
+
+    pub fn plus_seconds(&self, seconds: i64) -> DateTime {
+        DateTime(self.0.saturating_add(seconds))
+    }
+
+    /// The UTC day of the week; the unix epoch fell on a Thursday.
+    pub fn weekday(&self) -> Weekday {
+        let days = self.0.div_euclid(SECONDS_PER_DAY);
+        let number = (days + 4).rem_euclid(7) as u8;
+        Weekday::all()
+            .iter()
+            .find(|weekday| weekday.number() == number)
+            .cloned()
+            .unwrap_or(Weekday::Thursday)
+    }
+
+    /// Seconds elapsed since the last UTC midnight.
+    pub fn seconds_since_midnight(&self) -> u32 {
+        self.0.rem_euclid(SECONDS_PER_DAY) as u32
+    }
 }
 
 impl std::fmt::Display for DateTime {
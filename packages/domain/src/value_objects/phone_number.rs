@@ -1,6 +1,6 @@
 use std::{
     collections::HashSet,
-    ops::{Deref, DerefMut},
+    ops::Deref,
 };
 
 use crate::{error::DomainResult, DomainError, SharedStr};
@@ -2,39 +2,60 @@ use std::{ops::Deref, str::FromStr};
 
 use crate::{error::DomainResult, DomainError, SharedStr};
 
+/// A resource name, or a pattern over resource names. Names are dotted
+/// paths such as `report.content.comments`; a segment may be `*`, which
+/// matches any single segment, or any remaining ones when it comes last.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Resource(SharedStr);
 
 impl Resource {
+    pub const WILDCARD: &'static str = "*";
+
     pub fn new(resource: &str) -> DomainResult<Self> {
         let resource = resource.trim();
 
+        if resource == Self::WILDCARD {
+            return Ok(Self(resource.into()));
+        }
+
         if resource.len() < 3 {
             return Err(DomainError::ValidationError(
                 "Resource must be at least 3 characters".into(),
             ));
         }
 
-        if resource.len() > 30 {
+        if resource.len() > 64 {
             return Err(DomainError::ValidationError(
-                "Resource must be less than 30 characters".into(),
+                "Resource must be less than 64 characters".into(),
             ));
         }
 
-        if !resource
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
-        {
-            return Err(DomainError::ValidationError(
-                "Resource can only contain alphanumeric characters, underscores, and hyphens"
-                    .into(),
-            ));
-        }
+        for segment in resource.split('.') {
+            if segment == Self::WILDCARD {
+                continue;
+            }
 
-        if resource.starts_with('_') || resource.starts_with('-') {
-            return Err(DomainError::ValidationError(
-                "Resource cannot start with underscore or hyphen".into(),
-            ));
+            if segment.is_empty() {
+                return Err(DomainError::ValidationError(
+                    "Resource cannot contain empty segments".into(),
+                ));
+            }
+
+            if !segment
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(DomainError::ValidationError(
+                    "Resource segments can only contain alphanumeric characters, underscores, and hyphens, or be `*`"
+                        .into(),
+                ));
+            }
+
+            if segment.starts_with('_') || segment.starts_with('-') {
+                return Err(DomainError::ValidationError(
+                    "Resource segments cannot start with underscore or hyphen".into(),
+                ));
+            }
         }
 
         Ok(Self(resource.into()))
@@ -43,6 +64,37 @@ impl Resource {
     pub fn resource(&self) -> &str {
         &self.0
     }
+
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.')
+    }
+
+    pub fn is_pattern(&self) -> bool {
+        self.segments().any(|segment| segment == Self::WILDCARD)
+    }
+
+    /// Whether this resource, read as a pattern, covers `other`. A resource
+    /// covers itself and everything below it, so `report` covers
+    /// `report.content.comments`; `report.*` covers everything below
+    /// `report` but not `report` itself.
+    pub fn covers(&self, other: &Resource) -> bool {
+        let mut pattern = self.segments().peekable();
+        let mut target = other.segments();
+
+        while let Some(expected) = pattern.next() {
+            let Some(actual) = target.next() else {
+                return false;
+            };
+            if expected == Self::WILDCARD {
+                if pattern.peek().is_none() {
+                    return true;
+                }
+            } else if expected != actual {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 impl Deref for Resource {
@@ -66,3 +118,36 @@ impl FromStr for Resource {
         Self::new(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Resource;
+
+    fn covers(pattern: &str, resource: &str) -> bool {
+        Resource::new(pattern)
+            .unwrap()
+            .covers(&Resource::new(resource).unwrap())
+    }
+
+    #[test]
+    fn covers_itself_and_everything_below() {
+        assert!(covers("report", "report"));
+        assert!(covers("report", "report.content"));
+        assert!(covers("report", "report.content.comments"));
+        assert!(!covers("report.content", "report"));
+        assert!(!covers("report", "reports"));
+        assert!(!covers("report.content", "report.title"));
+    }
+
+    #[test]
+    fn wildcard_segments() {
+        assert!(covers("*", "report"));
+        assert!(covers("*", "report.content.comments"));
+        assert!(covers("report.*", "report.content"));
+        assert!(covers("report.*", "report.content.comments"));
+        assert!(!covers("report.*", "report"));
+        assert!(covers("report.*.comments", "report.content.comments"));
+        assert!(!covers("report.*.comments", "report.content.attachments"));
+        assert!(!covers("report.*", "user.profile"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     borrow::Cow,
-    ops::{Deref, DerefMut},
+    ops::Deref,
 };
 
 use crate::{error::DomainResult, DomainError, SharedStr};
@@ -1,26 +1,113 @@
-use surrealdb::{engine::remote::ws::{Client, Ws}, opt::auth::Root, Surreal};
+use std::path::PathBuf;
+
+use surrealdb::{engine::any::{self, Any}, opt::auth::Root, Surreal};
 use crate::error::{InfrastructureError, InfrastructureResult};
 use surrealdb_migrations::MigrationRunner;
+
+/// Where the SurrealDB data lives.
+#[derive(Debug, Clone)]
+pub enum SurrealEngine {
+    /// A SurrealDB server reached over WebSocket, signed in as root.
+    Remote { url: String, username: String, password: String },
+    /// An embedded `kv-mem` engine; everything is gone when the client drops.
+    Memory,
+    /// An embedded engine persisted to `path`, for single-node deployments.
+    File { path: PathBuf },
+}
+
+impl SurrealEngine {
+    fn endpoint(&self) -> String {
+        match self {
+            SurrealEngine::Remote { url, .. } if url.contains("://") => url.clone(),
+            SurrealEngine::Remote { url, .. } => format!("ws://{url}"),
+            SurrealEngine::Memory => "mem://".to_string(),
+            SurrealEngine::File { path } => format!("surrealkv://{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SurrealDBConfig {
+    engine: SurrealEngine,
+    namespace: String,
+    database: String,
+}
+
+impl SurrealDBConfig {
+    pub fn new(engine: SurrealEngine) -> Self {
+        Self {
+            engine,
+            namespace: "test".to_string(),
+            database: "test".to_string(),
+        }
+    }
+
+    pub fn remote(url: &str, username: &str, password: &str) -> Self {
+        Self::new(SurrealEngine::Remote {
+            url: url.to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    pub fn memory() -> Self {
+        Self::new(SurrealEngine::Memory)
+    }
+
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self::new(SurrealEngine::File { path: path.into() })
+    }
+
+    pub fn set_namespace(&mut self, namespace: &str) -> &mut Self {
+        self.namespace = namespace.to_string();
+        self
+    }
+
+    pub fn set_database(&mut self, database: &str) -> &mut Self {
+        self.database = database.to_string();
+        self
+    }
+
+    pub fn engine(&self) -> &SurrealEngine {
+        &self.engine
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+}
+
 #[derive(Clone)]
 pub struct SurrealDBClient {
-    pub db: Surreal<Client>,
+    pub db: Surreal<Any>,
 }
 
 impl SurrealDBClient {
     pub async fn new(url: &str, user: &str, pass: &str) -> InfrastructureResult<Self> {
+        Self::connect(&SurrealDBConfig::remote(url, user, pass)).await
+    }
+
+    pub async fn connect(config: &SurrealDBConfig) -> InfrastructureResult<Self> {
         // 1. open one connection (or a pool of connections if you prefer)
-        let db = Surreal::new::<Ws>(url).await?;
+        let db = any::connect(config.engine().endpoint()).await?;
 
-        db.signin(Root {
-            username: user,
-            password: pass,
-        }).await?;
-        db.use_ns("test").use_db("test").await?;
+        // Embedded engines run without authentication
+        if let SurrealEngine::Remote { username, password, .. } = config.engine() {
+            db.signin(Root {
+                username,
+                password,
+            }).await?;
+        }
+        db.use_ns(config.namespace()).use_db(config.database()).await?;
         // Apply all migrations
         MigrationRunner::new(&db)
             .up()
             .await
-            .expect("Failed to apply migrations");
+            .map_err(|err| InfrastructureError::Repository(format!("Failed to apply migrations: {err}")))?;
         Ok(Self { db })
     }
 }
@@ -1,4 +1,15 @@
 pub mod client;
+pub mod outbox;
+pub mod page;
+pub mod projection;
+pub mod query;
 pub mod records;
 pub mod repository;
 pub mod utils;
+
+pub use ::memory;
+
+pub use memory::{
+    InMemoryEventRepository, InMemoryPolicyRepository, InMemoryReportRepository,
+    InMemoryRoleRepository, InMemoryTenantRepository, InMemoryUserRepository,
+};
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::{InfrastructureError, InfrastructureResult};
+
+use super::client::SurrealDBClient;
+
+// Statement order inside the transaction, used to pick the write result.
+const WRITE_STATEMENT: usize = 2;
+// Statement order inside the relay transaction, used to pick the batch size.
+const RELAYED_STATEMENT: usize = 2;
+
+/// Writes an aggregate record and its pending domain events in one
+/// transaction. The write is refused when the stored record already reached
+/// `version`, so a stale copy can neither overwrite newer data nor emit
+/// events for it.
+pub(crate) async fn save_with_outbox<I, R, E>(
+    client: &SurrealDBClient,
+    table: &'static str,
+    id: I,
+    version: u64,
+    record: R,
+    events: Vec<E>,
+) -> InfrastructureResult<R>
+where
+    I: Serialize + Send + 'static,
+    R: Serialize + DeserializeOwned + Send + 'static,
+    E: Serialize + Send + 'static,
+{
+    let result: Option<R> = client
+        .db
+        .query(
+            "BEGIN TRANSACTION;
+             LET $current = (SELECT VALUE version FROM ONLY type::thing($table, $id));
+             IF $current != NONE AND $current >= $version {
+                 THROW 'version conflict'
+             };
+             UPSERT type::thing($table, $id) CONTENT $record RETURN AFTER;
+             INSERT INTO outbox $outbox;
+             COMMIT TRANSACTION;",
+        )
+        .bind(("table", table))
+        .bind(("id", id))
+        .bind(("version", version))
+        .bind(("record", record))
+        .bind(("outbox", events))
+        .await?
+        .take(WRITE_STATEMENT)?;
+    result.ok_or(InfrastructureError::Repository(format!(
+        "{table} not saved!"
+    )))
+}
+
+/// Deletes an aggregate record and writes its pending domain events in one
+/// transaction. `version` is the one the deleted aggregate moved to, so the
+/// delete is refused when the stored record is missing or already newer.
+pub(crate) async fn delete_with_outbox<I, R, E>(
+    client: &SurrealDBClient,
+    table: &'static str,
+    id: I,
+    version: u64,
+    events: Vec<E>,
+) -> InfrastructureResult<R>
+where
+    I: Serialize + Send + 'static,
+    R: DeserializeOwned,
+    E: Serialize + Send + 'static,
+{
+    let result: Option<R> = client
+        .db
+        .query(
+            "BEGIN TRANSACTION;
+             LET $current = (SELECT VALUE version FROM ONLY type::thing($table, $id));
+             IF $current = NONE OR $current >= $version {
+                 THROW 'version conflict'
+             };
+             DELETE type::thing($table, $id) RETURN BEFORE;
+             INSERT INTO outbox $outbox;
+             COMMIT TRANSACTION;",
+        )
+        .bind(("table", table))
+        .bind(("id", id))
+        .bind(("version", version))
+        .bind(("outbox", events))
+        .await?
+        .take(WRITE_STATEMENT)?;
+    result.ok_or(InfrastructureError::Repository(format!(
+        "{table} not deleted!"
+    )))
+}
+
+/// Moves outbox events into `audit_log`, the rows the database audit
+/// triggers used to write. Each batch copies the oldest unprocessed events
+/// and marks them processed in one transaction; the audit row is keyed by
+/// the event id, so a batch retried after a failed commit writes every
+/// event once.
+pub struct AuditLogRelay {
+    client: SurrealDBClient,
+    batch_size: u32,
+    interval: Duration,
+}
+
+impl AuditLogRelay {
+    pub fn new(client: SurrealDBClient) -> Self {
+        Self {
+            client,
+            batch_size: 100,
+            interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn set_batch_size(&mut self, batch_size: u32) -> &mut Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn set_interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Relays one batch, returning how many events it moved.
+    pub async fn relay_batch(&self) -> InfrastructureResult<usize> {
+        let relayed: Option<usize> = self
+            .client
+            .db
+            .query(
+                "BEGIN TRANSACTION;
+                 LET $batch = (SELECT * FROM outbox WHERE processed_at = NONE ORDER BY occurred_at LIMIT $batch_size);
+                 FOR $event IN $batch {
+                     UPSERT type::thing('audit_log', record::id($event.id)) CONTENT {
+                         table: $event.table,
+                         action: $event.action,
+                         user_id: $event.user_id,
+                         before: $event.before,
+                         after: $event.after,
+                         occurred_at: $event.occurred_at
+                     };
+                     UPDATE $event.id SET processed_at = time::now();
+                 };
+                 RETURN array::len($batch);
+                 COMMIT TRANSACTION;",
+            )
+            .bind(("batch_size", self.batch_size))
+            .await?
+            .take(RELAYED_STATEMENT)?;
+        Ok(relayed.unwrap_or_default())
+    }
+
+    /// Relays batches until the outbox has no unprocessed events left,
+    /// returning how many events were moved.
+    pub async fn drain(&self) -> InfrastructureResult<usize> {
+        let mut relayed = 0;
+        loop {
+            let batch = self.relay_batch().await?;
+            relayed += batch;
+            if batch < self.batch_size as usize {
+                return Ok(relayed);
+            }
+        }
+    }
+
+    /// Drains the outbox every `interval` until a batch fails.
+    pub async fn run(&self) -> InfrastructureResult<()> {
+        let mut ticker = tokio::time::interval(self.interval);
+        loop {
+            ticker.tick().await;
+            self.drain().await?;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Keyset pages over SurrealDB tables. Rows are ordered by the sort fields
+//! and then by record id, and the page after a cursor is selected with a
+//! `WHERE` past the cursor's keys rather than a `START` offset. Missing
+//! sort fields come first, the way SurrealDB orders `NONE`.
+//!
+//! Each table lists the fields it may be sorted by as `(name, path)` pairs:
+//! callers and cursors use the name, the query reads the path.
+
+use application::{
+    error::{AppError, AppResult},
+    ports::{Cursor, Page, SortBy},
+    query::{
+        ast::{Direction, Value},
+        compiler::CompiledQuery,
+    },
+};
+
+use super::query::parameter;
+
+/// Record ids compare as plain strings, the way cursors carry them.
+const ID: &str = "meta::id(id)";
+
+/// `SELECT * FROM <table>` past `after`, one row beyond `page_size` so
+/// [`into_page`] can tell whether more follow. `scope` is a condition every
+/// row must meet, such as the subject's tenant; its parameters are bound by
+/// the caller.
+pub fn keyset_query(
+    table: &str,
+    scope: Option<&str>,
+    sortable: &[(&str, &str)],
+    sort_by: &[SortBy],
+    after: Option<&Cursor>,
+    page_size: u32,
+) -> AppResult<CompiledQuery> {
+    keyset_query_by(table, ID, scope, sortable, sort_by, after, page_size)
+}
+
+/// Like [`keyset_query`], for tables whose record ids are not plain
+/// strings: `id` is the expression rows are told apart by, and must read
+/// the way [`into_page`]'s `key` reads `id`.
+pub fn keyset_query_by(
+    table: &str,
+    id: &str,
+    scope: Option<&str>,
+    sortable: &[(&str, &str)],
+    sort_by: &[SortBy],
+    after: Option<&Cursor>,
+    page_size: u32,
+) -> AppResult<CompiledQuery> {
+    let mut order = Vec::new();
+    for sort in sort_by {
+        match sort {
+            SortBy::Ascending(field) => order.push((field_path(field, sortable)?, Direction::Ascending)),
+            SortBy::Descending(field) => order.push((field_path(field, sortable)?, Direction::Descending)),
+            SortBy::None => {}
+        }
+    }
+
+    let mut binds = Vec::new();
+    let mut text = format!("SELECT *, {id} AS cursor_id FROM {table}");
+    let mut conditions: Vec<String> = scope.map(|scope| format!("({scope})")).into_iter().collect();
+    if let Some(after) = after {
+        let keys = after.keys_for(sort_by)?;
+        if keys.len() != order.len() + 1 {
+            return Err(AppError::ValidationError("invalid cursor".to_string()));
+        }
+        let fields: Vec<(&str, Direction)> = order
+            .iter()
+            .copied()
+            .chain([(id, Direction::Ascending)])
+            .collect();
+        let mut keys_at = Vec::new();
+        for (index, key) in keys.iter().enumerate() {
+            if matches!(key, Value::Null) {
+                keys_at.push(None);
+                continue;
+            }
+            let name = format!("c{index}");
+            keys_at.push(Some(parameter(&name, key)));
+            binds.push((name, key.clone()));
+        }
+        // Past the cursor: equal on every earlier key and beyond it on this one.
+        let branches: Vec<String> = (0..fields.len())
+            .filter_map(|at| {
+                let mut terms: Vec<String> = fields[..at]
+                    .iter()
+                    .zip(&keys_at)
+                    .map(|((field, _), key)| equal(field, key.as_deref()))
+                    .collect();
+                terms.push(past(fields[at].0, fields[at].1, keys_at[at].as_deref())?);
+                Some(format!("({})", terms.join(" AND ")))
+            })
+            .collect();
+        conditions.push(format!("({})", branches.join(" OR ")));
+    }
+    if !conditions.is_empty() {
+        text.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+    }
+
+    let mut ordering: Vec<String> = order
+        .iter()
+        .map(|(field, direction)| match direction {
+            Direction::Ascending => format!("{field} ASC"),
+            Direction::Descending => format!("{field} DESC"),
+        })
+        .collect();
+    ordering.push("cursor_id ASC".to_string());
+    text.push_str(&format!(" ORDER BY {} LIMIT $limit", ordering.join(", ")));
+    binds.push(("limit".to_string(), Value::from(page_size + 1)));
+
+    Ok(CompiledQuery { text, binds })
+}
+
+/// Missing fields sort first, so a null key matches them with `NONE`.
+fn equal(field: &str, key: Option<&str>) -> String {
+    match key {
+        Some(key) => format!("{field} = {key}"),
+        None => format!("{field} = NONE"),
+    }
+}
+
+/// Rows beyond `key` in `direction`. Missing fields sort before every
+/// value, so descending past a value still reaches them, and nothing lies
+/// beyond a missing field when descending.
+fn past(field: &str, direction: Direction, key: Option<&str>) -> Option<String> {
+    match (direction, key) {
+        (Direction::Ascending, Some(key)) => Some(format!("{field} > {key}")),
+        (Direction::Descending, Some(key)) => Some(format!("({field} < {key} OR {field} = NONE)")),
+        (Direction::Ascending, None) => Some(format!("{field} != NONE")),
+        (Direction::Descending, None) => None,
+    }
+}
+
+/// Trims the extra row [`keyset_query`] asked for and points the cursor at
+/// the last row kept. `key` reads a sort field, or `id`, of a row.
+pub fn into_page<T>(
+    mut rows: Vec<T>,
+    sort_by: &[SortBy],
+    page_size: u32,
+    key: impl Fn(&T, &str) -> Value,
+) -> Page<T> {
+    let has_more = rows.len() > page_size as usize;
+    rows.truncate(page_size as usize);
+    let next_cursor = rows.last().filter(|_| has_more).map(|row| {
+        let keys = sort_by
+            .iter()
+            .filter_map(|sort| match sort {
+                SortBy::Ascending(field) | SortBy::Descending(field) => Some(key(row, field)),
+                SortBy::None => None,
+            })
+            .chain([key(row, "id")])
+            .collect();
+        Cursor::new(sort_by, keys)
+    });
+    Page {
+        items: rows,
+        next_cursor,
+        has_more,
+    }
+}
+
+/// ` ORDER BY ...` for offset pages, empty when `sort_by` sorts nothing.
+pub fn order_clause(sortable: &[(&str, &str)], sort_by: &[SortBy]) -> AppResult<String> {
+    let mut ordering = Vec::new();
+    for sort in sort_by {
+        match sort {
+            SortBy::Ascending(field) => ordering.push(format!("{} ASC", field_path(field, sortable)?)),
+            SortBy::Descending(field) => ordering.push(format!("{} DESC", field_path(field, sortable)?)),
+            SortBy::None => {}
+        }
+    }
+    if ordering.is_empty() {
+        Ok(String::new())
+    } else {
+        Ok(format!(" ORDER BY {}", ordering.join(", ")))
+    }
+}
+
+/// Sort fields end up in the query text, so only the table's own pass,
+/// read at the path they are stored at.
+fn field_path<'a>(field: &str, sortable: &[(&str, &'a str)]) -> AppResult<&'a str> {
+    sortable
+        .iter()
+        .find(|(name, _)| *name == field)
+        .map(|(_, path)| *path)
+        .ok_or_else(|| {
+            let names: Vec<&str> = sortable.iter().map(|(name, _)| *name).collect();
+            AppError::ValidationError(format!("cannot sort by {field}, expected one of {}", names.join(", ")))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SORTABLE: &[(&str, &str)] = &[("email", "email"), ("created_at", "profile.created_at")];
+
+    #[test]
+    fn sorts_only_by_listed_fields() {
+        let result = keyset_query("user", None, SORTABLE, &[SortBy::Ascending("profile.password".into())], None, 10);
+        assert!(
+            matches!(&result, Err(AppError::ValidationError(message)) if message == "cannot sort by profile.password, expected one of email, created_at"),
+            "{result:?}"
+        );
+        assert!(order_clause(SORTABLE, &[SortBy::Descending("password".into())]).is_err());
+        assert_eq!(order_clause(SORTABLE, &[SortBy::None]).unwrap(), "");
+    }
+
+    #[test]
+    fn reads_sort_fields_at_their_paths() {
+        let sort_by = [SortBy::Descending("created_at".into())];
+        let after = Cursor::new(&sort_by, vec![Value::from(5u32), Value::from("ann")]);
+        let compiled = keyset_query("user", None, SORTABLE, &sort_by, Some(&after), 10).unwrap();
+
+        assert_eq!(
+            compiled.text,
+            "SELECT *, meta::id(id) AS cursor_id FROM user \
+             WHERE (((profile.created_at < $c0 OR profile.created_at = NONE)) \
+             OR (profile.created_at = $c0 AND meta::id(id) > $c1)) \
+             ORDER BY profile.created_at DESC, cursor_id ASC LIMIT $limit"
+        );
+        assert_eq!(order_clause(SORTABLE, &sort_by).unwrap(), " ORDER BY profile.created_at DESC");
+    }
+}
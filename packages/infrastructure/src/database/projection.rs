@@ -0,0 +1,171 @@
+//! Compiles a [`Projection`] into the field list and `FETCH` clause of a
+//! SurrealQL `SELECT`, so fields a projection leaves out never leave the
+//! database.
+//!
+//! Nested fields sharing a parent are destructured under it, so
+//! `content.body` and `content.review_comments.comment` become
+//! `content.{body, review_comments.{comment}}` and keep the shape of the
+//! record, arrays included. Relations are destructured the same way and
+//! fetched: `author_id.{username}` with `FETCH author_id`.
+
+use application::projection::{
+    traits::{node::ProjectionNode, projectable::Projectable},
+    vistor::ProjectionVisitor,
+    Projection,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SurrealSelect {
+    fields: Vec<String>,
+    fetch: Vec<String>,
+}
+
+impl SurrealSelect {
+    pub fn compile<P: Projectable>(projection: &Projection<P>) -> Self {
+        let mut visitor = SurrealSelectVisitor::default();
+        projection.accept(&mut visitor);
+        visitor.select
+    }
+
+    /// A projection selecting nothing; callers should not query at all.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    pub fn fetch(&self) -> &[String] {
+        &self.fetch
+    }
+
+    /// `SELECT <fields> FROM <target> <clauses> [FETCH <relations>]`, where
+    /// `clauses` holds whatever goes between `FROM` and `FETCH`: `WHERE`,
+    /// `ORDER BY`, `LIMIT`, `START`.
+    pub fn query(&self, target: &str, clauses: &str) -> String {
+        let mut query = format!("SELECT {} FROM {}", self.fields.join(", "), target);
+        if !clauses.is_empty() {
+            query.push(' ');
+            query.push_str(clauses);
+        }
+        if !self.fetch.is_empty() {
+            query.push_str(" FETCH ");
+            query.push_str(&self.fetch.join(", "));
+        }
+        query
+    }
+}
+
+/// Field paths grouped by their leading segment, in the order first seen.
+/// Selecting a field whole makes selections of its sub-fields redundant.
+#[derive(Debug, Default)]
+struct FieldTree {
+    whole: bool,
+    children: Vec<(String, FieldTree)>,
+}
+
+impl FieldTree {
+    fn insert(&mut self, path: &str) {
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+        let index = match self.children.iter().position(|(name, _)| name == head) {
+            Some(index) => index,
+            None => {
+                self.children.push((head.to_string(), FieldTree::default()));
+                self.children.len() - 1
+            }
+        };
+        let child = &mut self.children[index].1;
+        match rest {
+            Some(rest) => child.insert(rest),
+            None => child.whole = true,
+        }
+    }
+
+    fn render(&self) -> Vec<String> {
+        self.children
+            .iter()
+            .map(|(name, child)| {
+                if child.whole || child.children.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{{{}}}", name, child.render().join(", "))
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Frame {
+    fields: FieldTree,
+    relations: Vec<String>,
+}
+
+impl Frame {
+    fn render(&self) -> Vec<String> {
+        let mut fields = self.fields.render();
+        fields.extend(self.relations.iter().cloned());
+        fields
+    }
+}
+
+#[derive(Debug, Default)]
+struct SurrealSelectVisitor {
+    /// Entities being visited, innermost last.
+    frames: Vec<Frame>,
+    /// Relations entered and not yet exited, outermost first.
+    relations: Vec<String>,
+    /// Fields of the entity exited last, waiting for its relation to close.
+    exited: Vec<String>,
+    select: SurrealSelect,
+}
+
+impl ProjectionVisitor for SurrealSelectVisitor {
+    fn enter_entity(&mut self, _entity: &str) {
+        self.frames.push(Frame::default());
+    }
+
+    fn visit_field(&mut self, field: &str) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.fields.insert(field);
+        }
+    }
+
+    fn enter_relation(&mut self, relation: &str) {
+        self.relations.push(relation.to_string());
+    }
+
+    fn exit_relation(&mut self) {
+        let Some(relation) = self.relations.pop() else {
+            return;
+        };
+        let fields = std::mem::take(&mut self.exited);
+        let item = if fields.is_empty() {
+            relation.clone()
+        } else {
+            format!("{}.{{{}}}", relation, fields.join(", "))
+        };
+        if let Some(frame) = self.frames.last_mut() {
+            frame.relations.push(item);
+        }
+
+        let mut path = self.relations.clone();
+        path.push(relation);
+        self.select.fetch.push(path.join("."));
+    }
+
+    fn exit_entity(&mut self) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+        if self.frames.is_empty() {
+            self.select.fields = frame.render();
+        } else {
+            self.exited = frame.render();
+        }
+    }
+}
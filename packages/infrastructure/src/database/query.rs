@@ -0,0 +1,208 @@
+//! Compiles a typed [`SelectAst`] into SurrealQL. Field paths come from the
+//! entities' `Field` enums and every value is bound as `$p<n>`, so the query
+//! text never holds caller input. Datetimes travel as epoch seconds and are
+//! turned back into datetimes in the query, so they compare with `datetime`
+//! fields.
+
+use application::query::{
+    ast::{Comparison, Direction, Expr, SelectAst, Sort, Value},
+    compiler::{CompiledQuery, QueryCompiler},
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SurrealCompiler;
+
+impl QueryCompiler for SurrealCompiler {
+    fn compile(&self, query: &SelectAst) -> CompiledQuery {
+        let mut binds = Binds::default();
+
+        let fields = if query.fields.is_empty() {
+            "*".to_string()
+        } else {
+            join(query.fields.iter().map(ToString::to_string))
+        };
+        let mut text = format!("SELECT {} FROM {}", fields, query.table);
+
+        if let Some(filter) = &query.filter {
+            text.push_str(" WHERE ");
+            text.push_str(&condition(filter, &mut binds));
+        }
+        if !query.order_by.is_empty() {
+            text.push_str(" ORDER BY ");
+            text.push_str(&join(query.order_by.iter().map(sort)));
+        }
+        if let Some(limit) = query.limit {
+            text.push_str(&format!(" LIMIT {}", binds.bind(limit.into())));
+        }
+        if let Some(start) = query.start {
+            text.push_str(&format!(" START {}", binds.bind(start.into())));
+        }
+
+        CompiledQuery {
+            text,
+            binds: binds.0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Binds(Vec<(String, Value)>);
+
+impl Binds {
+    /// Stores `value` and returns the expression that refers to it. Lists are
+    /// bound element by element so the datetimes in them are converted too.
+    fn bind(&mut self, value: Value) -> String {
+        if let Value::List(values) = value {
+            return format!("[{}]", join(values.into_iter().map(|value| self.bind(value))));
+        }
+        let name = format!("p{}", self.0.len());
+        let expression = parameter(&name, &value);
+        self.0.push((name, value));
+        expression
+    }
+}
+
+/// `$name`, read back as a datetime when `value` is one.
+pub fn parameter(name: &str, value: &Value) -> String {
+    match value {
+        Value::DateTime(_) => format!("time::from_secs(${name})"),
+        _ => format!("${name}"),
+    }
+}
+
+fn join(items: impl Iterator<Item = String>) -> String {
+    items.collect::<Vec<_>>().join(", ")
+}
+
+fn sort(sort: &Sort) -> String {
+    match sort.direction {
+        Direction::Ascending => format!("{} ASC", sort.field),
+        Direction::Descending => format!("{} DESC", sort.field),
+    }
+}
+
+fn condition(expr: &Expr, binds: &mut Binds) -> String {
+    match expr {
+        Expr::Compare { field, op, value } => {
+            let op = match op {
+                Comparison::Eq => "=",
+                Comparison::Ne => "!=",
+                Comparison::Lt => "<",
+                Comparison::Le => "<=",
+                Comparison::Gt => ">",
+                Comparison::Ge => ">=",
+            };
+            format!("{} {} {}", field, op, binds.bind(value.clone()))
+        }
+        Expr::In { field, values } => {
+            format!("{} INSIDE {}", field, binds.bind(Value::List(values.clone())))
+        }
+        Expr::Contains { field, value } => {
+            format!("{} CONTAINS {}", field, binds.bind(value.clone()))
+        }
+        Expr::And(exprs) if exprs.is_empty() => "true".to_string(),
+        Expr::Or(exprs) if exprs.is_empty() => "false".to_string(),
+        Expr::And(exprs) => group(exprs, " AND ", binds),
+        Expr::Or(exprs) => group(exprs, " OR ", binds),
+        Expr::Not(inner) => format!("!({})", condition(inner, binds)),
+    }
+}
+
+fn group(exprs: &[Expr], separator: &str, binds: &mut Binds) -> String {
+    let parts: Vec<String> = exprs.iter().map(|inner| condition(inner, binds)).collect();
+    format!("({})", parts.join(separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use application::{
+        projection::fields::report::RepotField,
+        query::builder::{Filter, Query},
+    };
+    use domain::{DateTime, Report, ReportStatus};
+
+    use super::*;
+
+    fn compile(query: Query<Report>) -> CompiledQuery {
+        SurrealCompiler.compile(query.ast())
+    }
+
+    fn names(compiled: &CompiledQuery) -> Vec<&str> {
+        compiled.binds.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    #[test]
+    fn selects_every_field_without_binds() {
+        let compiled = compile(Query::select());
+        assert_eq!(compiled.text, "SELECT * FROM report");
+        assert!(compiled.binds.is_empty());
+    }
+
+    #[test]
+    fn binds_every_value_in_order() {
+        let query = Query::select()
+            .field(RepotField::Title)
+            .field(RepotField::Status)
+            .filter(Filter::eq(RepotField::Status, ReportStatus::Draft))
+            .filter(Filter::gt(RepotField::Version, 1u32))
+            .order_by(RepotField::Title, Direction::Ascending)
+            .order_by(RepotField::Version, Direction::Descending)
+            .page(2, 10);
+        let compiled = compile(query);
+
+        assert_eq!(
+            compiled.text,
+            "SELECT title, status FROM report WHERE (status = $p0 AND version > $p1) \
+             ORDER BY title ASC, version DESC LIMIT $p2 START $p3"
+        );
+        assert_eq!(
+            compiled.binds,
+            [
+                ("p0".to_string(), ReportStatus::Draft.into()),
+                ("p1".to_string(), Value::Int(1)),
+                ("p2".to_string(), Value::Int(10)),
+                ("p3".to_string(), Value::Int(20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn datetimes_are_read_back_from_epoch_seconds() {
+        let query = Query::select()
+            .filter(Filter::ge(RepotField::CreatedAt, DateTime::new(100)))
+            .filter(Filter::is_in(
+                RepotField::DueDate,
+                [DateTime::new(1), DateTime::new(2)],
+            ));
+        let compiled = compile(query);
+
+        assert_eq!(
+            compiled.text,
+            "SELECT * FROM report WHERE (created_at >= time::from_secs($p0) \
+             AND due_date INSIDE [time::from_secs($p1), time::from_secs($p2)])"
+        );
+        assert_eq!(names(&compiled), ["p0", "p1", "p2"]);
+        assert_eq!(compiled.binds[0].1, Value::DateTime(DateTime::new(100)));
+        assert_eq!(compiled.binds[2].1, Value::DateTime(DateTime::new(2)));
+    }
+
+    #[test]
+    fn empty_groups_negation_and_containment() {
+        let mut ast = SelectAst::new("report");
+        ast.restrict(Expr::And(vec![
+            Expr::And(Vec::new()),
+            Expr::Not(Box::new(Expr::Or(Vec::new()))),
+            Filter::contains(RepotField::SharedWithTenants, "acme")
+                .or(Filter::ne(RepotField::Title, "Draft"))
+                .into_expr(),
+        ]));
+        let compiled = SurrealCompiler.compile(&ast);
+
+        assert_eq!(
+            compiled.text,
+            "SELECT * FROM report WHERE (true AND !(false) \
+             AND (shared_with_tenants CONTAINS $p0 OR title != $p1))"
+        );
+        assert_eq!(names(&compiled), ["p0", "p1"]);
+    }
+}
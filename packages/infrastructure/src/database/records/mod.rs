@@ -2,6 +2,7 @@ use serde::Deserialize;
 
 pub mod report;
 pub mod role;
+pub mod tenant;
 pub mod user;
 
 // 1. A private helper that can be a string OR a Thing map
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::serialization::InfrastructureTenantId;
+
+use super::IdHelper;
+
+impl<'de> Deserialize<'de> for InfrastructureTenantId {
+    fn deserialize<D>(des: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let helper = IdHelper::deserialize(des)?;
+        Ok(match helper {
+            IdHelper::String(s) => InfrastructureTenantId::new(&s),
+            IdHelper::Thing { id, .. } => InfrastructureTenantId::new(format!("{id}").as_str()),
+        })
+    }
+}
@@ -0,0 +1,238 @@
+use async_trait::async_trait;
+use application::{SubjectContex, dto::tenant::{command::MembershipCommand, view::MembershipView}, error::{AppError, AppResult}, ports::{Cursor, MembershipRepository, Page, SortBy}, query::ast::Value};
+use domain::{AggregateRoot, DomainError, Membership, TenantId, UserId, error::MembershipError};
+
+use crate::{
+    database::{client::SurrealDBClient, memory::missing, outbox::save_with_outbox, page::{into_page, keyset_query_by}, utils::now},
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::{events::InfrastructureDomainEvent, membership::InfrastructureMembership}
+};
+
+const TABLE: &str = "membership";
+/// A membership is visible to its tenant and to its user.
+const VISIBLE: &str = "tenant_id = $tenant OR user_id = $user";
+/// Keys cursors the way the in-memory repository does, `{tenant_id}:{user_id}`.
+const CURSOR_ID: &str = "string::concat(tenant_id, ':', user_id)";
+const SORTABLE: &[(&str, &str)] = &[("user_id", "user_id"), ("tenant_id", "tenant_id"), ("status", "status"), ("created_at", "created_at"), ("version", "version")];
+// Statement order inside the create and delete transactions, used to pick the write result.
+const WRITE_STATEMENT: usize = 2;
+
+/// Memberships are keyed by `[tenant_id, user_id]`. Only the membership's
+/// tenant may create or remove it; its user may save it, which is how
+/// invitations are accepted. Creating and deleting a membership also moves
+/// the tenant's `member_count`, which enforces `max_users`.
+pub struct SurrealMembershipRepository {
+    client: SurrealDBClient,
+}
+
+impl SurrealMembershipRepository {
+    pub fn new(client: SurrealDBClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, mut membership: Membership) -> AppResult<Membership> {
+        let events = membership
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureMembership>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = key(membership.tenet_id(), membership.user_id());
+        let version = membership.aggregate_version();
+        let record: InfrastructureMembership = membership.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let saved = save_with_outbox(&self.client, TABLE, id, version, record, events).await?;
+        Ok(saved.try_into()?)
+    }
+
+    /// Creates `membership` and takes a seat of its tenant in one
+    /// transaction. The seat is taken by incrementing `tenant.member_count`,
+    /// refused once it reached `max_members`, so concurrent enrolments write
+    /// the same tenant record and cannot both take the last seat.
+    async fn insert(&self, mut membership: Membership, max_members: Option<u32>) -> AppResult<Membership> {
+        let events = membership
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureMembership>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = key(membership.tenet_id(), membership.user_id());
+        let tenant = membership.tenet_id().as_str().to_string();
+        let record: InfrastructureMembership = membership.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let result: Option<InfrastructureMembership> = self
+            .client
+            .db
+            .query(
+                "BEGIN TRANSACTION;
+                 LET $seat = (UPDATE ONLY type::thing('tenant', $tenant) SET member_count += 1 WHERE $max_members = NONE OR member_count < $max_members RETURN VALUE member_count);
+                 IF $seat = NONE { THROW 'member limit reached' };
+                 CREATE type::thing('membership', $id) CONTENT $record RETURN AFTER;
+                 INSERT INTO outbox $outbox;
+                 COMMIT TRANSACTION;",
+            )
+            .bind(("tenant", tenant))
+            .bind(("max_members", max_members))
+            .bind(("id", id))
+            .bind(("record", record))
+            .bind(("outbox", events))
+            .await
+            .map_err(|err| limit_error(err.to_string(), max_members))?
+            .take(WRITE_STATEMENT)
+            .map_err(|err| limit_error(err.to_string(), max_members))?;
+        match result {
+            Some(membership) => Ok(membership.try_into()?),
+            None => Err(AppError::Repository("Membership not created!".to_string())),
+        }
+    }
+
+    async fn page(&self, ctx: &SubjectContex, condition: &str, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32, bind: (&'static str, String)) -> AppResult<Page<MembershipView>> {
+        let scope = format!("({VISIBLE}) AND {condition}");
+        let compiled = keyset_query_by(TABLE, CURSOR_ID, Some(&scope), SORTABLE, sort_by, after, page_size)?;
+        let mut request = self
+            .client
+            .db
+            .query(compiled.text)
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
+            .bind(("user", ctx.user_id.as_str().to_string()))
+            .bind(bind);
+        for (name, value) in compiled.binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureMembership> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut memberships: Vec<Membership> = Vec::new();
+        for membership in result {
+            memberships.push(membership.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
+        }
+        Ok(into_page(memberships, sort_by, page_size, sort_key).map(MembershipView::from))
+    }
+}
+
+#[async_trait]
+impl MembershipRepository for SurrealMembershipRepository {
+    async fn create(&self, ctx: SubjectContex, command: MembershipCommand) -> AppResult<MembershipView> {
+        Ok(self.insert(new_membership(&ctx, command)?, None).await?.into())
+    }
+
+    async fn create_within_limit(&self, ctx: SubjectContex, command: MembershipCommand, max_members: u32) -> AppResult<MembershipView> {
+        Ok(self.insert(new_membership(&ctx, command)?, Some(max_members)).await?.into())
+    }
+
+    async fn get(&self, ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<MembershipView> {
+        Ok(self.load(ctx, tenant_id, user_id).await?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, mut membership: Membership) -> AppResult<bool> {
+        if !membership.belongs_to(&ctx.tenant_id) {
+            return Err(AppError::Forbidden);
+        }
+        let events = membership
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureMembership>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = key(membership.tenet_id(), membership.user_id());
+        let deleted: Option<InfrastructureMembership> = self
+            .client
+            .db
+            .query(
+                "BEGIN TRANSACTION;
+                 LET $current = (SELECT VALUE version FROM ONLY type::thing('membership', $id));
+                 IF $current = NONE OR $current >= $version { THROW 'version conflict' };
+                 DELETE type::thing('membership', $id) RETURN BEFORE;
+                 UPDATE type::thing('tenant', $tenant) SET member_count -= 1;
+                 INSERT INTO outbox $outbox;
+                 COMMIT TRANSACTION;",
+            )
+            .bind(("id", id))
+            .bind(("version", membership.aggregate_version()))
+            .bind(("tenant", membership.tenet_id().as_str().to_string()))
+            .bind(("outbox", events))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(WRITE_STATEMENT)
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match deleted {
+            Some(_) => Ok(true),
+            None => Err(AppError::Repository("Membership not deleted!".to_string())),
+        }
+    }
+
+    async fn count_members(&self, ctx: SubjectContex, tenant_id: TenantId) -> AppResult<u32> {
+        if tenant_id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let result: Option<u32> = self
+            .client
+            .db
+            .query("SELECT VALUE count() FROM membership WHERE tenant_id = $tenant GROUP ALL")
+            .bind(("tenant", tenant_id.as_str().to_string()))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        Ok(result.unwrap_or_default())
+    }
+
+    async fn get_members_page(&self, ctx: SubjectContex, tenant_id: TenantId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>> {
+        // Other tenants' member lists are not visible, not forbidden.
+        let condition = if tenant_id == ctx.tenant_id { "tenant_id = $member_tenant" } else { "false" };
+        self.page(&ctx, condition, sort_by, after, page_size, ("member_tenant", tenant_id.as_str().to_string())).await
+    }
+
+    async fn get_user_tenants_page(&self, ctx: SubjectContex, user_id: UserId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>> {
+        self.page(&ctx, "user_id = $member_user", sort_by, after, page_size, ("member_user", user_id.as_str().to_string())).await
+    }
+
+    async fn load(&self, ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<Membership> {
+        let result: Option<InfrastructureMembership> = self
+            .client
+            .db
+            .query(format!("SELECT * FROM ONLY type::thing('{TABLE}', $id) WHERE {VISIBLE}"))
+            .bind(("id", key(&tenant_id, &user_id)))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
+            .bind(("user", ctx.user_id.as_str().to_string()))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match result {
+            Some(membership) => Ok(membership.try_into()?),
+            None => Err(DomainError::from(MembershipError::NotFound).into()),
+        }
+    }
+
+    async fn save(&self, ctx: SubjectContex, membership: Membership) -> AppResult<Membership> {
+        // Loading first keeps saves to existing, visible memberships.
+        self.load(ctx, membership.tenet_id().clone(), membership.user_id().clone()).await?;
+        self.write(membership).await
+    }
+}
+
+/// A new membership of the subject's tenant, with its `created` event.
+fn new_membership(ctx: &SubjectContex, command: MembershipCommand) -> AppResult<Membership> {
+    let tenant_id = command.tenet_id.clone().unwrap_or_else(|| ctx.tenant_id());
+    if tenant_id != ctx.tenant_id {
+        return Err(AppError::Forbidden);
+    }
+    let user_id = command.user_id.clone().ok_or_else(|| missing(TABLE, "user_id"))?;
+    let created_at = command.created_at.unwrap_or_else(now);
+
+    let mut membership = Membership::new(user_id, tenant_id, command.roles.unwrap_or_default(), command.status.unwrap_or_default(), created_at, 1);
+    membership.mark_created(&ctx.user_id, created_at);
+    Ok(membership)
+}
+
+fn limit_error(message: String, max_members: Option<u32>) -> AppError {
+    match max_members {
+        Some(max_members) if message.contains("member limit reached") => DomainError::from(MembershipError::MemberLimitReached(max_members)).into(),
+        _ => AppError::Repository(message),
+    }
+}
+
+fn key(tenant_id: &TenantId, user_id: &UserId) -> Vec<String> {
+    vec![tenant_id.as_str().to_string(), user_id.as_str().to_string()]
+}
+
+fn sort_key(membership: &Membership, field: &str) -> Value {
+    match field {
+        "id" => format!("{}:{}", membership.tenet_id().as_str(), membership.user_id().as_str()).into(),
+        "user_id" => membership.user_id().into(),
+        "tenant_id" => membership.tenet_id().into(),
+        "status" => membership.status().to_string().into(),
+        "created_at" => (*membership.created_at()).into(),
+        "version" => (*membership.version()).into(),
+        _ => Value::Null,
+    }
+}
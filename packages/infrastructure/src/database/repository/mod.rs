@@ -1,5 +1,7 @@
 pub mod login_attempt;
+pub mod membership;
 pub mod report;
 pub mod role;
 pub mod session;
+pub mod tenant;
 pub mod user;
@@ -1,15 +1,22 @@
 use async_trait::async_trait;
-use application::{SubjectContex, error::{AppResult, AppError}, ports::{ ReportRepository, SortBy, report::ReportQueryResult}};
-use domain::{Title,user::UserId, report::{Report, ReportId}};
+use application::{SubjectContex, dto::report::{command::ReportCommand, view::ReportView}, error::{AppResult, AppError}, ports::{ Cursor, Page, ReportRepository, SortBy, report::ReportQueryResult}, projection::{Projection, fields::report::RepotField}, query::{Filter, Query, QueryCompiler, ast::{Expr, Value}}};
+use domain::{AggregateRoot, TenantId, Title,user::UserId, report::{Report, ReportId}};
 
 use crate::{
-    database::client::SurrealDBClient, error::InfrastructureError,
+    database::{client::SurrealDBClient, memory::{missing, next_version, report::apply_command}, outbox::{delete_with_outbox, save_with_outbox}, utils::now, page::{into_page, keyset_query, order_clause}, projection::SurrealSelect, query::SurrealCompiler}, error::{InfrastructureError, InfrastructureResult},
     serialization::{
-        InfrastructureReportId,InfrastructureUserId, report::{SurrealReportResponseExt, report::InfrastructureReport}, value_objects::{InfrastructureName, InfrastructureTitle},
+        events::InfrastructureDomainEvent,
+        InfrastructureReportId,InfrastructureUserId, report::{SurrealReportResponseExt, report::InfrastructureReport, view::InfrastructureReportView}, value_objects::{InfrastructureName, InfrastructureTitle},
         
     }
 };
 
+const TABLE: &str = "report";
+/// A report is visible to its owner tenant and to the tenants it is shared
+/// with.
+const VISIBLE: &str = "owner_tenant = $tenant OR $tenant IN shared_with_tenants";
+const SORTABLE: &[(&str, &str)] = &[("title", "title"), ("status", "status"), ("created_at", "created_at"), ("updated_at", "updated_at"), ("due_date", "due_date"), ("version", "version")];
+
 pub struct SurrealReportRepository {
     client: SurrealDBClient,
 }
@@ -23,139 +30,181 @@ impl SurrealReportRepository {
 // TODO: Permission must have logic for ranking it
 #[async_trait]
 impl ReportRepository for SurrealReportRepository {
-    async fn create(&self,ctx: SubjectContex, report: Report) -> AppResult<Report>{
-        let record: InfrastructureReport = report.try_into().map_err(|_| AppError::Repository("Report Error ".to_string()))?;
-        let result: Option<InfrastructureReport> =  self
-            .client
-            .db
-            .query("LET $report_id = $uid;
-                    INSERT INTO report CONTENT $report RETURN AFTER")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("report", record))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(report) => Ok(report.try_into()?),
-            None => Err(AppError::Repository("Report not created!".to_string())),
+    async fn create(&self,ctx: SubjectContex, command: ReportCommand) -> AppResult<ReportView>{
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let owner_tenant = command.owner_tenant.clone().unwrap_or_else(|| ctx.tenant_id());
+        if owner_tenant != ctx.tenant_id {
+            return Err(AppError::Forbidden);
         }
-        
+        let author_id = command.author_id.clone().unwrap_or_else(|| ctx.user_id());
+        let now = now();
+        let mut report = apply_command(id, None, command, owner_tenant, author_id, None, now)?;
+        report.mark_created(&ctx.user_id(), now);
+        Ok(self.save(ctx, report).await?.into())
     }
+
     async fn delete(&self,ctx: SubjectContex, id: ReportId) -> AppResult<bool>{
-        let result: Option<InfrastructureReport> =  self
-            .client
-            .db
-            .query("LET $report_id = $uid;
-                    DELETE person:$id RETURN BEFORE")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", id.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(_) => Ok(true),
-            None => Err(AppError::Repository("Report not deleted!".to_string())),
+        let mut report = self.load(ctx.clone(), id).await?;
+        report.mark_deleted(&ctx.user_id(), now());
+        let events = report
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureReport>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = report.aggregate_id();
+        let version = report.aggregate_version();
+        delete_with_outbox::<_, InfrastructureReport, _>(&self.client, "report", id, version, events).await?;
+        Ok(true)
+    }
+
+    async fn update(&self,ctx: SubjectContex, command: ReportCommand) -> AppResult<ReportView>{
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let current = self.load(ctx.clone(), id.clone()).await?;
+        if !current.belongs_to(&ctx.tenant_id)
+            || command.owner_tenant.as_ref().is_some_and(|owner| owner != current.owner_tenant())
+        {
+            return Err(AppError::Forbidden);
         }
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let owner_tenant = current.owner_tenant().clone();
+        let author_id = command.author_id.clone().unwrap_or_else(|| current.author_id().clone());
+        let now = now();
+        let mut report = apply_command(id, Some(current.clone()), command, owner_tenant, author_id, Some(version), now)?;
+        report.record_event("updated", &ctx.user_id(), now, current);
+        Ok(self.save(ctx, report).await?.into())
     }
-    async fn update(&self,ctx: SubjectContex, report: Report) -> AppResult<Report>{
-        let record: InfrastructureReport = report.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
-        let result: Option<InfrastructureReport> =  self
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: ReportId) -> AppResult<ReportView>{
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn get_by_author_id(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32, auther_id: UserId) -> AppResult<Vec<ReportView>>{
+        let auther_id: InfrastructureUserId = auther_id.into();
+        let order = order_clause(SORTABLE, sort_by)?;
+        
+        let result: Vec<InfrastructureReport> =  self
             .client
             .db
-            .query("LET $report_id = $uid;
-                    UPDATE report:$id MERGE $report RETURN AFTER")
-            .bind(("report", record.clone()))
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", record.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(report) => Ok(report.try_into()?),
-            None => Err(AppError::Repository("Report not Updated!".to_string())),
+            .query(format!("SELECT * FROM report WHERE auther_id = $auther_id AND ({VISIBLE}){order} LIMIT $page_size START $start_at"))
+            .bind(("auther_id", auther_id))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
+                        .bind(("page_size", page_size))
+            .bind(("start_at", page*page_size))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut reports: Vec<ReportView> = Vec::new();
+        for report in result{
+            reports.push(Report::try_from(report).map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?.into());
         }
+        Ok(reports)
     }
-    async fn get_by_id(&self, _request_contex: SubjectContex, id: ReportId) -> AppResult<Report>{
-        let id: InfrastructureReportId = id.into();
+    
+    async fn get_by_title(&self, ctx:SubjectContex, title: Title) -> AppResult<ReportView>{
+        let title: InfrastructureTitle = title.try_into()?;
         let result: Option<InfrastructureReport> =  self
             .client
             .db
-            .query("SELECT * FROM ONLY report:$id")
-            .bind(("id", id))
+            .query(format!("SELECT * FROM report WHERE title = $title AND ({VISIBLE}) LIMIT 1"))
+            .bind(("title", title))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
             .map_err(|err| AppError::Repository(err.to_string()))?;
         match result {
-            Some(report) => Ok(report.try_into()?),
-            None => Err(AppError::Repository("Report not Updated!".to_string())),
+            Some(report) => Ok(Report::try_from(report)?.into()),
+            None => Err(AppError::Repository("Report not found!".to_string())),
         }
     }
-
-    async fn get_by_author_id(&self,ctx: SubjectContex,sort_by: &[SortBy], page: u32, page_size: u32, auther_id: UserId) -> AppResult<Vec<Report>>{
-        let auther_id: InfrastructureUserId = auther_id.into();
-        let mut order = String::new();
-        
-        for ord in sort_by{
-            order.push_str(&format!("{},", ord.as_string()));
-        }
-        if !sort_by.is_empty(){
-            order = format!("ORDER BY {}", order);
-            order.truncate(order.len() -1);
-        }
+    // TODO: Add permissions field into requert contex to be prossesed as required take into account murge all reports permessions with the report ones
+// TODO: Add permissions field into requert contex to be prossesed as required take into account murge all reports permessions with the user ones
+    async fn get_reports_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>>{
+        let order = order_clause(SORTABLE, sort_by)?;
         
         let result: Vec<InfrastructureReport> =  self
             .client
             .db
-            .query("SELECT * FROM report WHERE auther_id = $auther_id  $order LIMIT $page_size START $start_at")
-            .bind(("auther_id", auther_id))
-            .bind(("order", order))
-            .bind(("page_size", page_size))
+            .query(format!("SELECT * FROM report WHERE {VISIBLE}{order} LIMIT $page_size START $start_at"))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
+                        .bind(("page_size", page_size))
             .bind(("start_at", page*page_size))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut reports: Vec<ReportView> = Vec::new();
+        for report in result{
+            reports.push(Report::try_from(report).map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?.into());
+        }
+        Ok(reports)
+    }
+
+    async fn get_reports_page(&self,ctx: SubjectContex ,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<ReportView>>{
+        let compiled = keyset_query(TABLE, Some(VISIBLE), SORTABLE, sort_by, after, page_size)?;
+        let mut request = self.client.db.query(compiled.text).bind(("tenant", ctx.tenant_id.as_str().to_string()));
+        for (name, value) in compiled.binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureReport> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
         let mut reports: Vec<Report> = Vec::new();
         for report in result{
             reports.push(report.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
         }
-        Ok(reports)
+        Ok(into_page(reports, sort_by, page_size, sort_key).map(ReportView::from))
     }
-    
-    async fn get_by_title(&self, _request_contex:SubjectContex, title: Title) -> AppResult<Report>{
-        let title: InfrastructureTitle = title.try_into()?;
-        let result: Option<InfrastructureReport> =  self
+    async fn get_projected(&self, ctx: SubjectContex, id: ReportId, projection: &Projection<Report>) -> AppResult<ReportView>{
+        let select = SurrealSelect::compile(projection);
+        if select.is_empty() {
+            return Ok(ReportView::default());
+        }
+        let id: InfrastructureReportId = id.into();
+        let result: Option<InfrastructureReportView> =  self
             .client
             .db
-            .query("SELECT * FROM report WHERE title = $title LIMIT 1")
-            .bind(("title", title))
+            .query(select.query(&format!("ONLY type::thing('{TABLE}', $id)"), &format!("WHERE {VISIBLE}")))
+            .bind(("id", id))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
             .map_err(|err| AppError::Repository(err.to_string()))?;
         match result {
             Some(report) => Ok(report.try_into()?),
-            None => Err(AppError::Repository("Report not Updated!".to_string())),
+            None => Err(AppError::Repository("Report not found!".to_string())),
         }
     }
-    // TODO: Add permissions field into requert contex to be prossesed as required take into account murge all reports permessions with the report ones
-// TODO: Add permissions field into requert contex to be prossesed as required take into account murge all reports permessions with the user ones
-    async fn get_reports_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<Report>>{
-        let mut order = String::new();
-        
-        for ord in sort_by{
-            order.push_str(&format!("{},", ord.as_string()));
-        }
-        if !sort_by.is_empty(){
-            order = format!("ORDER BY {}", order);
-            order.truncate(order.len() -1);
+
+    async fn get_reports_paginated_projected(&self,ctx: SubjectContex, projection: &Projection<Report>, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>>{
+        let select = SurrealSelect::compile(projection);
+        if select.is_empty() {
+            return Ok(Vec::new());
         }
-        
-        let result: Vec<InfrastructureReport> =  self
+        let clauses = format!("WHERE {VISIBLE}{} LIMIT $page_size START $start_at", order_clause(SORTABLE, sort_by)?);
+        let result: Vec<InfrastructureReportView> =  self
             .client
             .db
-            .query("SELECT * FROM report $order LIMIT $page_size START $start_at")
-            .bind(("order", order))
+            .query(select.query(TABLE, &clauses))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
             .bind(("page_size", page_size))
             .bind(("start_at", page*page_size))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
-        let mut reports: Vec<Report> = Vec::new();
+        let mut reports: Vec<ReportView> = Vec::new();
+        for report in result{
+            reports.push(report.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
+        }
+        Ok(reports)
+    }
+
+    async fn query(&self,ctx: SubjectContex, query: &Query<Report>) -> AppResult<Vec<ReportView>>{
+        let mut ast = query.ast().clone();
+        ast.restrict(visible_to(&ctx.tenant_id));
+        let compiled = SurrealCompiler.compile(&ast);
+        let mut request = self.client.db.query(compiled.text);
+        for (name, value) in compiled.binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureReportView> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut reports: Vec<ReportView> = Vec::new();
         for report in result{
             reports.push(report.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
         }
         Ok(reports)
     }
+
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<ReportQueryResult>{
         let response = self.client
             .db
@@ -164,7 +213,54 @@ impl ReportRepository for SurrealReportRepository {
         // Using the extension trait
         Ok(response.into_report_result().await.map_err(|err| AppError::Repository(err.to_string()))?.try_into()?)
     }
-}
 
+    /// Only reports visible to the subject's tenant load, as for every read.
+    async fn load(&self, ctx: SubjectContex, id: ReportId) -> AppResult<Report>{
+        let id: InfrastructureReportId = id.into();
+        let result: Option<InfrastructureReport> =  self
+            .client
+            .db
+            .query(format!("SELECT * FROM ONLY type::thing('{TABLE}', $id) WHERE {VISIBLE}"))
+            .bind(("id", id))
+            .bind(("tenant", ctx.tenant_id.as_str().to_string()))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match result {
+            Some(report) => Ok(report.try_into()?),
+            None => Err(AppError::Repository("Report not found!".to_string())),
+        }
+    }
+
+    async fn save(&self, _request_contex: SubjectContex, mut report: Report) -> AppResult<Report>{
+        let events = report
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureReport>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = report.id().id().to_string();
+        let version = *report.version();
+        let record: InfrastructureReport = report.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let saved = save_with_outbox(&self.client, "report", id, version, record, events).await?;
+        Ok(saved.try_into()?)
+    }
+}
 
+/// [`VISIBLE`] as a typed filter, for queries compiled from an AST.
+fn visible_to(tenant: &TenantId) -> Expr {
+    Filter::eq(RepotField::OwnerTenant, tenant)
+        .or(Filter::contains(RepotField::SharedWithTenants, tenant))
+        .into_expr()
+}
 
+fn sort_key(report: &Report, field: &str) -> Value {
+    match field {
+        "id" => report.id().into(),
+        "title" => report.title().title().into(),
+        "status" => (*report.status()).into(),
+        "created_at" => (*report.created_at()).into(),
+        "updated_at" => (*report.updated_at()).into(),
+        "due_date" => (*report.due_date()).into(),
+        "version" => (*report.version()).into(),
+        _ => Value::Null,
+    }
+}
@@ -1,15 +1,20 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
-use application::{SubjectContex, error::{AppResult, AppError}, ports::{ SortBy, RoleRepository, role::RoleQueryResult}};
-use domain::{Name, role::{Role, RoleId}};
+use application::{SubjectContex, dto::role::{command::RoleCommand, view::RoleView}, error::{AppResult, AppError}, query::ast::Value, ports::{ Cursor, Page, SortBy, RoleRepository, role::RoleQueryResult}};
+use domain::{AggregateRoot, Name, Permission, PermissionId, role::{Role, RoleId}};
 
 use crate::{
-    database::client::SurrealDBClient, error::InfrastructureError,
+    database::{client::SurrealDBClient, memory::{missing, next_version, role::apply_command}, outbox::{delete_with_outbox, save_with_outbox}, utils::now, page::{into_page, keyset_query, order_clause}}, error::{InfrastructureError, InfrastructureResult},
     serialization::{
-        InfrastructureRoleId, role::{role::InfrastructureRole, SurrealRoleResponseExt}, value_objects::InfrastructureName,
+        events::InfrastructureDomainEvent,
+        InfrastructureRoleId, role::{permissions::InfrastructurePermission, role::InfrastructureRole, SurrealRoleResponseExt}, value_objects::InfrastructureName,
         
     }
 };
 
+const SORTABLE: &[(&str, &str)] = &[("name", "name"), ("created_at", "created_at"), ("version", "version")];
+
 pub struct SurrealRoleRepository {
     client: SurrealDBClient,
 }
@@ -18,75 +23,70 @@ impl SurrealRoleRepository {
     pub fn new(client: SurrealDBClient) -> Self {
         Self { client }
     }
+
+    /// Permissions live on the roles that grant them, so a command's
+    /// permission ids resolve against every stored role.
+    async fn resolve_permissions(&self, ids: HashSet<PermissionId>) -> AppResult<Vec<Permission>> {
+        let stored: Vec<InfrastructurePermission> = self
+            .client
+            .db
+            .query("array::distinct(array::flatten(SELECT VALUE permissions FROM role))")
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        let stored: Vec<Permission> = stored.into_iter().map(Permission::from).collect();
+        ids.into_iter()
+            .map(|id| {
+                stored
+                    .iter()
+                    .find(|permission| permission.id() == &id)
+                    .cloned()
+                    .ok_or_else(|| AppError::ValidationError(format!("unknown permission {}", id.as_str())))
+            })
+            .collect()
+    }
 }
 
 // TODO: Permission must have logic for ranking it
 #[async_trait]
 impl RoleRepository for SurrealRoleRepository {
-    async fn create(&self,ctx: SubjectContex, role: Role) -> AppResult<Role>{
-        let record: InfrastructureRole = role.try_into().map_err(|_| AppError::Repository("Role Error ".to_string()))?;
-        let result: Option<InfrastructureRole> =  self
-            .client
-            .db
-            .query("LET $role_id = $uid;
-                    INSERT INTO role CONTENT $role RETURN AFTER")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("role", record))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(role) => Ok(role.try_into()?),
-            None => Err(AppError::Repository("Role not created!".to_string())),
-        }
-        
+    async fn create(&self,ctx: SubjectContex, command: RoleCommand) -> AppResult<RoleView>{
+        let id = command.id.clone().ok_or_else(|| missing("role", "id"))?;
+        let permissions = self.resolve_permissions(command.permissions.clone()).await?;
+        let now = now();
+        let mut role = apply_command(id, None, command, permissions, None, now)?;
+        role.mark_created(&ctx.user_id(), now);
+        Ok(self.save(ctx, role).await?.into())
     }
-    async fn update(&self,ctx: SubjectContex, role: Role) -> AppResult<Role>{
-        let record: InfrastructureRole = role.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
-        let result: Option<InfrastructureRole> =  self
-            .client
-            .db
-            .query("LET $role_id = $uid;
-                    UPDATE role:$id MERGE $role RETURN AFTER")
-            .bind(("role", record.clone()))
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", record.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(role) => Ok(role.try_into()?),
-            None => Err(AppError::Repository("Role not Updated!".to_string())),
-        }
+
+    async fn update(&self,ctx: SubjectContex, command: RoleCommand) -> AppResult<RoleView>{
+        let id = command.id.clone().ok_or_else(|| missing("role", "id"))?;
+        let permissions = self.resolve_permissions(command.permissions.clone()).await?;
+        let current = self.load(ctx.clone(), id.clone()).await?;
+        let version = next_version("role", id.as_str(), *current.version(), command.version)?;
+        let now = now();
+        let mut role = apply_command(id, Some(current.clone()), command, permissions, Some(version), now)?;
+        role.record_event("updated", &ctx.user_id(), now, current);
+        Ok(self.save(ctx, role).await?.into())
     }
-    async fn get_by_id(&self, _request_contex: SubjectContex, id: RoleId) -> AppResult<Role>{
-        let id: InfrastructureRoleId = id.into();
-        let result: Option<InfrastructureRole> =  self
-            .client
-            .db
-            .query("SELECT * FROM ONLY role:$id")
-            .bind(("id", id))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(role) => Ok(role.try_into()?),
-            None => Err(AppError::Repository("Role not Updated!".to_string())),
-        }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: RoleId) -> AppResult<RoleView>{
+        Ok(self.load(ctx, id).await?.into())
     }
     async fn delete(&self,ctx: SubjectContex, id: RoleId) -> AppResult<bool>{
-        let result: Option<InfrastructureRole> =  self
-            .client
-            .db
-            .query("LET $role_id = $uid;
-                    DELETE person:$id RETURN BEFORE")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", id.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(_) => Ok(true),
-            None => Err(AppError::Repository("Role not deleted!".to_string())),
-        }
+        let mut role = self.load(ctx.clone(), id).await?;
+        role.mark_deleted(&ctx.user_id(), now());
+        let events = role
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureRole>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = role.aggregate_id();
+        let version = role.aggregate_version();
+        delete_with_outbox::<_, InfrastructureRole, _>(&self.client, "role", id, version, events).await?;
+        Ok(true)
     }
-    async fn get_by_name(&self, _request_contex:SubjectContex, name: Name) -> AppResult<Role>{
+
+    async fn get_by_name(&self, _request_contex:SubjectContex, name: Name) -> AppResult<RoleView>{
         let name: InfrastructureName = name.try_into()?;
         let result: Option<InfrastructureRole> =  self
             .client
@@ -96,37 +96,43 @@ impl RoleRepository for SurrealRoleRepository {
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
             .map_err(|err| AppError::Repository(err.to_string()))?;
         match result {
-            Some(role) => Ok(role.try_into()?),
-            None => Err(AppError::Repository("Role not Updated!".to_string())),
+            Some(role) => Ok(Role::try_from(role)?.into()),
+            None => Err(AppError::Repository("Role not found!".to_string())),
         }
     }
     
     // TODO: Add permissions field into requert contex to be prossesed as required take into account murge all roles permessions with the role ones
 // TODO: Add permissions field into requert contex to be prossesed as required take into account murge all roles permessions with the user ones
-    async fn get_roles_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<Role>>{
-        let mut order = String::new();
-        
-        for ord in sort_by{
-            order.push_str(&format!("{},", ord.as_string()));
-        }
-        if !sort_by.is_empty(){
-            order = format!("ORDER BY {}", order);
-            order.truncate(order.len() -1);
-        }
+    async fn get_roles_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<RoleView>>{
+        let order = order_clause(SORTABLE, sort_by)?;
         
         let result: Vec<InfrastructureRole> =  self
             .client
             .db
-            .query("SELECT * FROM role $order LIMIT $page_size START $start_at")
-            .bind(("order", order))
-            .bind(("page_size", page_size))
+            .query(format!("SELECT * FROM role{order} LIMIT $page_size START $start_at"))
+                        .bind(("page_size", page_size))
             .bind(("start_at", page*page_size))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut roles: Vec<RoleView> = Vec::new();
+        for role in result{
+            roles.push(Role::try_from(role).map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?.into());
+        }
+        Ok(roles)
+    }
+
+    async fn get_roles_page(&self,ctx: SubjectContex ,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<RoleView>>{
+        let compiled = keyset_query("role", None, SORTABLE, sort_by, after, page_size)?;
+        let mut request = self.client.db.query(compiled.text);
+        for (name, value) in compiled.binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureRole> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
         let mut roles: Vec<Role> = Vec::new();
         for role in result{
             roles.push(role.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
         }
-        Ok(roles)
+        Ok(into_page(roles, sort_by, page_size, sort_key).map(RoleView::from))
     }
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<RoleQueryResult>{
         let response = self.client
@@ -136,7 +142,42 @@ impl RoleRepository for SurrealRoleRepository {
         // Using the extension trait
         Ok(response.into_role_result().await.map_err(|err| AppError::Repository(err.to_string()))?.try_into()?)
     }
-}
 
+    async fn load(&self, _request_contex: SubjectContex, id: RoleId) -> AppResult<Role>{
+        let id: InfrastructureRoleId = id.into();
+        let result: Option<InfrastructureRole> =  self
+            .client
+            .db
+            .query("SELECT * FROM ONLY role:$id")
+            .bind(("id", id))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match result {
+            Some(role) => Ok(role.try_into()?),
+            None => Err(AppError::Repository("Role not found!".to_string())),
+        }
+    }
 
+    async fn save(&self, _request_contex: SubjectContex, mut role: Role) -> AppResult<Role>{
+        let events = role
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureRole>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = role.id().id().to_string();
+        let version = *role.version();
+        let record: InfrastructureRole = role.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let saved = save_with_outbox(&self.client, "role", id, version, record, events).await?;
+        Ok(saved.try_into()?)
+    }
+}
 
+fn sort_key(role: &Role, field: &str) -> Value {
+    match field {
+        "id" => role.id().as_str().into(),
+        "name" => role.name().name().into(),
+        "created_at" => (*role.created_at()).into(),
+        "version" => (*role.version()).into(),
+        _ => Value::Null,
+    }
+}
@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use application::{SubjectContex, dto::tenant::{command::TenantCommand, view::TenantView}, error::{AppError, AppResult}, ports::{Cursor, Page, SortBy, TenantRepository, tenant::TenantQueryResult}, query::ast::Value};
+use domain::{AggregateRoot, DateTime, Membership, Report, Tenant, TenantId, TenantStatus};
+
+use crate::{
+    database::{client::SurrealDBClient, memory::{missing, next_version, tenant::apply_command}, outbox::{delete_with_outbox, save_with_outbox}, page::{into_page, keyset_query, order_clause}, utils::now},
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::{
+        events::InfrastructureDomainEvent,
+        membership::InfrastructureMembership,
+        report::report::InfrastructureReport,
+        tenant::InfrastructureTenant,
+        value_objects::InfrastructureDateTime,
+    }
+};
+
+const TABLE: &str = "tenant";
+/// A subject sees its own tenant and the tenants that trust it.
+const VISIBLE: &str = "meta::id(id) = $tenant OR $tenant IN config.collaboration.trusted_tenants";
+const SORTABLE: &[(&str, &str)] = &[("name", "name"), ("created_at", "created_at"), ("version", "version")];
+
+/// A subject sees its own tenant and the tenants that trust it for
+/// collaboration, and may only write its own tenant, like
+/// [`InMemoryTenantRepository`](crate::database::InMemoryTenantRepository).
+pub struct SurrealTenantRepository {
+    client: SurrealDBClient,
+}
+
+impl SurrealTenantRepository {
+    pub fn new(client: SurrealDBClient) -> Self {
+        Self { client }
+    }
+
+    async fn write(&self, mut tenant: Tenant) -> AppResult<Tenant> {
+        let events = tenant
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureTenant>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = tenant.aggregate_id();
+        let version = tenant.aggregate_version();
+        let record: InfrastructureTenant = tenant.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let saved = save_with_outbox(&self.client, TABLE, id, version, record, events).await?;
+        Ok(saved.try_into()?)
+    }
+
+    async fn select(&self, query: String, binds: Vec<(String, Value)>, tenant: &TenantId) -> AppResult<Vec<Tenant>> {
+        let mut request = self.client.db.query(query).bind(("tenant", tenant.as_str().to_string()));
+        for (name, value) in binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureTenant> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut tenants: Vec<Tenant> = Vec::new();
+        for tenant in result {
+            tenants.push(tenant.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
+        }
+        Ok(tenants)
+    }
+}
+
+#[async_trait]
+impl TenantRepository for SurrealTenantRepository {
+    async fn create(&self, ctx: SubjectContex, command: TenantCommand) -> AppResult<TenantView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let mut tenant = apply_command(id, None, command, 1)?;
+        tenant.mark_created(&ctx.user_id, now());
+        Ok(self.write(tenant).await?.into())
+    }
+
+    async fn update(&self, ctx: SubjectContex, command: TenantCommand) -> AppResult<TenantView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        if id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let current = self.load(ctx.clone(), id.clone()).await?;
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let mut tenant = apply_command(id, Some(current.clone()), command, version)?;
+        tenant.record_event("updated", &ctx.user_id, now(), current);
+        Ok(self.write(tenant).await?.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: TenantId) -> AppResult<TenantView> {
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, id: TenantId) -> AppResult<bool> {
+        if id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let mut tenant = self.load(ctx.clone(), id).await?;
+        tenant.mark_deleted(&ctx.user_id, now());
+        let events = tenant
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureTenant>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        delete_with_outbox::<_, InfrastructureTenant, _>(&self.client, TABLE, tenant.aggregate_id(), tenant.aggregate_version(), events).await?;
+        Ok(true)
+    }
+
+    async fn get_tenants_paginated(&self, ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<TenantView>> {
+        let query = format!("SELECT * FROM {TABLE} WHERE {VISIBLE}{} LIMIT $page_size START $start_at", order_clause(SORTABLE, sort_by)?);
+        let binds = vec![("page_size".to_string(), page_size.into()), ("start_at".to_string(), (page * page_size).into())];
+        let tenants = self.select(query, binds, &ctx.tenant_id).await?;
+        Ok(tenants.into_iter().map(TenantView::from).collect())
+    }
+
+    async fn get_tenants_page(&self, ctx: SubjectContex, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<TenantView>> {
+        let compiled = keyset_query(TABLE, Some(VISIBLE), SORTABLE, sort_by, after, page_size)?;
+        let tenants = self.select(compiled.text, compiled.binds, &ctx.tenant_id).await?;
+        Ok(into_page(tenants, sort_by, page_size, sort_key).map(TenantView::from))
+    }
+
+    async fn raw_query(&self, _ctx: SubjectContex, _query: String) -> AppResult<TenantQueryResult> {
+        Err(AppError::Repository(format!("raw queries against {TABLE} are not supported")))
+    }
+
+    async fn load(&self, ctx: SubjectContex, id: TenantId) -> AppResult<Tenant> {
+        let query = format!("SELECT * FROM {TABLE} WHERE meta::id(id) = $id AND ({VISIBLE})");
+        let binds = vec![("id".to_string(), id.as_str().into())];
+        self.select(query, binds, &ctx.tenant_id)
+            .await?
+            .pop()
+            .ok_or(AppError::Repository("Tenant not found!".to_string()))
+    }
+
+    async fn save(&self, ctx: SubjectContex, tenant: Tenant) -> AppResult<Tenant> {
+        if tenant.id() != &ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        self.write(tenant).await
+    }
+
+    async fn get_due_for_purge(&self, _ctx: SubjectContex, now: DateTime) -> AppResult<Vec<Tenant>> {
+        let now: InfrastructureDateTime = now.try_into()?;
+        let result: Vec<InfrastructureTenant> = self
+            .client
+            .db
+            .query("SELECT * FROM tenant WHERE status = 'pending_deletion' AND purge_at <= <datetime>$now")
+            .bind(("now", now))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut tenants: Vec<Tenant> = Vec::new();
+        for tenant in result {
+            tenants.push(tenant.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
+        }
+        Ok(tenants)
+    }
+
+    /// Runs for every tenant, not only the subject's. The tenant, its
+    /// archived reports and memberships and all of their events are written
+    /// in one transaction, each record behind its own version check.
+    async fn purge(&self, ctx: SubjectContex, mut tenant: Tenant, now: DateTime) -> AppResult<Tenant> {
+        if tenant.status() != &TenantStatus::Deleted {
+            return Err(AppError::ValidationError(format!("{TABLE}:{} must be deleted before it is purged", tenant.id())));
+        }
+        let tenant_id = tenant.id().as_str().to_string();
+        let mut response = self
+            .client
+            .db
+            .query("SELECT * FROM report WHERE owner_tenant = $tenant AND status != 'Archived';
+                    SELECT * FROM membership WHERE tenant_id = $tenant AND status != 'archived';")
+            .bind(("tenant", tenant_id.clone()))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?;
+        let stored_reports: Vec<InfrastructureReport> = response.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let stored_memberships: Vec<InfrastructureMembership> = response.take(1).map_err(|err| AppError::Repository(err.to_string()))?;
+
+        let mut reports = Vec::new();
+        let mut report_events = Vec::new();
+        for report in stored_reports {
+            let mut report: Report = report.try_into()?;
+            report.archive(&ctx.user_id, now)?;
+            for event in report.take_pending_events() {
+                report_events.push(InfrastructureDomainEvent::<InfrastructureReport>::try_from(event)?);
+            }
+            reports.push(InfrastructureReport::try_from(report)?);
+        }
+        let mut memberships = Vec::new();
+        let mut membership_events = Vec::new();
+        for membership in stored_memberships {
+            let mut membership: Membership = membership.try_into()?;
+            membership.archive(&ctx.user_id, now);
+            for event in membership.take_pending_events() {
+                membership_events.push(InfrastructureDomainEvent::<InfrastructureMembership>::try_from(event)?);
+            }
+            let key = vec![membership.tenet_id().as_str().to_string(), membership.user_id().as_str().to_string()];
+            memberships.push((key, InfrastructureMembership::try_from(membership)?));
+        }
+
+        let tenant_events = tenant
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureTenant>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let version = tenant.aggregate_version();
+        let record: InfrastructureTenant = tenant.try_into()?;
+        let result: Option<InfrastructureTenant> = self
+            .client
+            .db
+            .query(
+                "BEGIN TRANSACTION;
+                 LET $current = (SELECT VALUE version FROM ONLY type::thing('tenant', $tenant));
+                 IF $current != NONE AND $current >= $version { THROW 'version conflict' };
+                 UPSERT type::thing('tenant', $tenant) CONTENT $record RETURN AFTER;
+                 FOR $report IN $reports {
+                     LET $stored = (SELECT VALUE version FROM ONLY type::thing('report', $report.id));
+                     IF $stored != NONE AND $stored >= $report.version { THROW 'version conflict' };
+                     UPSERT type::thing('report', $report.id) CONTENT $report;
+                 };
+                 FOR $membership IN $memberships {
+                     LET $stored = (SELECT VALUE version FROM ONLY type::thing('membership', $membership[0]));
+                     IF $stored != NONE AND $stored >= $membership[1].version { THROW 'version conflict' };
+                     UPSERT type::thing('membership', $membership[0]) CONTENT $membership[1];
+                 };
+                 INSERT INTO outbox $tenant_events;
+                 INSERT INTO outbox $report_events;
+                 INSERT INTO outbox $membership_events;
+                 COMMIT TRANSACTION;",
+            )
+            .bind(("tenant", tenant_id))
+            .bind(("version", version))
+            .bind(("record", record))
+            .bind(("reports", reports))
+            .bind(("memberships", memberships))
+            .bind(("tenant_events", tenant_events))
+            .bind(("report_events", report_events))
+            .bind(("membership_events", membership_events))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(2)
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match result {
+            Some(tenant) => Ok(tenant.try_into()?),
+            None => Err(AppError::Repository("Tenant not purged!".to_string())),
+        }
+    }
+}
+
+fn sort_key(tenant: &Tenant, field: &str) -> Value {
+    match field {
+        "id" => tenant.id().into(),
+        "name" => tenant.name().name().into(),
+        "created_at" => (*tenant.created_at()).into(),
+        "version" => (*tenant.version()).into(),
+        _ => Value::Null,
+    }
+}
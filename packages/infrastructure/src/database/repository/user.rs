@@ -1,10 +1,11 @@
 use async_trait::async_trait;
-use application::{SubjectContex, error::{AppResult, AppError}, ports::{ SortBy, UserRepository, user::UserQueryResult}};
-use domain::{Email, Username, user::{User, UserId}};
+use application::{SubjectContex, dto::user::{command::UserCommand, view::UserView}, error::{AppResult, AppError}, query::ast::Value, ports::{ Cursor, Page, SortBy, UserRepository, user::UserQueryResult}};
+use domain::{AggregateRoot, Email, Username, user::{User, UserId}};
 
 use crate::{
-    database::client::SurrealDBClient, error::InfrastructureError,
+    database::{client::SurrealDBClient, memory::{missing, next_version, user::apply_command}, outbox::{delete_with_outbox, save_with_outbox}, utils::now, page::{into_page, keyset_query, order_clause}}, error::{InfrastructureError, InfrastructureResult},
     serialization::{
+        events::InfrastructureDomainEvent,
         InfrastructureUserId,
         user::{
             SurrealUserResponseExt ,InfrastructureUserQueryResult,
@@ -16,6 +17,9 @@ use crate::{
     }
 };
 
+/// Sort fields and where they are stored; the profile holds `created_at`.
+const SORTABLE: &[(&str, &str)] = &[("email", "email"), ("username", "username"), ("last_login", "last_login"), ("created_at", "profile.created_at"), ("version", "version")];
+
 pub struct SurrealUserRepository {
     client: SurrealDBClient,
 }
@@ -30,70 +34,42 @@ impl SurrealUserRepository {
 
 #[async_trait]
 impl UserRepository for SurrealUserRepository {
-    async fn create(&self,ctx: SubjectContex, user: User) -> AppResult<User>{
-        let record: InfrastructureUser = user.try_into().map_err(|_| AppError::Repository("User Error ".to_string()))?;
-        let result: Option<InfrastructureUser> =  self
-            .client
-            .db
-            .query("LET $user_id = $uid;
-                    INSERT INTO user CONTENT $user RETURN AFTER")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("user", record))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(user) => Ok(user.try_into()?),
-            None => Err(AppError::Repository("User not created!".to_string())),
-        }
-        
+    async fn create(&self,ctx: SubjectContex, command: UserCommand) -> AppResult<UserView>{
+        let id = command.id.clone().ok_or_else(|| missing("user", "id"))?;
+        let now = now();
+        let mut user = apply_command(id, None, command, None, now)?;
+        user.mark_created(&ctx.user_id(), now);
+        Ok(self.save(ctx, user).await?.into())
     }
-    async fn update(&self,ctx: SubjectContex, user: User) -> AppResult<User>{
-        let record: InfrastructureUser = user.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
-        let result: Option<InfrastructureUser> =  self
-            .client
-            .db
-            .query("LET $user_id = $uid;
-                    UPDATE user:$id MERGE $user RETURN AFTER")
-            .bind(("user", record.clone()))
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", record.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(user) => Ok(user.try_into()?),
-            None => Err(AppError::Repository("User not Updated!".to_string())),
-        }
+
+    async fn update(&self,ctx: SubjectContex, command: UserCommand) -> AppResult<UserView>{
+        let id = command.id.clone().ok_or_else(|| missing("user", "id"))?;
+        let current = self.load(ctx.clone(), id.clone()).await?;
+        let version = next_version("user", id.as_str(), *current.version(), command.version)?;
+        let now = now();
+        let mut user = apply_command(id, Some(current.clone()), command, Some(version), now)?;
+        user.record_event("updated", &ctx.user_id(), now, current);
+        Ok(self.save(ctx, user).await?.into())
     }
-    async fn get_by_id(&self, _request_contex: SubjectContex, id: UserId) -> AppResult<User>{
-        let id: InfrastructureUserId = id.into();
-        let result: Option<InfrastructureUser> =  self
-            .client
-            .db
-            .query("SELECT * FROM ONLY user:$id")
-            .bind(("id", id))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(user) => Ok(user.try_into()?),
-            None => Err(AppError::Repository("User not Updated!".to_string())),
-        }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: UserId) -> AppResult<UserView>{
+        Ok(self.load(ctx, id).await?.into())
     }
     async fn delete(&self,ctx: SubjectContex, id: UserId) -> AppResult<bool>{
-        let result: Option<InfrastructureUser> =  self
-            .client
-            .db
-            .query("LET $user_id = $uid;
-                    DELETE person:$id RETURN BEFORE")
-            .bind(("uid", ctx.user_id_as_str()))
-            .bind(("id", id.id()))
-            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
-            .map_err(|err| AppError::Repository(err.to_string()))?;
-        match result {
-            Some(_) => Ok(true),
-            None => Err(AppError::Repository("User not deleted!".to_string())),
-        }
+        let mut user = self.load(ctx.clone(), id).await?;
+        user.mark_deleted(&ctx.user_id(), now());
+        let events = user
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureUser>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = user.aggregate_id();
+        let version = user.aggregate_version();
+        delete_with_outbox::<_, InfrastructureUser, _>(&self.client, "user", id, version, events).await?;
+        Ok(true)
     }
-    async fn get_by_email(&self, _request_contex:SubjectContex, email: Email) -> AppResult<User>{
+
+    async fn get_by_email(&self, _request_contex:SubjectContex, email: Email) -> AppResult<UserView>{
         let email: InfrastructureEmail = email.try_into()?;
         let result: Option<InfrastructureUser> =  self
             .client
@@ -103,11 +79,11 @@ impl UserRepository for SurrealUserRepository {
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
             .map_err(|err| AppError::Repository(err.to_string()))?;
         match result {
-            Some(user) => Ok(user.try_into()?),
-            None => Err(AppError::Repository("User not Updated!".to_string())),
+            Some(user) => Ok(User::try_from(user)?.into()),
+            None => Err(AppError::Repository("User not found!".to_string())),
         }
     }
-    async fn get_by_username(&self,_request_contex:SubjectContex,  username: Username) -> AppResult<User>{
+    async fn get_by_username(&self,_request_contex:SubjectContex,  username: Username) -> AppResult<UserView>{
         let username: InfrastructureUsername = username.try_into()?;
         let result: Option<InfrastructureUser> =  self
             .client
@@ -117,36 +93,42 @@ impl UserRepository for SurrealUserRepository {
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
             .map_err(|err| AppError::Repository(err.to_string()))?;
         match result {
-            Some(user) => Ok(user.try_into()?),
-            None => Err(AppError::Repository("User not Updated!".to_string())),
+            Some(user) => Ok(User::try_from(user)?.into()),
+            None => Err(AppError::Repository("User not found!".to_string())),
         }
     }
     
     // TODO: Add permissions field into requert contex to be prossesed as required take into account murge all roles permessions with the user ones
-    async fn get_users_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<User>>{
-        let mut order = String::new();
-        
-        for ord in sort_by{
-            order.push_str(&format!("{},", ord.as_string()));
-        }
-        if !sort_by.is_empty(){
-            order = format!("ORDER BY {}", order);
-            order.truncate(order.len() -1);
-        }
+    async fn get_users_paginated(&self,ctx: SubjectContex ,sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<UserView>>{
+        let order = order_clause(SORTABLE, sort_by)?;
         
         let result: Vec<InfrastructureUser> =  self
             .client
             .db
-            .query("SELECT * FROM user $order LIMIT $page_size START $start_at")
-            .bind(("order", order))
-            .bind(("page_size", page_size))
+            .query(format!("SELECT * FROM user{order} LIMIT $page_size START $start_at"))
+                        .bind(("page_size", page_size))
             .bind(("start_at", page*page_size))
             .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
+        let mut users: Vec<UserView> = Vec::new();
+        for user in result{
+            users.push(User::try_from(user).map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?.into());
+        }
+        Ok(users)
+    }
+
+    async fn get_users_page(&self,ctx: SubjectContex ,sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<UserView>>{
+        let compiled = keyset_query("user", None, SORTABLE, sort_by, after, page_size)?;
+        let mut request = self.client.db.query(compiled.text);
+        for (name, value) in compiled.binds {
+            request = request.bind((name, value));
+        }
+        let result: Vec<InfrastructureUser> = request
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0).map_err(|err| AppError::Repository(err.to_string()))?;
         let mut users: Vec<User> = Vec::new();
         for user in result{
             users.push(user.try_into().map_err(|err: InfrastructureError| AppError::Repository(err.to_string()))?);
         }
-        Ok(users)
+        Ok(into_page(users, sort_by, page_size, sort_key).map(UserView::from))
     }
 
     async fn raw_query(&self,ctx: SubjectContex, query: String) -> AppResult<UserQueryResult>{
@@ -157,6 +139,44 @@ impl UserRepository for SurrealUserRepository {
         // Using the extension trait
         Ok(response.into_user_result().await.map_err(|err| AppError::Repository(err.to_string()))?.try_into()?)
     }
-}
 
+    async fn load(&self, _request_contex: SubjectContex, id: UserId) -> AppResult<User>{
+        let id: InfrastructureUserId = id.into();
+        let result: Option<InfrastructureUser> =  self
+            .client
+            .db
+            .query("SELECT * FROM ONLY user:$id")
+            .bind(("id", id))
+            .await.map_err(|err| AppError::Repository(err.to_string()))?.take(0)  // Get first query result
+            .map_err(|err| AppError::Repository(err.to_string()))?;
+        match result {
+            Some(user) => Ok(user.try_into()?),
+            None => Err(AppError::Repository("User not found!".to_string())),
+        }
+    }
 
+    async fn save(&self, _request_contex: SubjectContex, mut user: User) -> AppResult<User>{
+        let events = user
+            .take_pending_events()
+            .into_iter()
+            .map(InfrastructureDomainEvent::<InfrastructureUser>::try_from)
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        let id = user.id().id().to_string();
+        let version = *user.version();
+        let record: InfrastructureUser = user.try_into().map_err(|err: InfrastructureError| AppError::Domain(err.into()))?;
+        let saved = save_with_outbox(&self.client, "user", id, version, record, events).await?;
+        Ok(saved.try_into()?)
+    }
+}
+
+fn sort_key(user: &User, field: &str) -> Value {
+    match field {
+        "id" => user.id().into(),
+        "email" => user.email().email().into(),
+        "username" => user.username().username().into(),
+        "last_login" => (*user.last_login()).into(),
+        "created_at" => (*user.profile().created_at()).into(),
+        "version" => (*user.version()).into(),
+        _ => Value::Null,
+    }
+}
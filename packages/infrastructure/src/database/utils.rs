@@ -1 +1 @@
-
+pub(crate) use memory::now;
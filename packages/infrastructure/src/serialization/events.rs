@@ -2,15 +2,16 @@ use std::ops::{Deref, DerefMut};
 
 use domain::{
     events::{DomainEvent, DomainEventId},
-    Event, Report, Role, Table, User,
+    Event, Membership, Report, Role, Table, Tenant, User,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::error::{InfrastructureError, InfrastructureResult};
 
 use super::{
-    report::report::InfrastructureReport, role::InfrastructureRole, user::user::InfrastructureUser,
-    value_objects::InfrastructureDateTime, InfrastructureUserId,
+    membership::InfrastructureMembership, report::report::InfrastructureReport, role::InfrastructureRole,
+    tenant::InfrastructureTenant, user::user::InfrastructureUser, value_objects::InfrastructureDateTime,
+    InfrastructureUserId,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +19,9 @@ pub enum InfrastructureTable {
     User,
     Role,
     Report,
+    Tenant,
+    Membership,
+    AccessRequest,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -119,6 +123,9 @@ impl From<Table> for InfrastructureTable {
             Table::User => InfrastructureTable::User,
             Table::Role => InfrastructureTable::Role,
             Table::Report => InfrastructureTable::Report,
+            Table::Tenant => InfrastructureTable::Tenant,
+            Table::Membership => InfrastructureTable::Membership,
+            Table::AccessRequest => InfrastructureTable::AccessRequest,
         }
     }
 }
@@ -129,6 +136,9 @@ impl From<InfrastructureTable> for Table {
             InfrastructureTable::User => Table::User,
             InfrastructureTable::Role => Table::Role,
             InfrastructureTable::Report => Table::Report,
+            InfrastructureTable::Tenant => Table::Tenant,
+            InfrastructureTable::Membership => Table::Membership,
+            InfrastructureTable::AccessRequest => Table::AccessRequest,
         }
     }
 }
@@ -231,3 +241,69 @@ impl TryFrom<InfrastructureDomainEvent<InfrastructureUser>> for DomainEvent<User
         ))
     }
 }
+
+// Tenant
+impl TryFrom<DomainEvent<Tenant>> for InfrastructureDomainEvent<InfrastructureTenant> {
+    type Error = InfrastructureError;
+
+    fn try_from(value: DomainEvent<Tenant>) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.id().as_str(),
+            value.table().into(),
+            value.action().into(),
+            value.user_id().into(),
+            value.occurred_at().try_into()?,
+            value.before().try_into()?,
+            value.after().try_into()?,
+        ))
+    }
+}
+
+impl TryFrom<InfrastructureDomainEvent<InfrastructureTenant>> for DomainEvent<Tenant> {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureDomainEvent<InfrastructureTenant>) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.id().as_str(),
+            value.table().into(),
+            value.action().into(),
+            value.user_id().into(),
+            value.occurred_at().try_into()?,
+            value.before().try_into()?,
+            value.after().try_into()?,
+        ))
+    }
+}
+
+// Membership
+impl TryFrom<DomainEvent<Membership>> for InfrastructureDomainEvent<InfrastructureMembership> {
+    type Error = InfrastructureError;
+
+    fn try_from(value: DomainEvent<Membership>) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.id().as_str(),
+            value.table().into(),
+            value.action().into(),
+            value.user_id().into(),
+            value.occurred_at().try_into()?,
+            value.before().try_into()?,
+            value.after().try_into()?,
+        ))
+    }
+}
+
+impl TryFrom<InfrastructureDomainEvent<InfrastructureMembership>> for DomainEvent<Membership> {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureDomainEvent<InfrastructureMembership>) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.id().as_str(),
+            value.table().into(),
+            value.action().into(),
+            value.user_id().into(),
+            value.occurred_at().try_into()?,
+            value.before().try_into()?,
+            value.after().try_into()?,
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use domain::{Event, Membership};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::{
+        tenant::InfrastructureTenantId, value_objects::InfrastructureDateTime, InfrastructureRoleId,
+        InfrastructureUserId,
+    },
+};
+
+use super::InfrastructureMembershipStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureMembership {
+    user_id: InfrastructureUserId,
+    tenant_id: InfrastructureTenantId,
+    roles: HashSet<InfrastructureRoleId>,
+    status: InfrastructureMembershipStatus,
+    created_at: InfrastructureDateTime,
+    version: u64,
+}
+
+impl InfrastructureMembership {
+    pub fn user_id(&self) -> InfrastructureUserId {
+        self.user_id.clone()
+    }
+
+    pub fn tenant_id(&self) -> InfrastructureTenantId {
+        self.tenant_id.clone()
+    }
+
+    pub fn status(&self) -> InfrastructureMembershipStatus {
+        self.status
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl Event for InfrastructureMembership {
+    fn get_type(&self) -> &str {
+        "MEMBERSHIP"
+    }
+}
+
+impl TryFrom<Membership> for InfrastructureMembership {
+    type Error = InfrastructureError;
+
+    fn try_from(value: Membership) -> InfrastructureResult<Self> {
+        let parts = value.into_parts();
+        Ok(Self {
+            user_id: parts.user_id.into(),
+            tenant_id: parts.tenet_id.into(),
+            roles: parts.roles.into_iter().map(Into::into).collect(),
+            status: parts.status.into(),
+            created_at: parts.created_at.try_into()?,
+            version: parts.version,
+        })
+    }
+}
+
+impl TryFrom<InfrastructureMembership> for Membership {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureMembership) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.user_id.into(),
+            value.tenant_id.into(),
+            value.roles.into_iter().map(Into::into).collect(),
+            value.status.into(),
+            value.created_at.try_into()?,
+            value.version,
+        ))
+    }
+}
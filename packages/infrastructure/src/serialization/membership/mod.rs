@@ -0,0 +1,5 @@
+pub mod membership;
+pub mod status;
+
+pub use membership::InfrastructureMembership;
+pub use status::InfrastructureMembershipStatus;
@@ -0,0 +1,31 @@
+use domain::MembershipStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureMembershipStatus {
+    Invited,
+    #[default]
+    Active,
+    Archived,
+}
+
+impl From<MembershipStatus> for InfrastructureMembershipStatus {
+    fn from(value: MembershipStatus) -> Self {
+        match value {
+            MembershipStatus::Invited => Self::Invited,
+            MembershipStatus::Active => Self::Active,
+            MembershipStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+impl From<InfrastructureMembershipStatus> for MembershipStatus {
+    fn from(value: InfrastructureMembershipStatus) -> Self {
+        match value {
+            InfrastructureMembershipStatus::Invited => Self::Invited,
+            InfrastructureMembershipStatus::Active => Self::Active,
+            InfrastructureMembershipStatus::Archived => Self::Archived,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod events;
+pub mod membership;
 pub mod report;
 pub mod role;
 pub mod sessions;
+pub mod tenant;
 pub mod user;
 pub mod value_objects;
 
@@ -11,6 +13,7 @@ pub use user::{
 
 pub use report::InfrastructureReportId;
 pub use role::{permissions::InfrastructurePermission, InfrastructureRoleId};
+pub use tenant::InfrastructureTenantId;
 pub use value_objects::{
     address::{InfrastructureAddress, InfrastructureAddressBuilder, InfrastructureAddressess},
     diff::InfrastructureDiff,
@@ -2,6 +2,7 @@ pub mod content;
 pub mod report;
 pub mod report_type;
 pub mod status;
+pub mod view;
 
 use application::ports::report::ReportQueryResult;
 use report::InfrastructureReport;
@@ -7,7 +7,7 @@ use crate::{
     error::{InfrastructureError, InfrastructureResult},
     serialization::{
         value_objects::{InfrastructureDateTime, InfrastructureTitle},
-        InfrastructurePermission, InfrastructureUserId,
+        InfrastructurePermission, InfrastructureTenantId, InfrastructureUserId,
     },
 };
 
@@ -25,6 +25,8 @@ pub struct InfrastructureReport {
     permissions: HashSet<InfrastructurePermission>,
     status: InfrastructureReportStatus,
     author_id: InfrastructureUserId,
+    owner_tenant: InfrastructureTenantId,
+    shared_with_tenants: HashSet<InfrastructureTenantId>,
     assigned_reviewer_id: HashSet<InfrastructureUserId>,
     created_at: InfrastructureDateTime,
     updated_at: InfrastructureDateTime,
@@ -33,8 +35,12 @@ pub struct InfrastructureReport {
 }
 
 impl InfrastructureReport {
-    pub fn new(id: InfrastructureReportId, author_id: InfrastructureUserId) -> InfrastructureReportBuilder {
-        InfrastructureReportBuilder::new(id, author_id)
+    pub fn new(
+        id: InfrastructureReportId,
+        author_id: InfrastructureUserId,
+        owner_tenant: InfrastructureTenantId,
+    ) -> InfrastructureReportBuilder {
+        InfrastructureReportBuilder::new(id, author_id, owner_tenant)
     }
 
     pub fn id(&self) -> InfrastructureReportId {
@@ -65,6 +71,14 @@ impl InfrastructureReport {
         self.author_id.clone()
     }
 
+    pub fn owner_tenant(&self) -> InfrastructureTenantId {
+        self.owner_tenant.clone()
+    }
+
+    pub fn shared_with_tenants(&self) -> HashSet<InfrastructureTenantId> {
+        self.shared_with_tenants.clone()
+    }
+
     pub fn assigned_reviewer_id(&self) -> HashSet<InfrastructureUserId> {
         self.assigned_reviewer_id.clone()
     }
@@ -93,6 +107,8 @@ pub struct InfrastructureReportBuilder {
     content: Option<InfrastructureReportContent>,
     report_type: Option<InfrastructureReportType>,
     author_id: InfrastructureUserId,
+    owner_tenant: InfrastructureTenantId,
+    shared_with_tenants: HashSet<InfrastructureTenantId>,
     status: InfrastructureReportStatus,
     reviewer_id: HashSet<InfrastructureUserId>,
     created_at: Option<InfrastructureDateTime>,
@@ -101,12 +117,18 @@ pub struct InfrastructureReportBuilder {
 }
 
 impl InfrastructureReportBuilder {
-    pub fn new(id: InfrastructureReportId, author_id: InfrastructureUserId) -> Self {
+    pub fn new(
+        id: InfrastructureReportId,
+        author_id: InfrastructureUserId,
+        owner_tenant: InfrastructureTenantId,
+    ) -> Self {
         Self {
             permissions: HashSet::new(),
             content: None,
             report_type: None,
             author_id,
+            owner_tenant,
+            shared_with_tenants: HashSet::new(),
             reviewer_id: HashSet::new(),
             created_at: None,
             due: None,
@@ -148,6 +170,10 @@ impl InfrastructureReportBuilder {
         self.reviewer_id.insert(reviewer);
         self
     }
+    pub fn add_shared_tenant(&mut self, tenant_id: InfrastructureTenantId) -> &mut Self {
+        self.shared_with_tenants.insert(tenant_id);
+        self
+    }
 
     pub fn build(
         self,
@@ -162,6 +188,8 @@ impl InfrastructureReportBuilder {
             permissions: self.permissions,
             status: self.status,
             author_id: self.author_id,
+            owner_tenant: self.owner_tenant,
+            shared_with_tenants: self.shared_with_tenants,
             assigned_reviewer_id: self.reviewer_id,
             created_at: self.created_at.unwrap_or(updated_at.clone()),
             updated_at: updated_at,
@@ -175,7 +203,11 @@ impl TryFrom<Report> for InfrastructureReport {
     type Error = InfrastructureError;
 
     fn try_from(value: Report) -> InfrastructureResult<Self> {
-        let mut report_builder = Self::new(value.id().into(), value.author_id().into());
+        let mut report_builder = Self::new(
+            value.id().into(),
+            value.author_id().into(),
+            value.owner_tenant().clone().into(),
+        );
         report_builder
             .set_content(value.content().try_into()?)
             .set_report_type(value.report_type().into())
@@ -193,6 +225,9 @@ impl TryFrom<Report> for InfrastructureReport {
         for reviewer in value.assigned_reviewer_id().into_iter() {
             report_builder.add_reviewer(reviewer.into());
         }
+        for tenant_id in value.shared_with_tenants().into_iter() {
+            report_builder.add_shared_tenant(tenant_id.clone().into());
+        }
 
         report_builder.build(&value.title().title(), value.updated_at().try_into()?)
     }
@@ -202,7 +237,11 @@ impl TryFrom<InfrastructureReport> for Report {
     type Error = InfrastructureError;
 
     fn try_from(value: InfrastructureReport) -> InfrastructureResult<Self> {
-        let mut report_builder = Self::new(value.id().into(), value.author_id().into());
+        let mut report_builder = Self::new(
+            value.id().into(),
+            value.author_id().into(),
+            value.owner_tenant().into(),
+        );
         report_builder
             .set_content(value.content().try_into()?)
             .set_report_type(value.report_type().into())
@@ -220,6 +259,9 @@ impl TryFrom<InfrastructureReport> for Report {
         for reviewer in value.assigned_reviewer_id().into_iter() {
             report_builder.add_reviewer(reviewer.into());
         }
+        for tenant_id in value.shared_with_tenants().into_iter() {
+            report_builder.add_shared_tenant(tenant_id.into());
+        }
 
         report_builder
             .build(&value.title().title(), value.updated_at().try_into()?)
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use application::dto::report::view::{ReportContentView, ReportView, ReviewCommentView};
+use serde::Deserialize;
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::{
+        value_objects::{
+            comment::InfrastructureComment, date_time::InfrastructureDateTime, InfrastructureBody,
+            InfrastructureTitle, InfrastructureUrl,
+        },
+        InfrastructureUserId,
+    },
+};
+
+use super::{InfrastructureReportId, InfrastructureReportStatus};
+
+/// A report row read through a projection: every field the `SELECT` left
+/// out is missing.
+// FIXME: report_type, owner_tenant and shared_with_tenants once InfrastructureReport stores them
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct InfrastructureReportView {
+    id: Option<InfrastructureReportId>,
+    title: Option<InfrastructureTitle>,
+    content: Option<InfrastructureReportContentView>,
+    status: Option<InfrastructureReportStatus>,
+    author_id: Option<InfrastructureUserId>,
+    assigned_reviewer_id: Option<HashSet<InfrastructureUserId>>,
+    created_at: Option<InfrastructureDateTime>,
+    updated_at: Option<InfrastructureDateTime>,
+    due_date: Option<InfrastructureDateTime>,
+    version: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct InfrastructureReportContentView {
+    body: Option<InfrastructureBody>,
+    attachments: Option<Vec<InfrastructureUrl>>,
+    review_comments: Option<Vec<InfrastructureReviewCommentView>>,
+    rejection_reason: Option<InfrastructureComment>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct InfrastructureReviewCommentView {
+    reviewer_id: Option<InfrastructureUserId>,
+    comment: Option<InfrastructureComment>,
+    created_at: Option<InfrastructureDateTime>,
+}
+
+impl TryFrom<InfrastructureReportView> for ReportView {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureReportView) -> InfrastructureResult<Self> {
+        Ok(Self {
+            id: value.id.map(Into::into),
+            title: value.title.map(TryInto::try_into).transpose()?,
+            content: value.content.map(TryInto::try_into).transpose()?,
+            report_type: None,
+            status: value.status.map(Into::into),
+            author_id: value.author_id.map(Into::into),
+            owner_tenant: None,
+            shared_with_tenants: HashSet::new(),
+            assigned_reviewer_id: value
+                .assigned_reviewer_id
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            created_at: value.created_at.map(TryInto::try_into).transpose()?,
+            updated_at: value.updated_at.map(TryInto::try_into).transpose()?,
+            due_date: value.due_date.map(TryInto::try_into).transpose()?,
+            version: value.version,
+        })
+    }
+}
+
+impl TryFrom<InfrastructureReportContentView> for ReportContentView {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureReportContentView) -> InfrastructureResult<Self> {
+        Ok(Self {
+            body: value.body.map(TryInto::try_into).transpose()?,
+            attachments: value
+                .attachments
+                .map(|attachments| attachments.into_iter().map(TryInto::try_into).collect())
+                .transpose()?,
+            review_comments: value
+                .review_comments
+                .map(|comments| comments.into_iter().map(TryInto::try_into).collect())
+                .transpose()?,
+            // A projection cannot tell an unselected reason from an absent one.
+            rejection_reason: value
+                .rejection_reason
+                .map(TryInto::try_into)
+                .transpose()?
+                .map(Some),
+        })
+    }
+}
+
+impl TryFrom<InfrastructureReviewCommentView> for ReviewCommentView {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureReviewCommentView) -> InfrastructureResult<Self> {
+        Ok(Self {
+            reviewer_id: value.reviewer_id.map(Into::into),
+            comment: value.comment.map(TryInto::try_into).transpose()?,
+            created_at: value.created_at.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
@@ -18,6 +18,7 @@ pub struct InfrastructureRole {
     permissions: HashSet<InfrastructurePermission>,
     is_system_role: bool,
     created_at: InfrastructureDateTime,
+    version: u64,
 }
 
 impl InfrastructureRole {
@@ -47,6 +48,10 @@ impl InfrastructureRole {
     pub fn created_at(&self) -> InfrastructureDateTime {
         self.created_at.clone()
     }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +62,7 @@ pub struct InfrastructureRoleBuilder {
     permissions: HashSet<InfrastructurePermission>,
     is_system_role: Option<bool>,
     created_at: Option<InfrastructureDateTime>,
+    version: u64,
 }
 
 impl InfrastructureRoleBuilder {
@@ -68,6 +74,7 @@ impl InfrastructureRoleBuilder {
             permissions: HashSet::new(),
             is_system_role: None,
             created_at: None,
+            version: 1,
         }
     }
 
@@ -95,6 +102,11 @@ impl InfrastructureRoleBuilder {
         self
     }
 
+    pub fn set_version(&mut self, version: u64) -> &mut Self {
+        self.version = version;
+        self
+    }
+
     pub fn build(self) -> InfrastructureResult<InfrastructureRole> {
         Ok(InfrastructureRole {
             id: self.id,
@@ -115,6 +127,7 @@ impl InfrastructureRoleBuilder {
             created_at: self.created_at.ok_or(InfrastructureError::ValidationError(
                 "Created At not found".to_string(),
             ))?,
+            version: self.version,
         })
     }
 }
@@ -128,7 +141,8 @@ impl TryFrom<Role> for InfrastructureRole {
             .set_name(value.name().try_into()?)
             .set_description(value.description().try_into()?)
             .set_created_at(value.created_at().try_into()?)
-            .set_is_system_role(value.is_system_role());
+            .set_is_system_role(value.is_system_role())
+            .set_version(*value.version());
         for permission in value.permissions().into_iter() {
             role_builder.add_permission(permission.into());
         }
@@ -146,7 +160,8 @@ impl TryFrom<InfrastructureRole> for Role {
             .set_name(value.name().try_into()?)
             .set_description(value.description().try_into()?)
             .set_created_at(value.created_at().try_into()?)
-            .set_is_system_role(value.is_system_role());
+            .set_is_system_role(value.is_system_role())
+            .set_version(value.version());
         for permission in value.permissions().into_iter() {
             role_builder.add_permission(permission.into());
         }
@@ -0,0 +1,478 @@
+use domain::{
+    tenant::config::{
+        authorization::TenantAuthorizationConfig,
+        collaboration::{
+            CollaborationAccessLevel, CollaborationMode, FederationPolicy, TenantCollaborationConfig,
+        },
+        environment::TenantEnvironmentConfig,
+        feature::{Feature, FeatureLimits, FeatureRolloutPolicy, TenantFeatureConfig},
+        TenantConfig,
+    },
+    value_objects::{date_time::Weekday, NetworkZone, TimeWindow},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::{value_objects::InfrastructureDateTime, InfrastructureRoleId},
+};
+
+use super::InfrastructureTenantId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureTenantConfig {
+    authorization: InfrastructureAuthorizationConfig,
+    environment: InfrastructureEnvironmentConfig,
+    collaboration: InfrastructureCollaborationConfig,
+    features: InfrastructureFeatureConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureAuthorizationConfig {
+    allow_cross_tenant_access: bool,
+    require_reviewer_for_publish: bool,
+    default_role: Option<InfrastructureRoleId>,
+    access_request_approver_role: Option<InfrastructureRoleId>,
+    max_access_grant_duration: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureEnvironmentConfig {
+    allowed_time_window: Option<InfrastructureTimeWindow>,
+    allowed_networks: Vec<InfrastructureNetworkZone>,
+    require_managed_device: bool,
+    max_risk_score: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureCollaborationConfig {
+    trusted_tenants: Vec<InfrastructureTenantId>,
+    allowed_modes: Vec<InfrastructureCollaborationMode>,
+    federation: InfrastructureFederationPolicy,
+    access_level: InfrastructureCollaborationAccessLevel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureFeatureConfig {
+    enabled_features: Vec<InfrastructureFeature>,
+    max_projects: u32,
+    max_users: u32,
+    api_rate_limit: u32,
+    rollout: InfrastructureFeatureRolloutPolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InfrastructureTimeWindow {
+    Absolute {
+        start: InfrastructureDateTime,
+        end: InfrastructureDateTime,
+    },
+    Recurring {
+        days: Vec<InfrastructureWeekday>,
+        start_seconds: u32,
+        end_seconds: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureWeekday {
+    Saturday,
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureNetworkZone {
+    TrustedCorporate,
+    Internal,
+    Partner,
+    PublicInternet,
+    Anonymous,
+    HighRisk,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureCollaborationMode {
+    GuestAccess,
+    ResourceSharing,
+    ProjectCollaboration,
+    FullFederation,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureFederationPolicy {
+    Disabled,
+    VerifiedOnly,
+    TrustedTenantsOnly,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureCollaborationAccessLevel {
+    ReadOnly,
+    Contributor,
+    Admin,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureFeature {
+    AdvancedReports,
+    CrossTenantSharing,
+    AuditLogs,
+    ApiAccess,
+    RiskScoring,
+    BetaDashboard,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InfrastructureFeatureRolloutPolicy {
+    Stable,
+    Beta,
+    Canary { percentage: u8 },
+}
+
+impl TryFrom<TenantConfig> for InfrastructureTenantConfig {
+    type Error = InfrastructureError;
+
+    fn try_from(value: TenantConfig) -> InfrastructureResult<Self> {
+        let parts = value.into_parts();
+        Ok(Self {
+            authorization: parts.authorization.into(),
+            environment: parts.environment.try_into()?,
+            collaboration: parts.collaboration.into(),
+            features: parts.features.into(),
+        })
+    }
+}
+
+impl TryFrom<InfrastructureTenantConfig> for TenantConfig {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureTenantConfig) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.authorization.into(),
+            value.environment.try_into()?,
+            value.collaboration.into(),
+            value.features.try_into()?,
+        ))
+    }
+}
+
+impl From<TenantAuthorizationConfig> for InfrastructureAuthorizationConfig {
+    fn from(value: TenantAuthorizationConfig) -> Self {
+        let parts = value.into_parts();
+        Self {
+            allow_cross_tenant_access: parts.allow_cross_tenant_access,
+            require_reviewer_for_publish: parts.require_reviewer_for_publish,
+            default_role: parts.default_role.map(Into::into),
+            access_request_approver_role: parts.access_request_approver_role.map(Into::into),
+            max_access_grant_duration: parts.max_access_grant_duration,
+        }
+    }
+}
+
+impl From<InfrastructureAuthorizationConfig> for TenantAuthorizationConfig {
+    fn from(value: InfrastructureAuthorizationConfig) -> Self {
+        Self::new(
+            value.allow_cross_tenant_access,
+            value.require_reviewer_for_publish,
+            value.default_role.map(Into::into),
+            value.access_request_approver_role.map(Into::into),
+            value.max_access_grant_duration,
+        )
+    }
+}
+
+impl TryFrom<TenantEnvironmentConfig> for InfrastructureEnvironmentConfig {
+    type Error = InfrastructureError;
+
+    fn try_from(value: TenantEnvironmentConfig) -> InfrastructureResult<Self> {
+        let parts = value.into_parts();
+        Ok(Self {
+            allowed_time_window: parts.allowed_time_window.map(TryInto::try_into).transpose()?,
+            allowed_networks: parts.allowed_networks.into_iter().map(Into::into).collect(),
+            require_managed_device: parts.require_managed_device,
+            max_risk_score: parts.max_risk_score,
+        })
+    }
+}
+
+impl TryFrom<InfrastructureEnvironmentConfig> for TenantEnvironmentConfig {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureEnvironmentConfig) -> InfrastructureResult<Self> {
+        let networks: Vec<NetworkZone> = value.allowed_networks.into_iter().map(Into::into).collect();
+        Ok(Self::new(
+            value.allowed_time_window.map(TryInto::try_into).transpose()?,
+            &networks,
+            value.require_managed_device,
+            value.max_risk_score,
+        ))
+    }
+}
+
+impl From<TenantCollaborationConfig> for InfrastructureCollaborationConfig {
+    fn from(value: TenantCollaborationConfig) -> Self {
+        let parts = value.into_parts();
+        Self {
+            trusted_tenants: parts.trusted_tenants.into_iter().map(Into::into).collect(),
+            allowed_modes: parts.allowed_modes.into_iter().map(Into::into).collect(),
+            federation: parts.federation.into(),
+            access_level: parts.access_level.into(),
+        }
+    }
+}
+
+impl From<InfrastructureCollaborationConfig> for TenantCollaborationConfig {
+    fn from(value: InfrastructureCollaborationConfig) -> Self {
+        Self::new(
+            value.trusted_tenants.into_iter().map(Into::into).collect(),
+            value.allowed_modes.into_iter().map(Into::into).collect(),
+            value.federation.into(),
+            value.access_level.into(),
+        )
+    }
+}
+
+impl From<TenantFeatureConfig> for InfrastructureFeatureConfig {
+    fn from(value: TenantFeatureConfig) -> Self {
+        let parts = value.into_parts();
+        let limits = parts.limits.into_parts();
+        Self {
+            enabled_features: parts.enabled_features.into_iter().map(Into::into).collect(),
+            max_projects: limits.max_projects,
+            max_users: limits.max_users,
+            api_rate_limit: limits.api_rate_limit,
+            rollout: parts.rollout.into(),
+        }
+    }
+}
+
+impl TryFrom<InfrastructureFeatureConfig> for TenantFeatureConfig {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureFeatureConfig) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.enabled_features.into_iter().map(Into::into).collect(),
+            FeatureLimits::new(value.max_projects, value.max_users, value.api_rate_limit),
+            value.rollout.into(),
+        )?)
+    }
+}
+
+impl TryFrom<TimeWindow> for InfrastructureTimeWindow {
+    type Error = InfrastructureError;
+
+    fn try_from(value: TimeWindow) -> InfrastructureResult<Self> {
+        Ok(match value {
+            TimeWindow::Absolute { start, end } => Self::Absolute {
+                start: start.try_into()?,
+                end: end.try_into()?,
+            },
+            TimeWindow::Recurring {
+                days,
+                start_seconds,
+                end_seconds,
+            } => Self::Recurring {
+                days: days.into_iter().map(Into::into).collect(),
+                start_seconds,
+                end_seconds,
+            },
+        })
+    }
+}
+
+impl TryFrom<InfrastructureTimeWindow> for TimeWindow {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureTimeWindow) -> InfrastructureResult<Self> {
+        Ok(match value {
+            InfrastructureTimeWindow::Absolute { start, end } => Self::Absolute {
+                start: start.try_into()?,
+                end: end.try_into()?,
+            },
+            InfrastructureTimeWindow::Recurring {
+                days,
+                start_seconds,
+                end_seconds,
+            } => Self::Recurring {
+                days: days.into_iter().map(Into::into).collect(),
+                start_seconds,
+                end_seconds,
+            },
+        })
+    }
+}
+
+impl From<Weekday> for InfrastructureWeekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Saturday => Self::Saturday,
+            Weekday::Sunday => Self::Sunday,
+            Weekday::Monday => Self::Monday,
+            Weekday::Tuesday => Self::Tuesday,
+            Weekday::Wednesday => Self::Wednesday,
+            Weekday::Thursday => Self::Thursday,
+            Weekday::Friday => Self::Friday,
+        }
+    }
+}
+
+impl From<InfrastructureWeekday> for Weekday {
+    fn from(value: InfrastructureWeekday) -> Self {
+        match value {
+            InfrastructureWeekday::Saturday => Self::Saturday,
+            InfrastructureWeekday::Sunday => Self::Sunday,
+            InfrastructureWeekday::Monday => Self::Monday,
+            InfrastructureWeekday::Tuesday => Self::Tuesday,
+            InfrastructureWeekday::Wednesday => Self::Wednesday,
+            InfrastructureWeekday::Thursday => Self::Thursday,
+            InfrastructureWeekday::Friday => Self::Friday,
+        }
+    }
+}
+
+impl From<NetworkZone> for InfrastructureNetworkZone {
+    fn from(value: NetworkZone) -> Self {
+        match value {
+            NetworkZone::TrustedCorporate => Self::TrustedCorporate,
+            NetworkZone::Internal => Self::Internal,
+            NetworkZone::Partner => Self::Partner,
+            NetworkZone::PublicInternet => Self::PublicInternet,
+            NetworkZone::Anonymous => Self::Anonymous,
+            NetworkZone::HighRisk => Self::HighRisk,
+        }
+    }
+}
+
+impl From<InfrastructureNetworkZone> for NetworkZone {
+    fn from(value: InfrastructureNetworkZone) -> Self {
+        match value {
+            InfrastructureNetworkZone::TrustedCorporate => Self::TrustedCorporate,
+            InfrastructureNetworkZone::Internal => Self::Internal,
+            InfrastructureNetworkZone::Partner => Self::Partner,
+            InfrastructureNetworkZone::PublicInternet => Self::PublicInternet,
+            InfrastructureNetworkZone::Anonymous => Self::Anonymous,
+            InfrastructureNetworkZone::HighRisk => Self::HighRisk,
+        }
+    }
+}
+
+impl From<CollaborationMode> for InfrastructureCollaborationMode {
+    fn from(value: CollaborationMode) -> Self {
+        match value {
+            CollaborationMode::GuestAccess => Self::GuestAccess,
+            CollaborationMode::ResourceSharing => Self::ResourceSharing,
+            CollaborationMode::ProjectCollaboration => Self::ProjectCollaboration,
+            CollaborationMode::FullFederation => Self::FullFederation,
+        }
+    }
+}
+
+impl From<InfrastructureCollaborationMode> for CollaborationMode {
+    fn from(value: InfrastructureCollaborationMode) -> Self {
+        match value {
+            InfrastructureCollaborationMode::GuestAccess => Self::GuestAccess,
+            InfrastructureCollaborationMode::ResourceSharing => Self::ResourceSharing,
+            InfrastructureCollaborationMode::ProjectCollaboration => Self::ProjectCollaboration,
+            InfrastructureCollaborationMode::FullFederation => Self::FullFederation,
+        }
+    }
+}
+
+impl From<FederationPolicy> for InfrastructureFederationPolicy {
+    fn from(value: FederationPolicy) -> Self {
+        match value {
+            FederationPolicy::Disabled => Self::Disabled,
+            FederationPolicy::VerifiedOnly => Self::VerifiedOnly,
+            FederationPolicy::TrustedTenantsOnly => Self::TrustedTenantsOnly,
+        }
+    }
+}
+
+impl From<InfrastructureFederationPolicy> for FederationPolicy {
+    fn from(value: InfrastructureFederationPolicy) -> Self {
+        match value {
+            InfrastructureFederationPolicy::Disabled => Self::Disabled,
+            InfrastructureFederationPolicy::VerifiedOnly => Self::VerifiedOnly,
+            InfrastructureFederationPolicy::TrustedTenantsOnly => Self::TrustedTenantsOnly,
+        }
+    }
+}
+
+impl From<CollaborationAccessLevel> for InfrastructureCollaborationAccessLevel {
+    fn from(value: CollaborationAccessLevel) -> Self {
+        match value {
+            CollaborationAccessLevel::ReadOnly => Self::ReadOnly,
+            CollaborationAccessLevel::Contributor => Self::Contributor,
+            CollaborationAccessLevel::Admin => Self::Admin,
+        }
+    }
+}
+
+impl From<InfrastructureCollaborationAccessLevel> for CollaborationAccessLevel {
+    fn from(value: InfrastructureCollaborationAccessLevel) -> Self {
+        match value {
+            InfrastructureCollaborationAccessLevel::ReadOnly => Self::ReadOnly,
+            InfrastructureCollaborationAccessLevel::Contributor => Self::Contributor,
+            InfrastructureCollaborationAccessLevel::Admin => Self::Admin,
+        }
+    }
+}
+
+impl From<Feature> for InfrastructureFeature {
+    fn from(value: Feature) -> Self {
+        match value {
+            Feature::AdvancedReports => Self::AdvancedReports,
+            Feature::CrossTenantSharing => Self::CrossTenantSharing,
+            Feature::AuditLogs => Self::AuditLogs,
+            Feature::ApiAccess => Self::ApiAccess,
+            Feature::RiskScoring => Self::RiskScoring,
+            Feature::BetaDashboard => Self::BetaDashboard,
+        }
+    }
+}
+
+impl From<InfrastructureFeature> for Feature {
+    fn from(value: InfrastructureFeature) -> Self {
+        match value {
+            InfrastructureFeature::AdvancedReports => Self::AdvancedReports,
+            InfrastructureFeature::CrossTenantSharing => Self::CrossTenantSharing,
+            InfrastructureFeature::AuditLogs => Self::AuditLogs,
+            InfrastructureFeature::ApiAccess => Self::ApiAccess,
+            InfrastructureFeature::RiskScoring => Self::RiskScoring,
+            InfrastructureFeature::BetaDashboard => Self::BetaDashboard,
+        }
+    }
+}
+
+impl From<FeatureRolloutPolicy> for InfrastructureFeatureRolloutPolicy {
+    fn from(value: FeatureRolloutPolicy) -> Self {
+        match value {
+            FeatureRolloutPolicy::Stable => Self::Stable,
+            FeatureRolloutPolicy::Beta => Self::Beta,
+            FeatureRolloutPolicy::Canary { percentage } => Self::Canary { percentage },
+        }
+    }
+}
+
+impl From<InfrastructureFeatureRolloutPolicy> for FeatureRolloutPolicy {
+    fn from(value: InfrastructureFeatureRolloutPolicy) -> Self {
+        match value {
+            InfrastructureFeatureRolloutPolicy::Stable => Self::Stable,
+            InfrastructureFeatureRolloutPolicy::Beta => Self::Beta,
+            InfrastructureFeatureRolloutPolicy::Canary { percentage } => Self::Canary { percentage },
+        }
+    }
+}
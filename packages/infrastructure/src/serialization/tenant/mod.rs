@@ -0,0 +1,48 @@
+pub mod config;
+pub mod status;
+pub mod tenant;
+
+pub use config::InfrastructureTenantConfig;
+pub use status::InfrastructureTenantStatus;
+pub use tenant::InfrastructureTenant;
+
+use domain::TenantId;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct InfrastructureTenantId(String);
+
+impl InfrastructureTenantId {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_string())
+    }
+
+    pub fn id(&self) -> String {
+        self.0.clone()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Serialize for InfrastructureTenantId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl From<TenantId> for InfrastructureTenantId {
+    fn from(value: TenantId) -> Self {
+        Self::new(value.as_str())
+    }
+}
+
+impl From<InfrastructureTenantId> for TenantId {
+    fn from(value: InfrastructureTenantId) -> Self {
+        Self::new(value.as_str())
+    }
+}
@@ -0,0 +1,48 @@
+use domain::TenantStatus;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::value_objects::InfrastructureDateTime,
+};
+
+/// Stored as a plain string next to an optional `purge_at`, so the status can
+/// be filtered and indexed without unpacking `PendingDeletion`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfrastructureTenantStatus {
+    Active,
+    Suspended,
+    PendingDeletion,
+    Deleted,
+}
+
+impl InfrastructureTenantStatus {
+    pub fn split(
+        status: TenantStatus,
+    ) -> InfrastructureResult<(Self, Option<InfrastructureDateTime>)> {
+        Ok(match status {
+            TenantStatus::Active => (Self::Active, None),
+            TenantStatus::Suspended => (Self::Suspended, None),
+            TenantStatus::PendingDeletion { purge_at } => {
+                (Self::PendingDeletion, Some(purge_at.try_into()?))
+            }
+            TenantStatus::Deleted => (Self::Deleted, None),
+        })
+    }
+
+    pub fn join(self, purge_at: Option<InfrastructureDateTime>) -> InfrastructureResult<TenantStatus> {
+        Ok(match self {
+            Self::Active => TenantStatus::Active,
+            Self::Suspended => TenantStatus::Suspended,
+            Self::PendingDeletion => TenantStatus::PendingDeletion {
+                purge_at: purge_at
+                    .ok_or(InfrastructureError::ValidationError(
+                        "Purge At not found".to_string(),
+                    ))?
+                    .try_into()?,
+            },
+            Self::Deleted => TenantStatus::Deleted,
+        })
+    }
+}
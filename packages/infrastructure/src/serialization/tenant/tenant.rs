@@ -0,0 +1,80 @@
+use domain::{Event, Tenant};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    serialization::value_objects::{InfrastructureDateTime, InfrastructureDescription, InfrastructureName},
+};
+
+use super::{InfrastructureTenantConfig, InfrastructureTenantId, InfrastructureTenantStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureTenant {
+    id: InfrastructureTenantId,
+    name: InfrastructureName,
+    description: InfrastructureDescription,
+    created_at: InfrastructureDateTime,
+    config: InfrastructureTenantConfig,
+    status: InfrastructureTenantStatus,
+    purge_at: Option<InfrastructureDateTime>,
+    version: u64,
+}
+
+impl InfrastructureTenant {
+    pub fn id(&self) -> InfrastructureTenantId {
+        self.id.clone()
+    }
+
+    pub fn status(&self) -> InfrastructureTenantStatus {
+        self.status
+    }
+
+    pub fn purge_at(&self) -> Option<InfrastructureDateTime> {
+        self.purge_at.clone()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl Event for InfrastructureTenant {
+    fn get_type(&self) -> &str {
+        "TENANT"
+    }
+}
+
+impl TryFrom<Tenant> for InfrastructureTenant {
+    type Error = InfrastructureError;
+
+    fn try_from(value: Tenant) -> InfrastructureResult<Self> {
+        let parts = value.into_parts();
+        let (status, purge_at) = InfrastructureTenantStatus::split(parts.status)?;
+        Ok(Self {
+            id: parts.id.into(),
+            name: parts.name.try_into()?,
+            description: parts.description.try_into()?,
+            created_at: parts.created_at.try_into()?,
+            config: parts.config.try_into()?,
+            status,
+            purge_at,
+            version: parts.version,
+        })
+    }
+}
+
+impl TryFrom<InfrastructureTenant> for Tenant {
+    type Error = InfrastructureError;
+
+    fn try_from(value: InfrastructureTenant) -> InfrastructureResult<Self> {
+        Ok(Self::new(
+            value.id.into(),
+            value.name.try_into()?,
+            value.description.try_into()?,
+            value.created_at.try_into()?,
+            value.config.try_into()?,
+            value.status.join(value.purge_at)?,
+            value.version,
+        ))
+    }
+}
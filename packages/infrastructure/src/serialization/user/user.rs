@@ -28,6 +28,7 @@ pub struct InfrastructureUser {
     failed_logins: Option<u64>,
     locked_until: Option<InfrastructureDateTime>,
     last_login: Option<InfrastructureDateTime>,
+    version: u64,
 }
 
 impl InfrastructureUser {
@@ -77,6 +78,10 @@ impl InfrastructureUser {
     pub fn last_login(&self) -> Option<InfrastructureDateTime> {
         self.last_login.clone()
     }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 // Builder pattern for complex object creation
@@ -92,6 +97,7 @@ pub struct InfrastructureUserBuilder {
     failed_logins: Option<u64>,
     locked_until: Option<InfrastructureDateTime>,
     last_login: Option<InfrastructureDateTime>,
+    version: u64,
 }
 
 impl InfrastructureUserBuilder {
@@ -108,6 +114,7 @@ impl InfrastructureUserBuilder {
             failed_logins: None,
             locked_until: None,
             last_login: None,
+            version: 1,
         }
     }
 
@@ -164,6 +171,11 @@ impl InfrastructureUserBuilder {
         self
     }
 
+    pub fn set_version(&mut self, version: u64) -> &mut Self {
+        self.version = version;
+        self
+    }
+
     pub fn build(self) -> InfrastructureResult<InfrastructureUser> {
         Ok(InfrastructureUser {
             id: self.id,
@@ -183,6 +195,7 @@ impl InfrastructureUserBuilder {
             failed_logins: self.failed_logins,
             locked_until: self.locked_until,
             last_login: self.last_login,
+            version: self.version,
         })
     }
 }
@@ -197,7 +210,8 @@ impl TryFrom<User> for InfrastructureUser {
             .set_status(value.status().into())
             .set_profile(value.profile().try_into()?)
             .set_preferences(value.preferences().try_into()?)
-            .set_username(value.username().try_into()?);
+            .set_username(value.username().try_into()?)
+            .set_version(*value.version());
         for permission in value.permissions().into_iter() {
             user_builder.add_permission(permission.into());
         }
@@ -237,7 +251,8 @@ impl TryFrom<InfrastructureUser> for User {
             .set_status(value.status().into())
             .set_profile(value.profile().try_into()?)
             .set_preferences(value.preferences().try_into()?)
-            .set_username(value.username().try_into()?);
+            .set_username(value.username().try_into()?)
+            .set_version(value.version());
         for permission in value.permissions().into_iter() {
             user_builder.add_permission(permission.into());
         }
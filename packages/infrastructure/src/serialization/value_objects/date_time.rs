@@ -9,7 +9,7 @@ pub struct InfrastructureDateTime(DateTime<Utc>);
 
 impl InfrastructureDateTime {
     pub fn new(datetime: DomainDateTime) -> InfrastructureResult<Self> {
-        match Utc.timestamp_opt(datetime.datetime(), 0).single() {
+        match Utc.timestamp_opt(*datetime.datetime(), 0).single() {
             Some(datetime) => Ok(Self(datetime)),
             None => Err(InfrastructureError::InvalidTimestamp),
         }
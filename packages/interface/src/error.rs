@@ -1,4 +1,8 @@
 use application::error::AppError;
+use axum::{
+    http::{StatusCode, header::RETRY_AFTER},
+    response::{IntoResponse, Response},
+};
 use domain::error::DomainError;
 // use surrealdb::error::{Api, Db};
 use thiserror::Error;
@@ -29,30 +33,76 @@ pub enum InterfaceError {
 
 pub type InterfaceResult<T> = Result<T, InterfaceError>;
 
+impl InterfaceError {
+    /// The HTTP status the error is answered with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            InterfaceError::Application(AppError::RateLimited { .. }) => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            InterfaceError::Application(AppError::Forbidden) | InterfaceError::Forbidden => {
+                StatusCode::FORBIDDEN
+            }
+            InterfaceError::Application(
+                AppError::ValidationError(_)
+                | AppError::PolicyError(_)
+                | AppError::FieldNotWritable(_)
+                | AppError::Domain(_),
+            )
+            | InterfaceError::Domain(_)
+            | InterfaceError::ValidationError(_)
+            | InterfaceError::UnHashedPassword
+            | InterfaceError::InvalidTimestamp => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Rate limited requests carry a `Retry-After` header with the seconds to
+/// wait.
+impl IntoResponse for InterfaceError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let retry_after = match &self {
+            InterfaceError::Application(AppError::RateLimited { retry_after }) => {
+                Some(*retry_after)
+            }
+            _ => None,
+        };
+        let mut response = (status, self.to_string()).into_response();
+        if let Some(retry_after) = retry_after {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, retry_after.into());
+        }
+        response
+    }
+}
+
 // For converting InterfaceError to DomainError
 impl From<InterfaceError> for DomainError {
     fn from(error: InterfaceError) -> Self {
         match error {
             InterfaceError::Domain(domain_error) => domain_error,
             InterfaceError::Application(application_error) => {
-                DomainError::InvalidOperation(application_error.to_string())
+                DomainError::InvalidOperation(application_error.to_string().into())
             }
             InterfaceError::Http(repo_error) => {
-                DomainError::InvalidOperation(repo_error.to_string())
+                DomainError::InvalidOperation(repo_error.to_string().into())
             }
             InterfaceError::Forbidden => {
-                DomainError::InvalidOperation("Forbidden Operation".to_string())
+                DomainError::InvalidOperation("Forbidden Operation".to_string().into())
             }
             InterfaceError::ValidationError(validation_error) => {
-                DomainError::ValidationError(validation_error.to_string())
+                DomainError::ValidationError(validation_error.to_string().into())
             }
             InterfaceError::UnHashedPassword => {
-                DomainError::ValidationError("Unhashed Password".to_string())
+                DomainError::ValidationError("Unhashed Password".to_string().into())
             }
             InterfaceError::InvalidTimestamp => {
-                DomainError::ValidationError("Invalid DateTime".to_string())
+                DomainError::ValidationError("Invalid DateTime".to_string().into())
             }
-            InterfaceError::Unknown(unknown) => DomainError::InvalidOperation(unknown),
+            InterfaceError::Unknown(unknown) => DomainError::InvalidOperation(unknown.into()),
         }
     }
 }
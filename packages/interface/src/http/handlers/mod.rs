@@ -1,3 +1,4 @@
+pub mod page;
 pub mod report;
 pub mod role;
 pub mod user;
@@ -0,0 +1,101 @@
+//! Query parameters and body of the paged list endpoints. `cursor` is the
+//! `next_cursor` of the previous page, `page_size` defaults to
+//! [`DEFAULT_PAGE_SIZE`] and is capped at [`MAX_PAGE_SIZE`], and `sort`
+//! reads like `title:asc,created_at:desc`.
+
+use application::ports::{Cursor, Page, SortBy};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{InterfaceError, InterfaceResult};
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PageQuery {
+    pub cursor: Option<String>,
+    pub page_size: Option<u32>,
+    pub sort: Option<String>,
+}
+
+impl PageQuery {
+    pub fn after(&self) -> InterfaceResult<Option<Cursor>> {
+        Ok(self.cursor.as_deref().map(Cursor::decode).transpose()?)
+    }
+
+    pub fn page_size(&self) -> InterfaceResult<u32> {
+        match self.page_size.unwrap_or(DEFAULT_PAGE_SIZE) {
+            0 => Err(InterfaceError::ValidationError(
+                "page_size must be at least 1".to_string(),
+            )),
+            page_size => Ok(page_size.min(MAX_PAGE_SIZE)),
+        }
+    }
+
+    /// Fields sort ascending unless followed by `:desc`.
+    pub fn sort_by(&self) -> InterfaceResult<Vec<SortBy>> {
+        let Some(sort) = self.sort.as_deref().filter(|sort| !sort.is_empty()) else {
+            return Ok(Vec::new());
+        };
+        sort.split(',')
+            .map(|part| match part.split_once(':') {
+                None => Ok(SortBy::Ascending(part.to_string())),
+                Some((field, "asc")) => Ok(SortBy::Ascending(field.to_string())),
+                Some((field, "desc")) => Ok(SortBy::Descending(field.to_string())),
+                Some(_) => Err(InterfaceError::ValidationError(format!(
+                    "cannot sort by {part}, expected field:asc or field:desc"
+                ))),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PageResponse<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T> PageResponse<T> {
+    pub fn new<V>(page: Page<V>, item: impl FnMut(V) -> T) -> Self {
+        Self {
+            items: page.items.into_iter().map(item).collect(),
+            next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+            has_more: page.has_more,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_sort_and_page_size() {
+        let query = PageQuery {
+            page_size: Some(1_000),
+            sort: Some("title,created_at:desc".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.sort_by().unwrap(),
+            [
+                SortBy::Ascending("title".to_string()),
+                SortBy::Descending("created_at".to_string())
+            ]
+        );
+        assert_eq!(query.page_size().unwrap(), MAX_PAGE_SIZE);
+        assert!(query.after().unwrap().is_none());
+
+        let query = PageQuery {
+            cursor: Some("zz".to_string()),
+            page_size: Some(0),
+            sort: Some("title:up".to_string()),
+        };
+        assert!(query.after().is_err());
+        assert!(query.page_size().is_err());
+        assert!(query.sort_by().is_err());
+        assert_eq!(PageQuery::default().page_size().unwrap(), DEFAULT_PAGE_SIZE);
+    }
+}
@@ -0,0 +1,72 @@
+//! Report endpoints. The subject and the environment of the request are
+//! resolved in front of these handlers and arrive as request extensions.
+
+use std::sync::Arc;
+
+use application::{
+    SubjectContex,
+    dto::report::view::ReportView,
+    usecases::{
+        pipeline::UseCase,
+        report::read::list::{ListReportInput, ListReportUseCase},
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+};
+use domain::tenant::environment::Environment;
+use serde::Serialize;
+
+use crate::error::InterfaceResult;
+
+use super::page::{PageQuery, PageResponse};
+
+#[derive(Clone)]
+pub struct ReportState {
+    pub list: Arc<ListReportUseCase>,
+}
+
+/// A report in a listing. Fields the subject may not read are left out.
+#[derive(Debug, Serialize)]
+pub struct ReportSummaryResponse {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub status: Option<String>,
+    pub author_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub updated_at: Option<i64>,
+    pub due_date: Option<i64>,
+    pub version: Option<u64>,
+}
+
+impl From<ReportView> for ReportSummaryResponse {
+    fn from(view: ReportView) -> Self {
+        Self {
+            id: view.id.map(|id| id.as_str().to_string()),
+            title: view.title.map(|title| title.title().to_string()),
+            status: view.status.map(|status| status.to_string()),
+            author_id: view.author_id.map(|id| id.as_str().to_string()),
+            created_at: view.created_at.map(|at| *at.datetime()),
+            updated_at: view.updated_at.map(|at| *at.datetime()),
+            due_date: view.due_date.map(|at| *at.datetime()),
+            version: view.version,
+        }
+    }
+}
+
+/// `GET /reports?cursor=&page_size=&sort=`
+pub async fn list_reports(
+    State(state): State<ReportState>,
+    Extension(ctx): Extension<SubjectContex>,
+    Extension(environment): Extension<Environment>,
+    Query(query): Query<PageQuery>,
+) -> InterfaceResult<Json<PageResponse<ReportSummaryResponse>>> {
+    let input = ListReportInput {
+        sort_by: query.sort_by()?,
+        after: query.after()?,
+        page_size: query.page_size()?,
+    };
+    let page = state.list.execute(ctx, environment, input).await?;
+    Ok(Json(PageResponse::new(page, ReportSummaryResponse::from)))
+}
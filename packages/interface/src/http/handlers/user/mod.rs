@@ -0,0 +1,75 @@
+//! User endpoints. The subject and the environment of the request are
+//! resolved in front of these handlers and arrive as request extensions.
+
+use std::sync::Arc;
+
+use application::{
+    SubjectContex,
+    dto::user::view::UserView,
+    usecases::{
+        pipeline::UseCase,
+        user::read::list::{ListUserInput, ListUserUseCase},
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+};
+use domain::tenant::environment::Environment;
+use serde::Serialize;
+
+use crate::error::InterfaceResult;
+
+use super::page::{PageQuery, PageResponse};
+
+#[derive(Clone)]
+pub struct UserState {
+    pub list: Arc<ListUserUseCase>,
+}
+
+/// A user in a listing. Fields the subject may not read are left out.
+#[derive(Debug, Serialize)]
+pub struct UserSummaryResponse {
+    pub id: Option<String>,
+    pub email: Option<String>,
+    pub username: Option<String>,
+    pub status: Option<String>,
+    pub last_login: Option<i64>,
+    pub created_at: Option<i64>,
+    pub version: Option<u64>,
+}
+
+impl From<UserView> for UserSummaryResponse {
+    fn from(view: UserView) -> Self {
+        Self {
+            id: view.id.map(|id| id.as_str().to_string()),
+            email: view.email.map(|email| email.email().to_string()),
+            username: view
+                .username
+                .map(|username| username.username().to_string()),
+            status: view.status.map(|status| status.to_string()),
+            last_login: view.last_login.map(|at| *at.datetime()),
+            created_at: view
+                .profile
+                .and_then(|profile| profile.created_at)
+                .map(|at| *at.datetime()),
+            version: view.version,
+        }
+    }
+}
+
+/// `GET /users?cursor=&page_size=&sort=`
+pub async fn list_users(
+    State(state): State<UserState>,
+    Extension(ctx): Extension<SubjectContex>,
+    Extension(environment): Extension<Environment>,
+    Query(query): Query<PageQuery>,
+) -> InterfaceResult<Json<PageResponse<UserSummaryResponse>>> {
+    let input = ListUserInput {
+        sort_by: query.sort_by()?,
+        after: query.after()?,
+        page_size: query.page_size()?,
+    };
+    let page = state.list.execute(ctx, environment, input).await?;
+    Ok(Json(PageResponse::new(page, UserSummaryResponse::from)))
+}
@@ -0,0 +1,19 @@
+use axum::{Router, routing::get};
+
+use super::handlers::{
+    report::{ReportState, list_reports},
+    user::{UserState, list_users},
+};
+
+/// The list endpoints. They expect the subject and the environment as
+/// request extensions, set by the layer that authenticates the request.
+pub fn router(reports: ReportState, users: UserState) -> Router {
+    Router::new()
+        .route("/reports", get(list_reports))
+        .with_state(reports)
+        .merge(
+            Router::new()
+                .route("/users", get(list_users))
+                .with_state(users),
+        )
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::access_request::{command::AccessRequestCommand, view::AccessRequestView}, error::{AppError, AppResult}, ports::{AccessRequestRepository, Cursor, Page, SortBy}, query::ast::Value};
+use domain::{AccessRequest, AggregateRoot, AccessRequestId, AccessRequestStatus};
+
+use super::{InMemoryEventRepository, Records, missing, now, page_after};
+
+const TABLE: &str = "access_request";
+const SORTABLE: &[&str] = &["requester_id", "status", "created_at", "updated_at", "version"];
+
+/// Access requests are scoped to the subject's tenant. Creating one records
+/// its `created` event in the outbox, like every later decision.
+pub struct InMemoryAccessRequestRepository {
+    requests: Records<AccessRequest>,
+    events: Arc<InMemoryEventRepository<AccessRequest>>,
+}
+
+impl Default for InMemoryAccessRequestRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryAccessRequestRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<AccessRequest>>) -> Self {
+        Self { requests: Records::new(TABLE), events }
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<AccessRequest>> {
+        self.events.clone()
+    }
+}
+
+#[async_trait]
+impl AccessRequestRepository for InMemoryAccessRequestRepository {
+    async fn create(&self, ctx: SubjectContex, command: AccessRequestCommand) -> AppResult<AccessRequestView> {
+        let id = command.id.clone().unwrap_or_else(|| AccessRequestId::new(&self.requests.next_id()));
+        let tenant_id = command.tenant_id.clone().unwrap_or_else(|| ctx.tenant_id());
+        if tenant_id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let requester_id = command.requester_id.clone().unwrap_or_else(|| ctx.user_id());
+        let created_at = command.created_at.unwrap_or_else(now);
+
+        let mut builder = AccessRequest::new(id.clone(), requester_id.clone(), tenant_id);
+        builder
+            .set_resource(command.resource.ok_or_else(|| missing(TABLE, "resource"))?)
+            .set_action(command.action.ok_or_else(|| missing(TABLE, "action"))?)
+            .set_justification(command.justification.ok_or_else(|| missing(TABLE, "justification"))?)
+            .set_requested_duration(command.requested_duration.ok_or_else(|| missing(TABLE, "requested_duration"))?)
+            .set_created_at(created_at);
+        let mut request = builder.build(created_at)?;
+        request.mark_created(&requester_id, created_at);
+
+        let request = self.requests.save(id.as_str(), request, &self.events, |request| *request.version(), |rows, _| {
+            if rows.contains_key(id.as_str()) {
+                return Err(AppError::Repository(format!("{TABLE}:{id} already exists")));
+            }
+            Ok(())
+        })?;
+        Ok(request.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: AccessRequestId) -> AppResult<AccessRequestView> {
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn get_by_status_page(&self, ctx: SubjectContex, status: Option<AccessRequestStatus>, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<AccessRequestView>> {
+        let requests = self.requests.filter(|request| {
+            request.belongs_to(&ctx.tenant_id) && status.is_none_or(|status| request.status() == &status)
+        })?;
+        Ok(page_after(requests, sort_by, SORTABLE, after, page_size, key)?.map(AccessRequestView::from))
+    }
+
+    async fn load(&self, ctx: SubjectContex, id: AccessRequestId) -> AppResult<AccessRequest> {
+        self.requests
+            .get(id.as_str())?
+            .filter(|request| request.belongs_to(&ctx.tenant_id))
+            .ok_or(AppError::Repository("Access request not found!".to_string()))
+    }
+
+    async fn save(&self, ctx: SubjectContex, request: AccessRequest) -> AppResult<AccessRequest> {
+        let id = request.id().as_str().to_string();
+        self.requests.save(&id, request, &self.events, |request| *request.version(), |rows, request| {
+            let stored_elsewhere = rows.get(&id).is_some_and(|stored| !stored.belongs_to(&ctx.tenant_id));
+            if stored_elsewhere || !request.belongs_to(&ctx.tenant_id) {
+                return Err(AppError::Forbidden);
+            }
+            Ok(())
+        })
+    }
+}
+
+fn key(request: &AccessRequest, field: &str) -> Value {
+    match field {
+        "id" => request.id().as_str().into(),
+        "requester_id" => request.requester_id().into(),
+        "status" => request.status().to_string().into(),
+        "created_at" => (*request.created_at()).into(),
+        "updated_at" => (*request.updated_at()).into(),
+        "version" => (*request.version()).into(),
+        _ => Value::Null,
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::RwLock;
+
+use application::{SubjectContex, error::{AppError, AppResult}, ports::events::EventRepository};
+use async_trait::async_trait;
+use domain::{DomainEventId, Event, UserId, events::DomainEvent};
+
+/// The outbox of the in-memory adapters: every event a repository `save`
+/// takes from an aggregate lands here, in the order it was recorded.
+pub struct InMemoryEventRepository<T: Clone + Event> {
+    events: RwLock<Vec<DomainEvent<T>>>,
+}
+
+impl<T: Clone + Event> Default for InMemoryEventRepository<T> {
+    fn default() -> Self {
+        Self { events: RwLock::new(Vec::new()) }
+    }
+}
+
+impl<T: Clone + Event> InMemoryEventRepository<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, events: Vec<DomainEvent<T>>) -> AppResult<()> {
+        self.events
+            .write()
+            .map_err(|_| AppError::Repository("outbox is poisoned".to_string()))?
+            .extend(events);
+        Ok(())
+    }
+
+    pub fn all(&self) -> AppResult<Vec<DomainEvent<T>>> {
+        Ok(self
+            .events
+            .read()
+            .map_err(|_| AppError::Repository("outbox is poisoned".to_string()))?
+            .clone())
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Event + Send + Sync> EventRepository<T> for InMemoryEventRepository<T> {
+    async fn get_by_user(&self, _ctx: SubjectContex, user_id: UserId) -> AppResult<DomainEvent<T>> {
+        self.all()?
+            .into_iter()
+            .rev()
+            .find(|event| event.user_id() == &user_id)
+            .ok_or(AppError::Repository("Event not found!".to_string()))
+    }
+
+    async fn get_by_id(&self, _ctx: SubjectContex, id: DomainEventId) -> AppResult<DomainEvent<T>> {
+        self.all()?
+            .into_iter()
+            .find(|event| event.id().as_str() == id.as_str())
+            .ok_or(AppError::Repository("Event not found!".to_string()))
+    }
+
+    async fn get_by_table(&self, _ctx: SubjectContex, table: &str) -> AppResult<Vec<DomainEvent<T>>> {
+        Ok(self
+            .all()?
+            .into_iter()
+            .filter(|event| event.table().name() == table)
+            .collect())
+    }
+
+    /// `sort_by` is a field name optionally followed by `ASC` or `DESC`, as in
+    /// the SurrealQL `ORDER BY` clause the database adapter builds.
+    async fn get_users_paginated(&self, _ctx: SubjectContex, sort_by: &str, page: u32, page_size: u32) -> AppResult<Vec<DomainEvent<T>>> {
+        let mut order = sort_by.split_whitespace();
+        let field = order.next().unwrap_or("occurred_at");
+        let descending = order.next().is_some_and(|direction| direction.eq_ignore_ascii_case("DESC"));
+
+        let mut events = self.all()?;
+        match field {
+            "occurred_at" => events.sort_by_key(|event| *event.occurred_at()),
+            "id" => events.sort_by(|a, b| a.id().as_str().cmp(b.id().as_str())),
+            "action" => events.sort_by(|a, b| a.action().cmp(b.action())),
+            "table" => events.sort_by_key(|event| event.table().name()),
+            "user_id" => events.sort_by(|a, b| a.user_id().as_str().cmp(b.user_id().as_str())),
+            _ => return Err(AppError::ValidationError(format!("cannot sort events by {field}"))),
+        }
+        if descending {
+            events.reverse();
+        }
+        Ok(super::paginate(events, page, page_size))
+    }
+}
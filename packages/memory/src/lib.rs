@@ -0,0 +1,448 @@
+//! In-memory adapters for every repository port.
+//!
+//! They keep aggregates in process memory and follow the same rules as the
+//! SurrealDB adapters: zero based pages and keyset cursors, `SortBy`
+//! ordering, optimistic `version` checks on writes, domain events recorded
+//! in the outbox for every create, update and delete, and tenant scoping on
+//! reads. Use cases and policies can be exercised against them without a
+//! running database.
+
+pub mod access_request;
+pub mod events;
+pub mod membership;
+pub mod policy;
+pub mod report;
+pub mod role;
+pub mod temporary_grant;
+pub mod tenant;
+pub mod user;
+
+pub use access_request::InMemoryAccessRequestRepository;
+pub use events::InMemoryEventRepository;
+pub use membership::InMemoryMembershipRepository;
+pub use policy::InMemoryPolicyRepository;
+pub use report::InMemoryReportRepository;
+pub use role::InMemoryRoleRepository;
+pub use temporary_grant::InMemoryTemporaryGrantRepository;
+pub use tenant::InMemoryTenantRepository;
+pub use user::InMemoryUserRepository;
+
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+        RwLock, RwLockReadGuard, RwLockWriteGuard,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use application::{
+    error::{AppError, AppResult},
+    ports::{Cursor, Page, SortBy},
+    query::{
+        ast::{Comparison, Direction, Expr, SelectAst, Value},
+        schema::FieldPath,
+    },
+};
+use domain::{AggregateRoot, DateTime};
+
+/// Wall clock used for timestamps a command leaves empty, the way the
+/// database fills them with `time::now()`.
+pub fn now() -> DateTime {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    DateTime::new(seconds)
+}
+
+/// A single table: records keyed by id, listed in id order.
+pub(crate) struct Records<T> {
+    table: &'static str,
+    rows: RwLock<BTreeMap<String, T>>,
+    sequence: AtomicU64,
+}
+
+impl<T: Clone> Records<T> {
+    pub(crate) fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            rows: RwLock::new(BTreeMap::new()),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Ids handed out for commands that do not carry one.
+    pub(crate) fn next_id(&self) -> String {
+        format!("{:020}", self.sequence.fetch_add(1, AtomicOrdering::SeqCst) + 1)
+    }
+
+    pub(crate) fn get(&self, id: &str) -> AppResult<Option<T>> {
+        Ok(self.read()?.get(id).cloned())
+    }
+
+    pub(crate) fn find(&self, predicate: impl Fn(&T) -> bool) -> AppResult<Option<T>> {
+        Ok(self.read()?.values().find(|record| predicate(record)).cloned())
+    }
+
+    pub(crate) fn filter(&self, predicate: impl Fn(&T) -> bool) -> AppResult<Vec<T>> {
+        Ok(self
+            .read()?
+            .values()
+            .filter(|record| predicate(record))
+            .cloned()
+            .collect())
+    }
+
+    pub(crate) fn insert(&self, id: String, record: T) -> AppResult<()> {
+        self.write()?.insert(id, record);
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, id: &str) -> AppResult<Option<T>> {
+        Ok(self.write()?.remove(id))
+    }
+
+    /// Runs `write` against the stored record while holding the table lock, so
+    /// version and uniqueness checks cannot race with other writers.
+    pub(crate) fn upsert(
+        &self,
+        id: &str,
+        write: impl FnOnce(&BTreeMap<String, T>, Option<&T>) -> AppResult<T>,
+    ) -> AppResult<T> {
+        let mut rows = self.write()?;
+        let record = write(&*rows, rows.get(id))?;
+        rows.insert(id.to_string(), record.clone());
+        Ok(record)
+    }
+
+    fn read(&self) -> AppResult<RwLockReadGuard<'_, BTreeMap<String, T>>> {
+        self.rows
+            .read()
+            .map_err(|_| AppError::Repository(format!("{} table is poisoned", self.table)))
+    }
+
+    fn write(&self) -> AppResult<RwLockWriteGuard<'_, BTreeMap<String, T>>> {
+        self.rows
+            .write()
+            .map_err(|_| AppError::Repository(format!("{} table is poisoned", self.table)))
+    }
+}
+
+impl<T: AggregateRoot> Records<T> {
+    /// Stores the aggregate and moves its pending events to `outbox`. The
+    /// aggregate is rejected when the stored copy is not older than it, exactly
+    /// like the SurrealDB outbox transaction.
+    pub(crate) fn save(
+        &self,
+        id: &str,
+        mut aggregate: T,
+        outbox: &InMemoryEventRepository<T>,
+        version: impl Fn(&T) -> u64,
+        check: impl FnOnce(&BTreeMap<String, T>, &T) -> AppResult<()>,
+    ) -> AppResult<T> {
+        let events = aggregate.take_pending_events();
+        let saved = self.upsert(id, |rows, current| {
+            if let Some(current) = current
+                && version(current) >= version(&aggregate)
+            {
+                return Err(version_conflict(
+                    self.table,
+                    id,
+                    version(&aggregate),
+                    version(current),
+                ));
+            }
+            check(rows, &aggregate)?;
+            Ok(aggregate)
+        })?;
+        outbox.record(events)?;
+        Ok(saved)
+    }
+
+    /// Removes the aggregate and moves its pending events to `outbox`.
+    /// `aggregate` carries the version its deletion moved it to, so the
+    /// delete is refused when the stored copy is missing or not older, like
+    /// the SurrealDB outbox transaction.
+    pub(crate) fn delete(
+        &self,
+        id: &str,
+        mut aggregate: T,
+        outbox: &InMemoryEventRepository<T>,
+    ) -> AppResult<T> {
+        let events = aggregate.take_pending_events();
+        let version = aggregate.aggregate_version();
+        let removed = {
+            let mut rows = self.write()?;
+            match rows.get(id).map(T::aggregate_version) {
+                Some(stored) if stored < version => {}
+                stored => {
+                    return Err(version_conflict(
+                        self.table,
+                        id,
+                        version,
+                        stored.unwrap_or_default(),
+                    ))
+                }
+            }
+            rows.remove(id)
+        };
+        outbox.record(events)?;
+        removed.ok_or_else(|| AppError::Repository(format!("{}:{id} not deleted!", self.table)))
+    }
+}
+
+pub fn missing(table: &str, field: &str) -> AppError {
+    AppError::ValidationError(format!("{table}.{field} is required"))
+}
+
+pub fn version_conflict(table: &str, id: &str, expected: u64, found: u64) -> AppError {
+    AppError::Repository(format!(
+        "{table}:{id} version conflict, expected {expected} but found {found}"
+    ))
+}
+
+/// A command that carries a `version` must match the stored one; the stored
+/// version then moves forward by one.
+pub fn next_version(
+    table: &str,
+    id: &str,
+    stored: u64,
+    expected: Option<u64>,
+) -> AppResult<u64> {
+    match expected {
+        Some(expected) if expected != stored => {
+            Err(version_conflict(table, id, expected, stored))
+        }
+        _ => Ok(stored + 1),
+    }
+}
+
+/// Fields outside `sortable` are rejected instead of being silently ignored.
+fn check_sortable(sort_by: &[SortBy], sortable: &[&str]) -> AppResult<()> {
+    for order in sort_by {
+        if let SortBy::Ascending(field) | SortBy::Descending(field) = order
+            && !sortable.contains(&field.as_str())
+        {
+            return Err(AppError::ValidationError(format!(
+                "cannot sort by {field}, expected one of {}",
+                sortable.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Orders `records` by every `SortBy` in turn.
+pub(crate) fn sort_records<T>(
+    records: &mut [T],
+    sort_by: &[SortBy],
+    sortable: &[&str],
+    compare: impl Fn(&T, &T, &str) -> Ordering,
+) -> AppResult<()> {
+    check_sortable(sort_by, sortable)?;
+    records.sort_by(|a, b| {
+        sort_by
+            .iter()
+            .map(|order| match order {
+                SortBy::Ascending(field) => compare(a, b, field),
+                SortBy::Descending(field) => compare(a, b, field).reverse(),
+                SortBy::None => Ordering::Equal,
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(())
+}
+
+/// Keyset page of `records` ordered by `sort_by` and then by id, holding the
+/// rows strictly after `after`. `key` reads a sortable field, or `id`, as a
+/// comparable value.
+pub(crate) fn page_after<T>(
+    records: Vec<T>,
+    sort_by: &[SortBy],
+    sortable: &[&str],
+    after: Option<&Cursor>,
+    page_size: u32,
+    key: impl Fn(&T, &str) -> Value,
+) -> AppResult<Page<T>> {
+    check_sortable(sort_by, sortable)?;
+    let mut order: Vec<(&str, Direction)> = sort_by
+        .iter()
+        .filter_map(|sort| match sort {
+            SortBy::Ascending(field) => Some((field.as_str(), Direction::Ascending)),
+            SortBy::Descending(field) => Some((field.as_str(), Direction::Descending)),
+            SortBy::None => None,
+        })
+        .collect();
+    order.push(("id", Direction::Ascending));
+
+    let compare = |a: &[Value], b: &[Value]| {
+        order
+            .iter()
+            .zip(a.iter().zip(b))
+            .map(|((_, direction), (a, b))| {
+                let ordering = compare_values(a, b).unwrap_or(Ordering::Equal);
+                match direction {
+                    Direction::Ascending => ordering,
+                    Direction::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+
+    let mut rows: Vec<(Vec<Value>, T)> = records
+        .into_iter()
+        .map(|record| (order.iter().map(|(field, _)| key(&record, field)).collect(), record))
+        .collect();
+    rows.sort_by(|(a, _), (b, _)| compare(a, b));
+    if let Some(after) = after {
+        let keys = after.keys_for(sort_by)?;
+        if keys.len() != order.len() {
+            return Err(AppError::ValidationError("invalid cursor".to_string()));
+        }
+        rows.retain(|(row, _)| compare(row, keys).is_gt());
+    }
+
+    let has_more = rows.len() > page_size as usize;
+    rows.truncate(page_size as usize);
+    let next_cursor = rows
+        .last()
+        .filter(|_| has_more)
+        .map(|(keys, _)| Cursor::new(sort_by, keys.clone()));
+    Ok(Page {
+        items: rows.into_iter().map(|(_, record)| record).collect(),
+        next_cursor,
+        has_more,
+    })
+}
+
+pub(crate) fn paginate<T>(records: Vec<T>, page: u32, page_size: u32) -> Vec<T> {
+    records
+        .into_iter()
+        .skip(page as usize * page_size as usize)
+        .take(page_size as usize)
+        .collect()
+}
+
+/// Runs a typed select over `records`: filter, then order, then page.
+/// `value` reads a field the way the table stores it and rejects paths the
+/// record does not have.
+pub(crate) fn select<T>(
+    records: Vec<T>,
+    query: &SelectAst,
+    value: impl Fn(&T, &FieldPath) -> AppResult<Value>,
+) -> AppResult<Vec<T>> {
+    let mut rows = Vec::new();
+    for record in records {
+        if let Some(filter) = &query.filter
+            && !matches(filter, &|path: &FieldPath| value(&record, path))?
+        {
+            continue;
+        }
+        let keys = query
+            .order_by
+            .iter()
+            .map(|sort| value(&record, &sort.field))
+            .collect::<AppResult<Vec<_>>>()?;
+        rows.push((keys, record));
+    }
+    rows.sort_by(|(a, _), (b, _)| {
+        query
+            .order_by
+            .iter()
+            .zip(a.iter().zip(b))
+            .map(|(sort, (a, b))| {
+                let ordering = compare_values(a, b).unwrap_or(Ordering::Equal);
+                match sort.direction {
+                    Direction::Ascending => ordering,
+                    Direction::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(rows
+        .into_iter()
+        .map(|(_, record)| record)
+        .skip(query.start.unwrap_or_default() as usize)
+        .take(query.limit.map_or(usize::MAX, |limit| limit as usize))
+        .collect())
+}
+
+fn matches(expr: &Expr, value: &dyn Fn(&FieldPath) -> AppResult<Value>) -> AppResult<bool> {
+    Ok(match expr {
+        Expr::Compare { field, op, value: expected } => {
+            let actual = value(field)?;
+            let ordering = compare_values(&actual, expected);
+            match op {
+                Comparison::Eq => equals(&actual, expected),
+                Comparison::Ne => !equals(&actual, expected),
+                Comparison::Lt => ordering == Some(Ordering::Less),
+                Comparison::Le => ordering.is_some_and(Ordering::is_le),
+                Comparison::Gt => ordering == Some(Ordering::Greater),
+                Comparison::Ge => ordering.is_some_and(Ordering::is_ge),
+            }
+        }
+        Expr::In { field, values } => {
+            let actual = value(field)?;
+            values.iter().any(|expected| equals(&actual, expected))
+        }
+        Expr::Contains { field, value: expected } => match (value(field)?, expected) {
+            (Value::List(items), _) => items.iter().any(|item| equals(item, expected)),
+            (Value::String(text), Value::String(part)) => text.contains(part.as_str()),
+            _ => false,
+        },
+        Expr::And(exprs) => {
+            for expr in exprs {
+                if !matches(expr, value)? {
+                    return Ok(false);
+                }
+            }
+            true
+        }
+        Expr::Or(exprs) => {
+            for expr in exprs {
+                if matches(expr, value)? {
+                    return Ok(true);
+                }
+            }
+            false
+        }
+        Expr::Not(expr) => !matches(expr, value)?,
+    })
+}
+
+fn equals(a: &Value, b: &Value) -> bool {
+    a == b || compare_values(a, b) == Some(Ordering::Equal)
+}
+
+/// Orders values of the same kind, with `Null` before everything else.
+/// Values of different kinds do not compare.
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        (Value::Null, _) => Some(Ordering::Less),
+        (_, Value::Null) => Some(Ordering::Greater),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::Int(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+        (Value::Float(a), Value::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::DateTime(a), Value::DateTime(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+pub(crate) fn unknown_field(table: &str, path: &str) -> AppError {
+    AppError::ValidationError(format!("cannot query {table} by {path}"))
+}
+
+pub(crate) fn raw_query_unsupported(table: &str) -> AppError {
+    AppError::Repository(format!(
+        "raw queries against {table} are not supported by the in-memory repository"
+    ))
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::tenant::{command::MembershipCommand, view::MembershipView}, error::{AppError, AppResult}, ports::{Cursor, MembershipRepository, Page, SortBy}, query::ast::Value};
+use domain::{AggregateRoot, DateTime, DomainError, Membership, MembershipStatus, TenantId, UserId, error::MembershipError};
+
+use super::{InMemoryEventRepository, Records, missing, now, page_after};
+
+const TABLE: &str = "membership";
+const SORTABLE: &[&str] = &["user_id", "tenant_id", "status", "created_at", "version"];
+
+/// Memberships are keyed by `{tenant_id}:{user_id}`. A membership is visible
+/// to its tenant and to its user, and only its tenant may create or remove
+/// it; its user may save it, which is how invitations are accepted.
+pub struct InMemoryMembershipRepository {
+    memberships: Records<Membership>,
+    events: Arc<InMemoryEventRepository<Membership>>,
+}
+
+impl Default for InMemoryMembershipRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryMembershipRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<Membership>>) -> Self {
+        Self { memberships: Records::new(TABLE), events }
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<Membership>> {
+        self.events.clone()
+    }
+
+    /// Archives every membership of `tenant_id`, recording an `archived`
+    /// event for each.
+    pub(crate) fn archive_tenant(&self, tenant_id: &TenantId, actor: &UserId, now: DateTime) -> AppResult<()> {
+        for mut membership in self.memberships.filter(|membership| membership.belongs_to(tenant_id) && membership.status() != &MembershipStatus::Archived)? {
+            membership.archive(actor, now);
+            let key = key(membership.tenet_id(), membership.user_id());
+            self.memberships.save(&key, membership, &self.events, |membership| *membership.version(), |_, _| Ok(()))?;
+        }
+        Ok(())
+    }
+
+    /// Stores a new membership; with `max_members`, the tenant's members are
+    /// counted under the same lock the membership is written with.
+    fn insert(&self, ctx: SubjectContex, command: MembershipCommand, max_members: Option<u32>) -> AppResult<MembershipView> {
+        let tenant_id = command.tenet_id.clone().unwrap_or_else(|| ctx.tenant_id());
+        if tenant_id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let user_id = command.user_id.clone().ok_or_else(|| missing(TABLE, "user_id"))?;
+        let created_at = command.created_at.unwrap_or_else(now);
+        let key = key(&tenant_id, &user_id);
+
+        let mut membership = Membership::new(user_id, tenant_id, command.roles.unwrap_or_default(), command.status.unwrap_or_default(), created_at, 1);
+        membership.mark_created(&ctx.user_id, created_at);
+
+        let membership = self.memberships.save(&key, membership, &self.events, |membership| *membership.version(), |rows, membership| {
+            if rows.contains_key(&key) {
+                return Err(DomainError::from(MembershipError::AlreadyMember { user: membership.user_id().as_str().into(), tenant: membership.tenet_id().as_str().into() }).into());
+            }
+            if let Some(max_members) = max_members
+                && rows.values().filter(|member| member.belongs_to(membership.tenet_id())).count() as u32 >= max_members
+            {
+                return Err(DomainError::from(MembershipError::MemberLimitReached(max_members)).into());
+            }
+            Ok(())
+        })?;
+        Ok(membership.into())
+    }
+
+    fn page(&self, predicate: impl Fn(&Membership) -> bool, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>> {
+        let memberships = self.memberships.filter(predicate)?;
+        Ok(page_after(memberships, sort_by, SORTABLE, after, page_size, sort_key)?.map(MembershipView::from))
+    }
+}
+
+#[async_trait]
+impl MembershipRepository for InMemoryMembershipRepository {
+    async fn create(&self, ctx: SubjectContex, command: MembershipCommand) -> AppResult<MembershipView> {
+        self.insert(ctx, command, None)
+    }
+
+    async fn create_within_limit(&self, ctx: SubjectContex, command: MembershipCommand, max_members: u32) -> AppResult<MembershipView> {
+        self.insert(ctx, command, Some(max_members))
+    }
+
+    async fn get(&self, ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<MembershipView> {
+        Ok(self.load(ctx, tenant_id, user_id).await?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, membership: Membership) -> AppResult<bool> {
+        if !membership.belongs_to(&ctx.tenant_id) {
+            return Err(AppError::Forbidden);
+        }
+        let id = key(membership.tenet_id(), membership.user_id());
+        self.memberships.delete(&id, membership, &self.events)?;
+        Ok(true)
+    }
+
+    async fn count_members(&self, ctx: SubjectContex, tenant_id: TenantId) -> AppResult<u32> {
+        if tenant_id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        Ok(self.memberships.filter(|membership| membership.belongs_to(&tenant_id))?.len() as u32)
+    }
+
+    async fn get_members_page(&self, ctx: SubjectContex, tenant_id: TenantId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>> {
+        // Other tenants' member lists are not visible, not forbidden.
+        self.page(|membership| tenant_id == ctx.tenant_id && membership.belongs_to(&tenant_id), sort_by, after, page_size)
+    }
+
+    async fn get_user_tenants_page(&self, ctx: SubjectContex, user_id: UserId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<MembershipView>> {
+        self.page(|membership| membership.user_id() == &user_id && is_visible(membership, &ctx), sort_by, after, page_size)
+    }
+
+    async fn load(&self, ctx: SubjectContex, tenant_id: TenantId, user_id: UserId) -> AppResult<Membership> {
+        self.memberships
+            .get(&key(&tenant_id, &user_id))?
+            .filter(|membership| is_visible(membership, &ctx))
+            .ok_or_else(|| DomainError::from(MembershipError::NotFound).into())
+    }
+
+    async fn save(&self, ctx: SubjectContex, membership: Membership) -> AppResult<Membership> {
+        let key = key(membership.tenet_id(), membership.user_id());
+        self.memberships.save(&key, membership, &self.events, |membership| *membership.version(), |rows, membership| {
+            if !rows.contains_key(&key) {
+                return Err(DomainError::from(MembershipError::NotFound).into());
+            }
+            if !is_visible(membership, &ctx) {
+                return Err(AppError::Forbidden);
+            }
+            Ok(())
+        })
+    }
+}
+
+fn key(tenant_id: &TenantId, user_id: &UserId) -> String {
+    format!("{}:{}", tenant_id.as_str(), user_id.as_str())
+}
+
+fn is_visible(membership: &Membership, ctx: &SubjectContex) -> bool {
+    membership.belongs_to(&ctx.tenant_id) || membership.user_id() == &ctx.user_id
+}
+
+fn sort_key(membership: &Membership, field: &str) -> Value {
+    match field {
+        "id" => key(membership.tenet_id(), membership.user_id()).into(),
+        "user_id" => membership.user_id().into(),
+        "tenant_id" => membership.tenet_id().into(),
+        "status" => membership.status().to_string().into(),
+        "created_at" => (*membership.created_at()).into(),
+        "version" => (*membership.version()).into(),
+        _ => Value::Null,
+    }
+}
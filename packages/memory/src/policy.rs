@@ -0,0 +1,43 @@
+use std::sync::RwLock;
+
+use application::{authorization::policys::ApplicationStoredPolicy, ports::policy::PolicyRepository};
+
+#[derive(Default)]
+pub struct InMemoryPolicyRepository {
+    policies: RwLock<Vec<ApplicationStoredPolicy>>,
+}
+
+impl InMemoryPolicyRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_policies(policies: Vec<ApplicationStoredPolicy>) -> Self {
+        Self { policies: RwLock::new(policies) }
+    }
+
+    /// Stores `policy`, replacing any policy with the same id.
+    pub fn save(&self, policy: ApplicationStoredPolicy) {
+        let mut policies = self.policies.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match policies.iter_mut().find(|stored| stored.id.as_str() == policy.id.as_str()) {
+            Some(stored) => *stored = policy,
+            None => policies.push(policy),
+        }
+    }
+
+    pub fn delete(&self, id: &str) -> bool {
+        let mut policies = self.policies.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let before = policies.len();
+        policies.retain(|stored| stored.id.as_str() != id);
+        policies.len() != before
+    }
+}
+
+impl PolicyRepository for InMemoryPolicyRepository {
+    fn load_active_policies(&self) -> Vec<ApplicationStoredPolicy> {
+        self.policies
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
@@ -0,0 +1,321 @@
+use std::{cmp::Ordering, collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::report::{command::{ReportCommand, ReportContentCommand, ReportTypeCommand}, view::ReportView}, error::{AppError, AppResult}, ports::{Cursor, Page, ReportRepository, SortBy, report::ReportQueryResult}, projection::{Projection, projector::{ReadMask, ReadProjector}}, query::{Query, ast::Value}};
+use domain::{AggregateRoot, DateTime, Report, ReportContent, ReportId, ReportStatus, ReportType, TenantId, Title, UserId, report::content::ReviewComment};
+
+use super::{InMemoryEventRepository, Records, missing, next_version, now, page_after, paginate, raw_query_unsupported, select, sort_records, unknown_field};
+
+const TABLE: &str = "report";
+const SORTABLE: &[&str] = &["title", "status", "created_at", "updated_at", "due_date", "version"];
+
+/// Reports are scoped to the subject's tenant: a report is visible to its
+/// owner tenant and to the tenants it is shared with, and only the owner
+/// tenant may write it.
+pub struct InMemoryReportRepository {
+    reports: Records<Report>,
+    events: Arc<InMemoryEventRepository<Report>>,
+}
+
+impl Default for InMemoryReportRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryReportRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<Report>>) -> Self {
+        Self { reports: Records::new(TABLE), events }
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<Report>> {
+        self.events.clone()
+    }
+
+    /// Archives every report owned by `tenant_id` that is not archived yet,
+    /// recording an `archived` event for each.
+    pub(crate) fn archive_owned_by(&self, tenant_id: &TenantId, actor: &UserId, now: DateTime) -> AppResult<()> {
+        for mut report in self.reports.filter(|report| report.belongs_to(tenant_id) && !report.status().is_final())? {
+            report.archive(actor, now)?;
+            let id = report.id().as_str().to_string();
+            self.reports.save(&id, report, &self.events, |report| *report.version(), |_, _| Ok(()))?;
+        }
+        Ok(())
+    }
+
+    fn visible(&self, ctx: &SubjectContex, id: &ReportId) -> AppResult<Report> {
+        self.reports
+            .get(id.as_str())?
+            .filter(|report| is_visible(report, &ctx.tenant_id))
+            .ok_or(AppError::Repository("Report not found!".to_string()))
+    }
+}
+
+#[async_trait]
+impl ReportRepository for InMemoryReportRepository {
+    async fn create(&self, ctx: SubjectContex, command: ReportCommand) -> AppResult<ReportView> {
+        let id = command.id.clone().unwrap_or_else(|| ReportId::new(&self.reports.next_id()));
+        let owner_tenant = command.owner_tenant.clone().unwrap_or_else(|| ctx.tenant_id());
+        if owner_tenant != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        if self.reports.get(id.as_str())?.is_some() {
+            return Err(AppError::Repository(format!("{TABLE}:{id} already exists")));
+        }
+        let author_id = command.author_id.clone().unwrap_or_else(|| ctx.user_id());
+        let now = now();
+        let mut report = apply_command(id.clone(), None, command, owner_tenant, author_id, None, now)?;
+        report.mark_created(&ctx.user_id, now);
+        Ok(self.reports.save(id.as_str(), report, &self.events, |report| *report.version(), |_, _| Ok(()))?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, report_id: ReportId) -> AppResult<bool> {
+        let mut report = self.visible(&ctx, &report_id)?;
+        if !report.belongs_to(&ctx.tenant_id) {
+            return Err(AppError::Forbidden);
+        }
+        report.mark_deleted(&ctx.user_id, now());
+        self.reports.delete(report_id.as_str(), report, &self.events)?;
+        Ok(true)
+    }
+
+    async fn update(&self, ctx: SubjectContex, command: ReportCommand) -> AppResult<ReportView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let current = self.visible(&ctx, &id).map_err(|_| AppError::Repository("Report not Updated!".to_string()))?;
+        if !current.belongs_to(&ctx.tenant_id)
+            || command.owner_tenant.as_ref().is_some_and(|owner| owner != current.owner_tenant())
+        {
+            return Err(AppError::Forbidden);
+        }
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let owner_tenant = current.owner_tenant().clone();
+        let author_id = command.author_id.clone().unwrap_or_else(|| current.author_id().clone());
+        let now = now();
+        let mut report = apply_command(id.clone(), Some(current.clone()), command, owner_tenant, author_id, Some(version), now)?;
+        report.record_event("updated", &ctx.user_id, now, current);
+        Ok(self.reports.save(id.as_str(), report, &self.events, |report| *report.version(), |_, _| Ok(()))?.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: ReportId) -> AppResult<ReportView> {
+        Ok(self.visible(&ctx, &id)?.into())
+    }
+
+    async fn get_by_author_id(&self, ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32, auther_id: UserId) -> AppResult<Vec<ReportView>> {
+        let mut reports = self
+            .reports
+            .filter(|report| is_visible(report, &ctx.tenant_id) && report.is_author(&auther_id))?;
+        sort_records(&mut reports, sort_by, SORTABLE, compare)?;
+        Ok(paginate(reports, page, page_size).into_iter().map(ReportView::from).collect())
+    }
+
+    async fn get_by_title(&self, ctx: SubjectContex, title: Title) -> AppResult<ReportView> {
+        self.reports
+            .find(|report| is_visible(report, &ctx.tenant_id) && report.title() == &title)?
+            .map(ReportView::from)
+            .ok_or(AppError::Repository("Report not found!".to_string()))
+    }
+
+    async fn get_reports_paginated(&self, ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>> {
+        let mut reports = self.reports.filter(|report| is_visible(report, &ctx.tenant_id))?;
+        sort_records(&mut reports, sort_by, SORTABLE, compare)?;
+        Ok(paginate(reports, page, page_size).into_iter().map(ReportView::from).collect())
+    }
+
+    async fn get_reports_page(&self, ctx: SubjectContex, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<ReportView>> {
+        let reports = self.reports.filter(|report| is_visible(report, &ctx.tenant_id))?;
+        let page = page_after(reports, sort_by, SORTABLE, after, page_size, |report, field| value(report, field).unwrap_or(Value::Null))?;
+        Ok(page.map(ReportView::from))
+    }
+
+    async fn get_projected(&self, ctx: SubjectContex, id: ReportId, projection: &Projection<Report>) -> AppResult<ReportView> {
+        Ok(project(self.visible(&ctx, &id)?, projection))
+    }
+
+    async fn get_reports_paginated_projected(&self, ctx: SubjectContex, projection: &Projection<Report>, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<ReportView>> {
+        let mut reports = self.reports.filter(|report| is_visible(report, &ctx.tenant_id))?;
+        sort_records(&mut reports, sort_by, SORTABLE, compare)?;
+        Ok(paginate(reports, page, page_size).into_iter().map(|report| project(report, projection)).collect())
+    }
+
+    async fn query(&self, ctx: SubjectContex, query: &Query<Report>) -> AppResult<Vec<ReportView>> {
+        let reports = self.reports.filter(|report| is_visible(report, &ctx.tenant_id))?;
+        let reports = select(reports, query.ast(), |report, path| value(report, &path.to_string()))?;
+        if query.fields().is_empty() {
+            return Ok(reports.into_iter().map(ReportView::from).collect());
+        }
+        let mask = ReadMask::from_fields(query.fields().iter().copied());
+        Ok(reports.into_iter().map(|report| report.project_fields(&mask)).collect())
+    }
+
+    async fn raw_query(&self, _ctx: SubjectContex, _query: String) -> AppResult<ReportQueryResult> {
+        Err(raw_query_unsupported(TABLE))
+    }
+
+    async fn load(&self, ctx: SubjectContex, id: ReportId) -> AppResult<Report> {
+        self.visible(&ctx, &id)
+    }
+
+    async fn save(&self, ctx: SubjectContex, report: Report) -> AppResult<Report> {
+        let id = report.id().as_str().to_string();
+        self.reports.save(&id, report, &self.events, |report| *report.version(), |rows, report| {
+            let stored_elsewhere = rows.get(&id).is_some_and(|stored| !stored.belongs_to(&ctx.tenant_id));
+            if stored_elsewhere || !report.belongs_to(&ctx.tenant_id) {
+                return Err(AppError::Forbidden);
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Views hold no linked users, so the projection's relations are ignored.
+fn project(report: Report, projection: &Projection<Report>) -> ReportView {
+    report.project_fields(&ReadMask::from_fields(projection.fields().iter().copied()))
+}
+
+/// Field values as the report table stores them; arrays of review comments
+/// yield the list of the requested comment field.
+fn value(report: &Report, path: &str) -> AppResult<Value> {
+    let content = report.content();
+    let report_type = report.report_type();
+    let comments = |field: fn(&ReviewComment) -> Value| Value::List(content.review_comments().iter().map(field).collect());
+    Ok(match path {
+        "id" => report.id().into(),
+        "title" => report.title().title().into(),
+        "status" => (*report.status()).into(),
+        "author_id" => report.author_id().into(),
+        "owner_tenant" => report.owner_tenant().into(),
+        "shared_with_tenants" => Value::List(report.shared_with_tenants().iter().map(Value::from).collect()),
+        "assigned_reviewer_id" => Value::List(report.assigned_reviewer_id().iter().map(Value::from).collect()),
+        "created_at" => (*report.created_at()).into(),
+        "updated_at" => (*report.updated_at()).into(),
+        "due_date" => (*report.due_date()).into(),
+        "version" => (*report.version()).into(),
+        "content.body" => content.body().body().into(),
+        "content.attachments" => Value::List(content.attachments().iter().map(|url| url.url().into()).collect()),
+        "content.rejection_reason" => content.rejection_reason().as_ref().map(|reason| reason.comment()).into(),
+        "content.review_comments.reviewer_id" => comments(|comment| comment.reviewer_id().into()),
+        "content.review_comments.comment" => comments(|comment| comment.comment().comment().into()),
+        "content.review_comments.created_at" => comments(|comment| (*comment.created_at()).into()),
+        "report_type.id" => report_type.id().as_str().into(),
+        "report_type.name" => report_type.name().name().into(),
+        "report_type.description" => report_type.description().description().into(),
+        "report_type.created_at" => (*report_type.created_at()).into(),
+        _ => return Err(unknown_field(TABLE, path)),
+    })
+}
+
+fn is_visible(report: &Report, tenant: &TenantId) -> bool {
+    report.belongs_to(tenant) || report.is_shared_with(tenant)
+}
+
+fn compare(a: &Report, b: &Report, field: &str) -> Ordering {
+    match field {
+        "title" => a.title().title().cmp(b.title().title()),
+        "status" => a.status().to_string().cmp(&b.status().to_string()),
+        "created_at" => a.created_at().cmp(b.created_at()),
+        "updated_at" => a.updated_at().cmp(b.updated_at()),
+        "due_date" => a.due_date().cmp(b.due_date()),
+        "version" => a.version().cmp(b.version()),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Merges `command` over `current`; fields the command leaves empty keep
+/// their stored value, and an empty tenant set keeps the stored set.
+/// Reviewers and review comments only change through the report's review
+/// methods, so they are always carried over.
+pub fn apply_command(id: ReportId, current: Option<Report>, command: ReportCommand, owner_tenant: TenantId, author_id: UserId, version: Option<u64>, now: DateTime) -> AppResult<Report> {
+    let mut builder = Report::new(id.clone(), author_id, owner_tenant);
+    let mut title = None;
+    let mut content = None;
+    let mut report_type = None;
+    let mut reviewers = HashSet::new();
+    // New reports start as drafts, like the status default of the report table.
+    let mut status = ReportStatus::Draft;
+    if let Some(current) = current.map(Report::into_parts) {
+        status = current.status;
+        builder
+            .set_created_at(current.created_at)
+            .add_shared_tenants(current.shared_with_tenants);
+        if let Some(due_date) = current.due_date {
+            builder.set_due(due_date);
+        }
+        title = Some(current.title);
+        content = Some(current.content);
+        report_type = Some(current.report_type);
+        reviewers = current.assigned_reviewer_id;
+    }
+
+    // Status only moves through the report's lifecycle methods.
+    if command.status.is_some_and(|requested| requested != status) {
+        return Err(AppError::ValidationError(format!("{TABLE}:{id} status changes go through submit, review, approve, reject or archive")));
+    }
+    builder.set_status(status);
+    if let Some(created_at) = command.created_at {
+        builder.set_created_at(created_at);
+    }
+    if let Some(due_date) = command.due_date {
+        builder.set_due(due_date);
+    }
+    if !command.shared_with_tenants.is_empty() {
+        builder.add_shared_tenants(command.shared_with_tenants);
+    }
+    for reviewer in reviewers {
+        builder.add_reviewer(reviewer);
+    }
+    if let Some(content) = apply_content(content, command.content)? {
+        builder.set_content(content);
+    }
+    if let Some(report_type) = apply_report_type(report_type, command.report_type, now)? {
+        builder.set_report_type(report_type);
+    }
+    if let Some(version) = version {
+        builder.set_version(version);
+    }
+    let title = command.title.or(title).ok_or_else(|| missing(TABLE, "title"))?;
+    Ok(builder.build(title.title(), command.updated_at.unwrap_or(now))?)
+}
+
+fn apply_content(current: Option<ReportContent>, command: Option<ReportContentCommand>) -> AppResult<Option<ReportContent>> {
+    let Some(command) = command else {
+        return Ok(current);
+    };
+    let (body, attachments, review_comments, rejection_reason) = match current.map(ReportContent::into_parts) {
+        Some(parts) => (Some(parts.body), parts.attachments, parts.review_comments, parts.rejection_reason),
+        None => (None, HashSet::new(), HashSet::new(), None),
+    };
+    let mut builder = ReportContent::new();
+    if let Some(body) = command.body.or(body) {
+        builder.set_body(body);
+    }
+    for attachment in command.attachments.unwrap_or(attachments) {
+        builder.add_attachment(attachment);
+    }
+    for review_comment in review_comments {
+        builder.add_review_comment(review_comment);
+    }
+    if let Some(rejection_reason) = command.rejection_reason.unwrap_or(rejection_reason) {
+        builder.set_rejection_reason(rejection_reason);
+    }
+    Ok(Some(builder.build()?))
+}
+
+fn apply_report_type(current: Option<ReportType>, command: Option<ReportTypeCommand>, now: DateTime) -> AppResult<Option<ReportType>> {
+    let Some(command) = command else {
+        return Ok(current);
+    };
+    let (id, name, description, created_at) = match current.map(ReportType::into_parts) {
+        Some(parts) => (Some(parts.id), Some(parts.name), Some(parts.description), Some(parts.created_at)),
+        None => (None, None, None, None),
+    };
+    Ok(Some(ReportType::new(
+        command.id.or(id).ok_or_else(|| missing(TABLE, "report_type.id"))?,
+        command.name.or(name).ok_or_else(|| missing(TABLE, "report_type.name"))?,
+        command.description.or(description).ok_or_else(|| missing(TABLE, "report_type.description"))?,
+        command.created_at.or(created_at).unwrap_or(now),
+    )))
+}
@@ -0,0 +1,197 @@
+use std::{cmp::Ordering, collections::{BTreeMap, HashSet}, sync::Arc};
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::role::{command::RoleCommand, view::RoleView}, error::{AppError, AppResult}, ports::{Cursor, Page, RoleRepository, SortBy, role::RoleQueryResult}, query::ast::Value};
+use domain::{AggregateRoot, DateTime, Name, Permission, PermissionId, Role, RoleId};
+
+use super::{InMemoryEventRepository, Records, missing, next_version, now, page_after, paginate, raw_query_unsupported, sort_records};
+
+const TABLE: &str = "role";
+const SORTABLE: &[&str] = &["name", "created_at", "version"];
+
+/// Roles are shared by every tenant. Commands reference permissions by id,
+/// so the repository keeps a catalogue of the permissions it has seen.
+pub struct InMemoryRoleRepository {
+    roles: Records<Role>,
+    permissions: Records<Permission>,
+    events: Arc<InMemoryEventRepository<Role>>,
+}
+
+impl Default for InMemoryRoleRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryRoleRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<Role>>) -> Self {
+        Self { roles: Records::new(TABLE), permissions: Records::new("permission"), events }
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<Role>> {
+        self.events.clone()
+    }
+
+    /// Makes `permission` available to role commands that reference its id.
+    pub fn add_permission(&self, permission: Permission) -> AppResult<()> {
+        self.permissions.insert(permission.id().as_str().to_string(), permission)
+    }
+
+    fn resolve_permissions(&self, ids: HashSet<PermissionId>) -> AppResult<Vec<Permission>> {
+        ids.into_iter()
+            .map(|id| {
+                self.permissions
+                    .get(id.as_str())?
+                    .ok_or_else(|| AppError::ValidationError(format!("unknown permission {}", id.as_str())))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl RoleRepository for InMemoryRoleRepository {
+    async fn create(&self, ctx: SubjectContex, command: RoleCommand) -> AppResult<RoleView> {
+        let id = command.id.clone().unwrap_or_else(|| RoleId::new(&self.roles.next_id()));
+        if self.roles.get(id.as_str())?.is_some() {
+            return Err(AppError::Repository(format!("{TABLE}:{} already exists", id.as_str())));
+        }
+        let permissions = self.resolve_permissions(command.permissions.clone())?;
+        let now = now();
+        let mut role = apply_command(id.clone(), None, command, permissions, None, now)?;
+        role.mark_created(&ctx.user_id, now);
+        Ok(self.roles.save(id.as_str(), role, &self.events, |role| *role.version(), check_role)?.into())
+    }
+
+    async fn update(&self, ctx: SubjectContex, command: RoleCommand) -> AppResult<RoleView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let permissions = self.resolve_permissions(command.permissions.clone())?;
+        let current = self.roles.get(id.as_str())?.ok_or(AppError::Repository("Role not Updated!".to_string()))?;
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let now = now();
+        let mut role = apply_command(id.clone(), Some(current.clone()), command, permissions, Some(version), now)?;
+        role.record_event("updated", &ctx.user_id, now, current);
+        Ok(self.roles.save(id.as_str(), role, &self.events, |role| *role.version(), check_role)?.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: RoleId) -> AppResult<RoleView> {
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn get_by_name(&self, _ctx: SubjectContex, id: Name) -> AppResult<RoleView> {
+        self.roles
+            .find(|role| role.name() == &id)?
+            .map(RoleView::from)
+            .ok_or(AppError::Repository("Role not found!".to_string()))
+    }
+
+    async fn get_roles_paginated(&self, _ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<RoleView>> {
+        let mut roles = self.roles.filter(|_| true)?;
+        sort_records(&mut roles, sort_by, SORTABLE, compare)?;
+        Ok(paginate(roles, page, page_size).into_iter().map(RoleView::from).collect())
+    }
+
+    async fn get_roles_page(&self, _ctx: SubjectContex, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<RoleView>> {
+        let roles = self.roles.filter(|_| true)?;
+        Ok(page_after(roles, sort_by, SORTABLE, after, page_size, key)?.map(RoleView::from))
+    }
+
+    async fn delete(&self, ctx: SubjectContex, id: RoleId) -> AppResult<bool> {
+        let mut role = self.roles.get(id.as_str())?.ok_or(AppError::Repository("Role not deleted!".to_string()))?;
+        role.mark_deleted(&ctx.user_id, now());
+        self.roles.delete(id.as_str(), role, &self.events)?;
+        Ok(true)
+    }
+
+    async fn raw_query(&self, _ctx: SubjectContex, _query: String) -> AppResult<RoleQueryResult> {
+        Err(raw_query_unsupported(TABLE))
+    }
+
+    async fn load(&self, _ctx: SubjectContex, id: RoleId) -> AppResult<Role> {
+        self.roles
+            .get(id.as_str())?
+            .ok_or(AppError::Repository("Role not found!".to_string()))
+    }
+
+    async fn save(&self, _ctx: SubjectContex, role: Role) -> AppResult<Role> {
+        for permission in role.permissions() {
+            self.add_permission(permission.clone())?;
+        }
+        let id = role.id().as_str().to_string();
+        self.roles.save(&id, role, &self.events, |role| *role.version(), check_role)
+    }
+}
+
+fn key(role: &Role, field: &str) -> Value {
+    match field {
+        "id" => role.id().as_str().into(),
+        "name" => role.name().name().into(),
+        "created_at" => (*role.created_at()).into(),
+        "version" => (*role.version()).into(),
+        _ => Value::Null,
+    }
+}
+
+fn compare(a: &Role, b: &Role, field: &str) -> Ordering {
+    match field {
+        "name" => a.name().name().cmp(b.name().name()),
+        "created_at" => a.created_at().cmp(b.created_at()),
+        "version" => a.version().cmp(b.version()),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Mirrors the unique `name` index of the role table. Parent roles are
+/// checked by the role use cases, as for the Surreal adapter.
+fn check_role(rows: &BTreeMap<String, Role>, role: &Role) -> AppResult<()> {
+    match rows.values().find(|other| other.id() != role.id() && other.name() == role.name()) {
+        Some(_) => Err(AppError::Repository(format!("{TABLE} name {} already exists", role.name()))),
+        None => Ok(()),
+    }
+}
+
+/// Merges `command` over `current`; fields the command leaves empty keep
+/// their stored value, and an empty permission set keeps the stored one.
+pub fn apply_command(id: RoleId, current: Option<Role>, command: RoleCommand, permissions: Vec<Permission>, version: Option<u64>, now: DateTime) -> AppResult<Role> {
+    let mut builder = Role::new(id);
+    builder.set_is_system_role(false).set_created_at(now);
+    let mut stored_permissions = HashSet::new();
+    let mut stored_parent_roles = HashSet::new();
+    if let Some(current) = current.map(Role::into_parts) {
+        builder
+            .set_name(current.name)
+            .set_description(current.description)
+            .set_is_system_role(current.is_system_role)
+            .set_created_at(current.created_at);
+        stored_permissions = current.permissions;
+        stored_parent_roles = current.parent_roles;
+    }
+
+    if let Some(name) = command.name {
+        builder.set_name(name);
+    }
+    if let Some(description) = command.description {
+        builder.set_description(description);
+    }
+    if let Some(is_system_role) = command.is_system_role {
+        builder.set_is_system_role(is_system_role);
+    }
+    if let Some(created_at) = command.created_at {
+        builder.set_created_at(created_at);
+    }
+    if let Some(version) = version {
+        builder.set_version(version);
+    }
+    let permissions = if command.permissions.is_empty() { stored_permissions.into_iter().collect() } else { permissions };
+    for permission in permissions {
+        builder.add_permission(permission);
+    }
+    let parent_roles = command.parent_roles.unwrap_or(stored_parent_roles);
+    for parent in parent_roles {
+        builder.add_parent_role(parent);
+    }
+    Ok(builder.build()?)
+}
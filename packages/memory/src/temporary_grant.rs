@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use application::{SubjectContex, dto::tenant::{command::temporary_grant::TemporaryGrantCommand, view::temporary_grant::TemporaryGrantView}, error::{AppError, AppResult}, ports::{Cursor, Page, SortBy, TemporaryGrantRepository}, query::ast::Value};
+use domain::{Permission, PermissionId, TemporaryGrant, TemporaryGrantId, TenantId, UserId};
+
+use super::{Records, missing, now, page_after};
+
+const TABLE: &str = "temporary_grant";
+const SORTABLE: &[&str] = &["created_at", "expires_at", "version"];
+
+/// Grants are scoped to the subject's tenant. A grant issued from a command
+/// holds the single permission `{resource}:{action}`.
+pub struct InMemoryTemporaryGrantRepository {
+    grants: Records<TemporaryGrant>,
+}
+
+impl Default for InMemoryTemporaryGrantRepository {
+    fn default() -> Self {
+        Self { grants: Records::new(TABLE) }
+    }
+}
+
+impl InMemoryTemporaryGrantRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TemporaryGrantRepository for InMemoryTemporaryGrantRepository {
+    async fn issue(&self, ctx: SubjectContex, command: TemporaryGrantCommand) -> AppResult<TemporaryGrantView> {
+        let tenant_id = command.tenant_id.clone().ok_or_else(|| missing(TABLE, "tenant_id"))?;
+        if tenant_id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let id = command.id.clone().unwrap_or_else(|| TemporaryGrantId::new(&self.grants.next_id()));
+        let grant = self.grants.upsert(id.as_str(), |_, current| {
+            if current.is_some() {
+                return Err(AppError::Repository(format!("{TABLE}:{id} already exists")));
+            }
+            build(id.clone(), tenant_id.clone(), command)
+        })?;
+        Ok(grant.into())
+    }
+
+    async fn revoke(&self, ctx: SubjectContex, id: TemporaryGrantId) -> AppResult<bool> {
+        self.load(ctx, id.clone()).await?;
+        match self.grants.remove(id.as_str())? {
+            Some(_) => Ok(true),
+            None => Err(AppError::Repository("Temporary grant not revoked!".to_string())),
+        }
+    }
+
+    async fn get_by_user_page(&self, ctx: SubjectContex, user_id: UserId, tenant_id: TenantId, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<TemporaryGrantView>> {
+        let grants = self.load_by_user(ctx, user_id, tenant_id).await?;
+        Ok(page_after(grants, sort_by, SORTABLE, after, page_size, key)?.map(TemporaryGrantView::from))
+    }
+
+    async fn load(&self, ctx: SubjectContex, id: TemporaryGrantId) -> AppResult<TemporaryGrant> {
+        self.grants
+            .get(id.as_str())?
+            .filter(|grant| grant.belongs_to(&ctx.tenant_id))
+            .ok_or(AppError::Repository("Temporary grant not found!".to_string()))
+    }
+
+    async fn save(&self, ctx: SubjectContex, grant: TemporaryGrant) -> AppResult<TemporaryGrant> {
+        if !grant.belongs_to(&ctx.tenant_id) {
+            return Err(AppError::Forbidden);
+        }
+        let id = grant.id().as_str().to_string();
+        self.grants.upsert(&id, |_, current| {
+            if current.is_some_and(|stored| !stored.belongs_to(&ctx.tenant_id)) {
+                return Err(AppError::Forbidden);
+            }
+            Ok(grant)
+        })
+    }
+
+    async fn load_by_user(&self, ctx: SubjectContex, user_id: UserId, tenant_id: TenantId) -> AppResult<Vec<TemporaryGrant>> {
+        if tenant_id != ctx.tenant_id {
+            return Ok(Vec::new());
+        }
+        self.grants.filter(|grant| grant.user_id() == &user_id && grant.belongs_to(&tenant_id))
+    }
+}
+
+fn build(id: TemporaryGrantId, tenant_id: TenantId, command: TemporaryGrantCommand) -> AppResult<TemporaryGrant> {
+    let created_at = command.created_at.unwrap_or_else(now);
+    let description = command.description.ok_or_else(|| missing(TABLE, "description"))?;
+    let resource = command.resource.ok_or_else(|| missing(TABLE, "resource"))?;
+    let action = command.action.ok_or_else(|| missing(TABLE, "action"))?;
+    let permission = Permission::new(
+        PermissionId::new(&format!("{resource}:{action}")),
+        resource,
+        action,
+        description.clone(),
+        created_at,
+        0,
+    );
+
+    let mut grant = TemporaryGrant::new(id, command.user_id.ok_or_else(|| missing(TABLE, "user_id"))?, tenant_id);
+    grant
+        .set_description(description)
+        .add_permission(permission)
+        .set_expires_at(command.expires_at.ok_or_else(|| missing(TABLE, "expires_at"))?)
+        .set_created_at(created_at);
+    Ok(grant.build()?)
+}
+
+fn key(grant: &TemporaryGrant, field: &str) -> Value {
+    match field {
+        "id" => grant.id().as_str().into(),
+        "created_at" => (*grant.created_at()).into(),
+        "expires_at" => (*grant.expires_at()).into(),
+        "version" => (*grant.version()).into(),
+        _ => Value::Null,
+    }
+}
@@ -0,0 +1,285 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::tenant::{command::{TenantCommand, config::{TenantConfigCommand, authorization::TenantAuthorizationConfigCommand, collaboration::TenantCollaborationConfigCommand, environment::TenantEnvironmentConfigCommand, feature::{FeatureLimitsCommand, TenantFeatureConfigCommand}}}, view::TenantView}, error::{AppError, AppResult}, ports::{Cursor, Page, SortBy, TenantRepository, tenant::TenantQueryResult}, query::ast::Value};
+use domain::{AggregateRoot, DateTime, Tenant, TenantId, TenantStatus, tenant::config::{TenantConfig, authorization::TenantAuthorizationConfig, collaboration::{CollaborationAccessLevel, FederationPolicy, TenantCollaborationConfig}, environment::TenantEnvironmentConfig, feature::{FeatureLimits, FeatureRolloutPolicy, TenantFeatureConfig}}};
+
+use super::{InMemoryEventRepository, InMemoryMembershipRepository, InMemoryReportRepository, Records, missing, next_version, now, page_after, paginate, raw_query_unsupported, sort_records};
+
+const TABLE: &str = "tenant";
+const SORTABLE: &[&str] = &["name", "created_at", "version"];
+/// One day, for tenants created without an access grant bound.
+const DEFAULT_MAX_ACCESS_GRANT_DURATION: i64 = 24 * 60 * 60;
+
+/// A subject sees its own tenant and the tenants that trust it for
+/// collaboration, and may only write its own tenant. Purging a tenant
+/// archives its data in the report and membership repositories given to
+/// [`InMemoryTenantRepository::with_dependents`], and fails without them.
+pub struct InMemoryTenantRepository {
+    tenants: Records<Tenant>,
+    events: Arc<InMemoryEventRepository<Tenant>>,
+    reports: Option<Arc<InMemoryReportRepository>>,
+    memberships: Option<Arc<InMemoryMembershipRepository>>,
+}
+
+impl Default for InMemoryTenantRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryTenantRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<Tenant>>) -> Self {
+        Self { tenants: Records::new(TABLE), events, reports: None, memberships: None }
+    }
+
+    pub fn with_dependents(mut self, reports: Arc<InMemoryReportRepository>, memberships: Arc<InMemoryMembershipRepository>) -> Self {
+        self.reports = Some(reports);
+        self.memberships = Some(memberships);
+        self
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<Tenant>> {
+        self.events.clone()
+    }
+}
+
+#[async_trait]
+impl TenantRepository for InMemoryTenantRepository {
+    async fn create(&self, ctx: SubjectContex, command: TenantCommand) -> AppResult<TenantView> {
+        let id = command.id.clone().unwrap_or_else(|| TenantId::new(&self.tenants.next_id()));
+        if self.tenants.get(id.as_str())?.is_some() {
+            return Err(AppError::Repository(format!("{TABLE}:{id} already exists")));
+        }
+        let mut tenant = apply_command(id.clone(), None, command, 1)?;
+        tenant.mark_created(&ctx.user_id, now());
+        Ok(self.tenants.save(id.as_str(), tenant, &self.events, |tenant| *tenant.version(), |_, _| Ok(()))?.into())
+    }
+
+    async fn update(&self, ctx: SubjectContex, command: TenantCommand) -> AppResult<TenantView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let current = self
+            .tenants
+            .get(id.as_str())?
+            .filter(|tenant| is_visible(tenant, &ctx.tenant_id))
+            .ok_or(AppError::Repository("Tenant not Updated!".to_string()))?;
+        if current.id() != &ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let mut tenant = apply_command(id.clone(), Some(current.clone()), command, version)?;
+        tenant.record_event("updated", &ctx.user_id, now(), current);
+        Ok(self.tenants.save(id.as_str(), tenant, &self.events, |tenant| *tenant.version(), |_, _| Ok(()))?.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: TenantId) -> AppResult<TenantView> {
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, id: TenantId) -> AppResult<bool> {
+        if id != ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let mut tenant = self.tenants.get(id.as_str())?.ok_or(AppError::Repository("Tenant not deleted!".to_string()))?;
+        tenant.mark_deleted(&ctx.user_id, now());
+        self.tenants.delete(id.as_str(), tenant, &self.events)?;
+        Ok(true)
+    }
+
+    async fn get_tenants_paginated(&self, ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<TenantView>> {
+        let mut tenants = self.tenants.filter(|tenant| is_visible(tenant, &ctx.tenant_id))?;
+        sort_records(&mut tenants, sort_by, SORTABLE, compare)?;
+        Ok(paginate(tenants, page, page_size).into_iter().map(TenantView::from).collect())
+    }
+
+    async fn get_tenants_page(&self, ctx: SubjectContex, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<TenantView>> {
+        let tenants = self.tenants.filter(|tenant| is_visible(tenant, &ctx.tenant_id))?;
+        Ok(page_after(tenants, sort_by, SORTABLE, after, page_size, key)?.map(TenantView::from))
+    }
+
+    async fn raw_query(&self, _ctx: SubjectContex, _query: String) -> AppResult<TenantQueryResult> {
+        Err(raw_query_unsupported(TABLE))
+    }
+
+    async fn load(&self, ctx: SubjectContex, id: TenantId) -> AppResult<Tenant> {
+        self.tenants
+            .get(id.as_str())?
+            .filter(|tenant| is_visible(tenant, &ctx.tenant_id))
+            .ok_or(AppError::Repository("Tenant not found!".to_string()))
+    }
+
+    async fn save(&self, ctx: SubjectContex, tenant: Tenant) -> AppResult<Tenant> {
+        if tenant.id() != &ctx.tenant_id {
+            return Err(AppError::Forbidden);
+        }
+        let id = tenant.id().as_str().to_string();
+        self.tenants.save(&id, tenant, &self.events, |tenant| *tenant.version(), |_, _| Ok(()))
+    }
+
+    async fn get_due_for_purge(&self, _ctx: SubjectContex, now: DateTime) -> AppResult<Vec<Tenant>> {
+        self.tenants.filter(|tenant| tenant.is_purge_due(&now))
+    }
+
+    /// Runs for every tenant, not only the subject's. The in-memory tables
+    /// are locked one at a time, so unlike the database transaction a
+    /// failure can leave part of the tenant's data archived.
+    async fn purge(&self, ctx: SubjectContex, tenant: Tenant, now: DateTime) -> AppResult<Tenant> {
+        if tenant.status() != &TenantStatus::Deleted {
+            return Err(AppError::ValidationError(format!("{TABLE}:{} must be deleted before it is purged", tenant.id())));
+        }
+        let (Some(reports), Some(memberships)) = (&self.reports, &self.memberships) else {
+            return Err(AppError::Repository(format!("{TABLE}:{} cannot be purged without its report and membership repositories", tenant.id())));
+        };
+        let id = tenant.id().as_str().to_string();
+        let tenant = self.tenants.save(&id, tenant, &self.events, |tenant| *tenant.version(), |_, _| Ok(()))?;
+        reports.archive_owned_by(tenant.id(), &ctx.user_id, now)?;
+        memberships.archive_tenant(tenant.id(), &ctx.user_id, now)?;
+        Ok(tenant)
+    }
+}
+
+fn is_visible(tenant: &Tenant, subject_tenant: &TenantId) -> bool {
+    tenant.id() == subject_tenant || tenant.config().collaboration().trusted_tenants().contains(subject_tenant)
+}
+
+fn key(tenant: &Tenant, field: &str) -> Value {
+    match field {
+        "id" => tenant.id().into(),
+        "name" => tenant.name().name().into(),
+        "created_at" => (*tenant.created_at()).into(),
+        "version" => (*tenant.version()).into(),
+        _ => Value::Null,
+    }
+}
+
+fn compare(a: &Tenant, b: &Tenant, field: &str) -> Ordering {
+    match field {
+        "name" => a.name().name().cmp(b.name().name()),
+        "created_at" => a.created_at().cmp(b.created_at()),
+        "version" => a.version().cmp(b.version()),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Merges `command` over `current`; fields the command leaves empty keep
+/// their stored value; the status only changes through the lifecycle
+/// methods. A new tenant must bring its name, description and
+/// feature limits; other settings fall back to their most restrictive value.
+pub fn apply_command(id: TenantId, current: Option<Tenant>, command: TenantCommand, version: u64) -> AppResult<Tenant> {
+    let (name, description, created_at, config, status) = match current.map(Tenant::into_parts) {
+        Some(parts) => (Some(parts.name), Some(parts.description), Some(parts.created_at), Some(parts.config), parts.status),
+        None => (None, None, None, None, TenantStatus::Active),
+    };
+    Ok(Tenant::new(
+        id,
+        command.name.or(name).ok_or_else(|| missing(TABLE, "name"))?,
+        command.description.or(description).ok_or_else(|| missing(TABLE, "description"))?,
+        command.created_at.or(created_at).unwrap_or_else(now),
+        apply_config(config, command.config)?,
+        status,
+        version,
+    ))
+}
+
+fn apply_config(current: Option<TenantConfig>, command: Option<TenantConfigCommand>) -> AppResult<TenantConfig> {
+    let Some(command) = command else {
+        return current.ok_or_else(|| missing(TABLE, "config"));
+    };
+    let (authorization, environment, collaboration, features) = match current.map(TenantConfig::into_parts) {
+        Some(parts) => (Some(parts.authorization), Some(parts.environment), Some(parts.collaboration), Some(parts.features)),
+        None => (None, None, None, None),
+    };
+    Ok(TenantConfig::new(
+        apply_authorization(authorization, command.authorization),
+        apply_environment(environment, command.environment),
+        apply_collaboration(collaboration, command.collaboration),
+        apply_features(features, command.features)?,
+    ))
+}
+
+fn apply_authorization(current: Option<TenantAuthorizationConfig>, command: Option<TenantAuthorizationConfigCommand>) -> TenantAuthorizationConfig {
+    let Some(command) = command else {
+        return current.unwrap_or_else(|| TenantAuthorizationConfig::new(false, false, None, None, DEFAULT_MAX_ACCESS_GRANT_DURATION));
+    };
+    let (allow_cross_tenant_access, require_reviewer_for_publish, default_role, access_request_approver_role, max_access_grant_duration) = match current.map(TenantAuthorizationConfig::into_parts) {
+        Some(parts) => (parts.allow_cross_tenant_access, parts.require_reviewer_for_publish, parts.default_role, parts.access_request_approver_role, parts.max_access_grant_duration),
+        None => (false, false, None, None, DEFAULT_MAX_ACCESS_GRANT_DURATION),
+    };
+    TenantAuthorizationConfig::new(
+        command.allow_cross_tenant_access.unwrap_or(allow_cross_tenant_access),
+        command.require_reviewer_for_publish.unwrap_or(require_reviewer_for_publish),
+        command.default_role.or(default_role),
+        command.access_request_approver_role.or(access_request_approver_role),
+        command.max_access_grant_duration.unwrap_or(max_access_grant_duration),
+    )
+}
+
+fn apply_environment(current: Option<TenantEnvironmentConfig>, command: Option<TenantEnvironmentConfigCommand>) -> TenantEnvironmentConfig {
+    let Some(command) = command else {
+        return current.unwrap_or_else(|| TenantEnvironmentConfig::new(None, &[], false, None));
+    };
+    let (allowed_time_window, allowed_networks, require_managed_device, max_risk_score) = match current.map(TenantEnvironmentConfig::into_parts) {
+        Some(parts) => (parts.allowed_time_window, parts.allowed_networks, parts.require_managed_device, parts.max_risk_score),
+        None => (None, Vec::new(), false, None),
+    };
+    TenantEnvironmentConfig::new(
+        command.allowed_time_window.or(allowed_time_window),
+        &command.allowed_networks.unwrap_or(allowed_networks),
+        command.require_managed_device.unwrap_or(require_managed_device),
+        command.max_risk_score.or(max_risk_score),
+    )
+}
+
+fn apply_collaboration(current: Option<TenantCollaborationConfig>, command: Option<TenantCollaborationConfigCommand>) -> TenantCollaborationConfig {
+    let current = current.map(TenantCollaborationConfig::into_parts);
+    let command = command.unwrap_or(TenantCollaborationConfigCommand {
+        trusted_tenants: None,
+        allowed_modes: None,
+        federation: None,
+        access_level: None,
+    });
+    let (trusted_tenants, allowed_modes, federation, access_level) = match current {
+        Some(parts) => (parts.trusted_tenants, parts.allowed_modes, parts.federation, parts.access_level),
+        None => (Vec::new(), Vec::new(), FederationPolicy::Disabled, CollaborationAccessLevel::ReadOnly),
+    };
+    TenantCollaborationConfig::new(
+        command.trusted_tenants.unwrap_or(trusted_tenants),
+        command.allowed_modes.unwrap_or(allowed_modes),
+        command.federation.unwrap_or(federation),
+        command.access_level.unwrap_or(access_level),
+    )
+}
+
+fn apply_features(current: Option<TenantFeatureConfig>, command: Option<TenantFeatureConfigCommand>) -> AppResult<TenantFeatureConfig> {
+    let Some(command) = command else {
+        return current.ok_or_else(|| missing(TABLE, "config.features"));
+    };
+    let (enabled_features, limits, rollout) = match current.map(TenantFeatureConfig::into_parts) {
+        Some(parts) => (parts.enabled_features, Some(parts.limits), parts.rollout),
+        None => (Vec::new(), None, FeatureRolloutPolicy::Stable),
+    };
+    Ok(TenantFeatureConfig::new(
+        command.enabled_features.unwrap_or(enabled_features),
+        apply_limits(limits, command.limits)?,
+        command.rollout.unwrap_or(rollout),
+    )?)
+}
+
+fn apply_limits(current: Option<FeatureLimits>, command: Option<FeatureLimitsCommand>) -> AppResult<FeatureLimits> {
+    let Some(command) = command else {
+        return current.ok_or_else(|| missing(TABLE, "config.features.limits"));
+    };
+    let (max_projects, max_users, api_rate_limit) = match current.map(FeatureLimits::into_parts) {
+        Some(parts) => (Some(parts.max_projects), Some(parts.max_users), Some(parts.api_rate_limit)),
+        None => (None, None, None),
+    };
+    Ok(FeatureLimits::new(
+        command.max_projects.or(max_projects).ok_or_else(|| missing(TABLE, "config.features.limits.max_projects"))?,
+        command.max_users.or(max_users).ok_or_else(|| missing(TABLE, "config.features.limits.max_users"))?,
+        command.api_rate_limit.or(api_rate_limit).ok_or_else(|| missing(TABLE, "config.features.limits.api_rate_limit"))?,
+    ))
+}
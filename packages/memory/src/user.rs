@@ -0,0 +1,240 @@
+use std::{cmp::Ordering, collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use application::{SubjectContex, dto::user::{command::{UserCommand, UserPreferencesCommand, UserProfileCommand}, view::UserView}, error::{AppError, AppResult}, ports::{Cursor, Page, SortBy, UserRepository, user::UserQueryResult}, query::ast::Value};
+use domain::{AggregateRoot, DateTime, Email, User, UserId, UserProfile, Username, user::UserPreferences};
+
+use super::{InMemoryEventRepository, Records, missing, next_version, now, page_after, paginate, raw_query_unsupported, sort_records};
+
+const TABLE: &str = "user";
+const SORTABLE: &[&str] = &["email", "username", "last_login", "created_at", "version"];
+
+/// Users are not owned by a tenant, so every subject sees the same table.
+pub struct InMemoryUserRepository {
+    users: Records<User>,
+    events: Arc<InMemoryEventRepository<User>>,
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::with_events(Arc::new(InMemoryEventRepository::new()))
+    }
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(events: Arc<InMemoryEventRepository<User>>) -> Self {
+        Self { users: Records::new(TABLE), events }
+    }
+
+    pub fn events(&self) -> Arc<InMemoryEventRepository<User>> {
+        self.events.clone()
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn create(&self, ctx: SubjectContex, command: UserCommand) -> AppResult<UserView> {
+        let id = command.id.clone().unwrap_or_else(|| UserId::new(&self.users.next_id()));
+        if self.users.get(id.as_str())?.is_some() {
+            return Err(AppError::Repository(format!("{TABLE}:{id} already exists")));
+        }
+        let now = now();
+        let mut user = apply_command(id.clone(), None, command, None, now)?;
+        user.mark_created(&ctx.user_id, now);
+        Ok(self.users.save(id.as_str(), user, &self.events, |user| *user.version(), check_unique)?.into())
+    }
+
+    async fn update(&self, ctx: SubjectContex, command: UserCommand) -> AppResult<UserView> {
+        let id = command.id.clone().ok_or_else(|| missing(TABLE, "id"))?;
+        let current = self.users.get(id.as_str())?.ok_or(AppError::Repository("User not Updated!".to_string()))?;
+        let version = next_version(TABLE, id.as_str(), *current.version(), command.version)?;
+        let now = now();
+        let mut user = apply_command(id.clone(), Some(current.clone()), command, Some(version), now)?;
+        user.record_event("updated", &ctx.user_id, now, current);
+        Ok(self.users.save(id.as_str(), user, &self.events, |user| *user.version(), check_unique)?.into())
+    }
+
+    async fn get_by_id(&self, ctx: SubjectContex, id: UserId) -> AppResult<UserView> {
+        Ok(self.load(ctx, id).await?.into())
+    }
+
+    async fn delete(&self, ctx: SubjectContex, id: UserId) -> AppResult<bool> {
+        let mut user = self.users.get(id.as_str())?.ok_or(AppError::Repository("User not deleted!".to_string()))?;
+        user.mark_deleted(&ctx.user_id, now());
+        self.users.delete(id.as_str(), user, &self.events)?;
+        Ok(true)
+    }
+
+    async fn get_by_email(&self, _ctx: SubjectContex, email: Email) -> AppResult<UserView> {
+        self.users
+            .find(|user| user.email() == &email)?
+            .map(UserView::from)
+            .ok_or(AppError::Repository("User not found!".to_string()))
+    }
+
+    async fn get_by_username(&self, _ctx: SubjectContex, username: Username) -> AppResult<UserView> {
+        self.users
+            .find(|user| user.username() == &username)?
+            .map(UserView::from)
+            .ok_or(AppError::Repository("User not found!".to_string()))
+    }
+
+    async fn get_users_paginated(&self, _ctx: SubjectContex, sort_by: &[SortBy], page: u32, page_size: u32) -> AppResult<Vec<UserView>> {
+        let mut users = self.users.filter(|_| true)?;
+        sort_records(&mut users, sort_by, SORTABLE, compare)?;
+        Ok(paginate(users, page, page_size).into_iter().map(UserView::from).collect())
+    }
+
+    async fn get_users_page(&self, _ctx: SubjectContex, sort_by: &[SortBy], after: Option<&Cursor>, page_size: u32) -> AppResult<Page<UserView>> {
+        let users = self.users.filter(|_| true)?;
+        Ok(page_after(users, sort_by, SORTABLE, after, page_size, key)?.map(UserView::from))
+    }
+
+    async fn raw_query(&self, _ctx: SubjectContex, _query: String) -> AppResult<UserQueryResult> {
+        Err(raw_query_unsupported(TABLE))
+    }
+
+    async fn load(&self, _ctx: SubjectContex, id: UserId) -> AppResult<User> {
+        self.users
+            .get(id.as_str())?
+            .ok_or(AppError::Repository("User not found!".to_string()))
+    }
+
+    async fn save(&self, _ctx: SubjectContex, user: User) -> AppResult<User> {
+        let id = user.id().as_str().to_string();
+        self.users.save(&id, user, &self.events, |user| *user.version(), check_unique)
+    }
+}
+
+fn key(user: &User, field: &str) -> Value {
+    match field {
+        "id" => user.id().into(),
+        "email" => user.email().email().into(),
+        "username" => user.username().username().into(),
+        "last_login" => (*user.last_login()).into(),
+        "created_at" => (*user.profile().created_at()).into(),
+        "version" => (*user.version()).into(),
+        _ => Value::Null,
+    }
+}
+
+fn compare(a: &User, b: &User, field: &str) -> Ordering {
+    match field {
+        "email" => a.email().email().cmp(b.email().email()),
+        "username" => a.username().username().cmp(b.username().username()),
+        "last_login" => a.last_login().cmp(b.last_login()),
+        "created_at" => a.profile().created_at().cmp(b.profile().created_at()),
+        "version" => a.version().cmp(b.version()),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Mirrors the unique `email` and `username` indexes of the user table.
+fn check_unique(rows: &BTreeMap<String, User>, user: &User) -> AppResult<()> {
+    for other in rows.values().filter(|other| other.id() != user.id()) {
+        if other.email() == user.email() {
+            return Err(AppError::Repository(format!("{TABLE} email {} already exists", user.email())));
+        }
+        if other.username() == user.username() {
+            return Err(AppError::Repository(format!("{TABLE} username {} already exists", user.username())));
+        }
+    }
+    Ok(())
+}
+
+/// Merges `command` over `current`; fields the command leaves empty keep
+/// their stored value.
+pub fn apply_command(id: UserId, current: Option<User>, command: UserCommand, version: Option<u64>, now: DateTime) -> AppResult<User> {
+    let mut builder = User::new(id);
+    let mut profile = None;
+    let mut preferences = UserPreferences::default();
+    if let Some(current) = current.map(User::into_parts) {
+        builder
+            .set_email(current.email)
+            .set_username(current.username)
+            .set_status(current.status);
+        if let Some(failed_logins) = current.failed_logins {
+            builder.set_failed_logins(failed_logins);
+        }
+        if let Some(locked_until) = current.locked_until {
+            builder.set_locked_until(locked_until);
+        }
+        if let Some(last_login) = current.last_login {
+            builder.set_last_login(last_login);
+        }
+        profile = Some(current.profile);
+        preferences = current.preferences;
+    }
+
+    if let Some(email) = command.email {
+        builder.set_email(email);
+    }
+    if let Some(username) = command.username {
+        builder.set_username(username);
+    }
+    if let Some(status) = command.status {
+        builder.set_status(status);
+    }
+    if let Some(version) = version {
+        builder.set_version(version);
+    }
+    builder
+        .set_profile(apply_profile(profile, command.profile, now)?)
+        .set_preferences(apply_preferences(preferences, command.preferences));
+    Ok(builder.build()?)
+}
+
+fn apply_profile(current: Option<UserProfile>, command: Option<UserProfileCommand>, now: DateTime) -> AppResult<UserProfile> {
+    let command = command.unwrap_or_default();
+    if current.is_none() {
+        // The profile builder unwraps these, so a new user has to bring them.
+        for (field, present) in [
+            ("profile.first_name", command.first_name.is_some()),
+            ("profile.last_name", command.last_name.is_some()),
+            ("profile.password", command.password.is_some()),
+        ] {
+            if !present {
+                return Err(missing(TABLE, field));
+            }
+        }
+    }
+    let created_at = current.as_ref().map_or(now, |profile| *profile.created_at());
+    let current = current.map(UserProfile::into_parts).unwrap_or_default();
+
+    let mut builder = UserProfile::new();
+    builder
+        .set_first_name(command.first_name.unwrap_or(current.first_name))
+        .set_last_name(command.last_name.unwrap_or(current.last_name))
+        .set_password(command.password.unwrap_or(current.password))
+        .set_is_deleted(current.is_deleted)
+        .add_phone_numbers(command.phone_numbers.unwrap_or(current.phone_numbers))
+        .add_addresss(command.addressess.unwrap_or(current.addressess));
+    if let Some(bio) = command.bio.or(current.bio) {
+        builder.set_bio(bio);
+    }
+    if let Some(avatar_url) = command.avatar_url.or(current.avatar_url) {
+        builder.set_avatar_url(avatar_url);
+    }
+    if let Some(date_of_birth) = command.date_of_birth.or(current.date_of_birth) {
+        builder.set_date_of_birth(date_of_birth);
+    }
+    if let Some(website) = command.website.or(current.website) {
+        builder.set_website(website);
+    }
+    Ok(builder.build(created_at, now)?)
+}
+
+fn apply_preferences(current: UserPreferences, command: Option<UserPreferencesCommand>) -> UserPreferences {
+    let command = command.unwrap_or_default();
+    let current = current.into_parts();
+    UserPreferences::new(
+        command.email_notifications.unwrap_or(current.email_notifications),
+        command.push_notifications.unwrap_or(current.push_notifications),
+        command.two_factor_auth.unwrap_or(current.two_factor_auth),
+        command.language.unwrap_or(current.language),
+    )
+}
@@ -0,0 +1,117 @@
+//! Shared setup for the use case tests: a tenant, a permissive engine and a
+//! low risk environment.
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use application::{
+    SubjectContex,
+    authorization::{
+        AccessDecision,
+        engine::{AuthorizationContext, AuthorizationEngine},
+        policys::ApplicationAuthorizationPolicy,
+        ports::AuthorizationService,
+    },
+    dto::tenant::command::{
+        TenantCommand,
+        config::{
+            TenantConfigCommand,
+            authorization::TenantAuthorizationConfigCommand,
+            feature::{FeatureLimitsCommand, TenantFeatureConfigCommand},
+        },
+    },
+    ports::TenantRepository,
+};
+use domain::{
+    DateTime, Description, Name, RoleId, TenantId, UserId,
+    tenant::{
+        config::feature::Feature,
+        environment::{
+            Environment,
+            datetime::EnvironmentTime,
+            device_security_posture::DeviceSecurityPosture,
+            location::{EnvironmentLocation, LocationZone},
+            network_information::{ConnectionType, NetworkInformation},
+            risk_signals::{AuthenticationStrength, RiskSignals},
+        },
+    },
+};
+
+/// Allows every request the guards let through.
+pub struct AllowAll;
+
+impl ApplicationAuthorizationPolicy for AllowAll {
+    fn evaluate(&self, _ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        Some(AccessDecision::Allow)
+    }
+}
+
+/// An engine with the guards of `engine` and [`AllowAll`] behind them.
+pub fn allow_all(mut engine: AuthorizationEngine) -> Arc<dyn AuthorizationService> {
+    engine.register_policy(AllowAll);
+    Arc::new(engine)
+}
+
+pub fn env() -> Environment {
+    at(DateTime::new(10))
+}
+
+/// A trusted, multi factor session at `now`.
+pub fn at(now: DateTime) -> Environment {
+    Environment::new(
+        EnvironmentTime::new(now, true),
+        EnvironmentLocation::new(LocationZone::Unknown),
+        DeviceSecurityPosture::new(true, true, true),
+        NetworkInformation::new(false, true, ConnectionType::Unknown),
+        RiskSignals::new(0, AuthenticationStrength::MultiFactor, 0),
+    )
+}
+
+pub fn subject(user: &str, tenant: &str) -> SubjectContex {
+    SubjectContex::new(UserId::new(user), TenantId::new(tenant), &[], &[])
+}
+
+/// Settings of the tenant created by [`create_tenant`].
+#[derive(Default)]
+pub struct TenantSettings {
+    pub max_users: u32,
+    pub default_role: Option<RoleId>,
+    pub require_reviewer_for_publish: bool,
+    pub features: Vec<Feature>,
+}
+
+pub async fn create_tenant(
+    tenants: &dyn TenantRepository,
+    ctx: &SubjectContex,
+    settings: TenantSettings,
+) {
+    let command = TenantCommand {
+        id: Some(ctx.tenant_id()),
+        name: Some(Name::new("Tenant").unwrap()),
+        description: Some(Description::new("A tenant").unwrap()),
+        created_at: Some(DateTime::new(0)),
+        version: None,
+        config: Some(TenantConfigCommand {
+            authorization: Some(TenantAuthorizationConfigCommand {
+                allow_cross_tenant_access: None,
+                require_reviewer_for_publish: Some(settings.require_reviewer_for_publish),
+                default_role: settings.default_role,
+                access_request_approver_role: None,
+                max_access_grant_duration: None,
+            }),
+            environment: None,
+            collaboration: None,
+            features: Some(TenantFeatureConfigCommand {
+                enabled_features: Some(settings.features),
+                limits: Some(FeatureLimitsCommand {
+                    max_projects: Some(1),
+                    max_users: Some(settings.max_users.max(1)),
+                    api_rate_limit: Some(0),
+                }),
+                rollout: None,
+            }),
+        }),
+    };
+    tenants.create(ctx.clone(), command).await.unwrap();
+}
@@ -0,0 +1,279 @@
+mod common;
+
+use std::{collections::HashSet, sync::Arc};
+
+use application::{
+    SubjectContex,
+    authorization::{engine::AuthorizationEngine, guard::tenant::TenantIsolationPolicy},
+    dto::{role::command::RoleCommand, tenant::command::MembershipCommand},
+    error::AppError,
+    ports::{Cursor, MembershipRepository, RoleRepository, SortBy},
+    rate_limit::TokenBucketRateLimiter,
+    usecases::{
+        membership::{
+            MembershipScope,
+            accept::AcceptMembershipUseCase,
+            add::AddMemberUseCase,
+            change_roles::{ChangeMemberRolesInput, ChangeMemberRolesUseCase},
+            invite::InviteMemberUseCase,
+            list_members::{ListMembersInput, ListMembersUseCase},
+            remove::RemoveMemberUseCase,
+        },
+        pipeline::UseCase,
+    },
+};
+use common::{TenantSettings, allow_all, create_tenant, env, subject};
+use domain::{
+    Description, DomainError, MembershipStatus, Name, RoleId, UserId, error::MembershipError,
+};
+use memory::{InMemoryMembershipRepository, InMemoryRoleRepository, InMemoryTenantRepository};
+
+fn role(id: &str) -> RoleCommand {
+    RoleCommand {
+        id: Some(RoleId::new(id)),
+        name: Some(Name::new(id).unwrap()),
+        description: Some(Description::new("A role").unwrap()),
+        permissions: HashSet::new(),
+        parent_roles: None,
+        is_system_role: None,
+        created_at: None,
+        version: None,
+    }
+}
+
+/// An admin of `acme` holding the member, editor and admin roles; an
+/// `owner` role exists that the admin does not hold.
+async fn admin(roles: &InMemoryRoleRepository) -> SubjectContex {
+    let ctx = subject("boss", "acme");
+    let mut held = Vec::new();
+    for id in ["member", "editor", "admin"] {
+        roles.create(ctx.clone(), role(id)).await.unwrap();
+        held.push(roles.load(ctx.clone(), RoleId::new(id)).await.unwrap());
+    }
+    roles.create(ctx.clone(), role("owner")).await.unwrap();
+    SubjectContex::new(ctx.user_id(), ctx.tenant_id(), &held, &[])
+}
+
+fn invitation(user: &str, roles: &[&str]) -> MembershipCommand {
+    MembershipCommand {
+        user_id: Some(UserId::new(user)),
+        roles: (!roles.is_empty()).then(|| roles.iter().map(|id| RoleId::new(id)).collect()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn members_join_change_roles_and_leave() {
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let roles = Arc::new(InMemoryRoleRepository::new());
+    let members = Arc::new(InMemoryMembershipRepository::new());
+    let admin = admin(&roles).await;
+    let acme = admin.tenant_id();
+    create_tenant(
+        tenants.as_ref(),
+        &admin,
+        TenantSettings {
+            max_users: 2,
+            default_role: Some(RoleId::new("member")),
+            ..Default::default()
+        },
+    )
+    .await;
+    let mut engine = AuthorizationEngine::new();
+    engine.register_guard_policy(TenantIsolationPolicy);
+    let auth = allow_all(engine);
+    let add = AddMemberUseCase::new(
+        members.clone(),
+        tenants.clone(),
+        roles.clone(),
+        auth.clone(),
+    );
+    let invite = InviteMemberUseCase::new(
+        members.clone(),
+        tenants.clone(),
+        roles.clone(),
+        auth.clone(),
+    );
+
+    let added = add
+        .execute(admin.clone(), env(), invitation("ann", &[]))
+        .await
+        .unwrap();
+    assert_eq!(added.roles, Some(HashSet::from([RoleId::new("member")])));
+    assert_eq!(added.status, Some(MembershipStatus::Active));
+    assert!(
+        add.execute(admin.clone(), env(), invitation("ann", &[]))
+            .await
+            .is_err()
+    );
+
+    let invited = invite
+        .execute(admin.clone(), env(), invitation("ben", &["editor"]))
+        .await
+        .unwrap();
+    assert_eq!(invited.status, Some(MembershipStatus::Invited));
+    let result = invite
+        .execute(admin.clone(), env(), invitation("cat", &[]))
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::Domain(DomainError::MembershipError(
+                MembershipError::MemberLimitReached(2)
+            )))
+        ),
+        "{result:?}"
+    );
+
+    let accept = AcceptMembershipUseCase::new(members.clone(), tenants.clone(), auth.clone());
+    assert!(
+        accept
+            .execute(admin.clone(), env(), acme.clone())
+            .await
+            .is_err()
+    );
+    // The invitee accepts from their own tenant.
+    let ben = subject("ben", "home");
+    let accepted = accept.execute(ben, env(), acme.clone()).await.unwrap();
+    assert_eq!(accepted.status, Some(MembershipStatus::Active));
+
+    let change = ChangeMemberRolesUseCase::new(
+        members.clone(),
+        tenants.clone(),
+        roles.clone(),
+        auth.clone(),
+    );
+    let changed = change
+        .execute(
+            admin.clone(),
+            env(),
+            ChangeMemberRolesInput {
+                tenant_id: acme.clone(),
+                user_id: UserId::new("ann"),
+                roles: HashSet::from([RoleId::new("admin")]),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(changed.version, Some(2));
+    let result = change
+        .execute(
+            admin.clone(),
+            env(),
+            ChangeMemberRolesInput {
+                tenant_id: acme.clone(),
+                user_id: UserId::new("ann"),
+                roles: HashSet::from([RoleId::new("owner")]),
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+
+    assert!(
+        RemoveMemberUseCase::new(members.clone(), tenants.clone(), auth)
+            .execute(
+                admin.clone(),
+                env(),
+                MembershipScope::new(acme.clone(), UserId::new("ann"))
+            )
+            .await
+            .unwrap()
+    );
+    assert_eq!(members.count_members(admin, acme).await.unwrap(), 1);
+    let actions: Vec<String> = members
+        .events()
+        .all()
+        .unwrap()
+        .iter()
+        .map(|event| event.action().to_string())
+        .collect();
+    assert_eq!(
+        actions,
+        ["created", "created", "accepted", "roles_changed", "deleted"]
+    );
+}
+
+#[tokio::test]
+async fn invited_roles_must_exist_and_be_held_by_the_actor() {
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let roles = Arc::new(InMemoryRoleRepository::new());
+    let members = Arc::new(InMemoryMembershipRepository::new());
+    let admin = admin(&roles).await;
+    create_tenant(
+        tenants.as_ref(),
+        &admin,
+        TenantSettings {
+            max_users: 5,
+            ..Default::default()
+        },
+    )
+    .await;
+    let invite = InviteMemberUseCase::new(
+        members,
+        tenants,
+        roles,
+        allow_all(AuthorizationEngine::new()),
+    );
+
+    let result = invite
+        .execute(admin.clone(), env(), invitation("cat", &["owner"]))
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+    assert!(
+        invite
+            .execute(admin, env(), invitation("cat", &["ghost"]))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn member_pages_follow_the_cursor() {
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let roles = Arc::new(InMemoryRoleRepository::new());
+    let members = Arc::new(InMemoryMembershipRepository::new());
+    let admin = admin(&roles).await;
+    create_tenant(
+        tenants.as_ref(),
+        &admin,
+        TenantSettings {
+            max_users: 5,
+            default_role: Some(RoleId::new("member")),
+            ..Default::default()
+        },
+    )
+    .await;
+    let auth = allow_all(AuthorizationEngine::new());
+    let add = AddMemberUseCase::new(members.clone(), tenants.clone(), roles, auth.clone());
+    for user in ["ann", "ben"] {
+        add.execute(admin.clone(), env(), invitation(user, &[]))
+            .await
+            .unwrap();
+    }
+    let list = ListMembersUseCase::new(
+        members,
+        tenants,
+        auth,
+        Arc::new(TokenBucketRateLimiter::default()),
+    );
+    let input = |after: Option<Cursor>| ListMembersInput {
+        sort_by: vec![SortBy::Descending("user_id".into())],
+        after,
+        page_size: 1,
+    };
+
+    let first = list
+        .execute(admin.clone(), env(), input(None))
+        .await
+        .unwrap();
+    assert_eq!(first.items[0].user_id, Some(UserId::new("ben")));
+    assert!(first.has_more);
+    let cursor = Cursor::decode(&first.next_cursor.unwrap().encode()).unwrap();
+    let second = list
+        .execute(admin, env(), input(Some(cursor)))
+        .await
+        .unwrap();
+    assert_eq!(second.items[0].user_id, Some(UserId::new("ann")));
+    assert!(!second.has_more);
+    assert!(second.next_cursor.is_none());
+}
@@ -0,0 +1,315 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    authorization::engine::AuthorizationEngine,
+    dto::report::command::ReportCommand,
+    error::AppError,
+    ports::ReportRepository,
+    rate_limit::TokenBucketRateLimiter,
+    usecases::{
+        pipeline::UseCase,
+        report::{
+            ReportReview, ReportReviewer,
+            approve::ApproveReportUseCase,
+            assign_reviewer::AssignReportReviewerUseCase,
+            comment::CommentOnReportUseCase,
+            create::CreateReportUseCase,
+            delete::DeleteReportUseCase,
+            read::{
+                get_by::{id::GetReportByIdUseCase, title::GetReportByTitleUseCase},
+                list::{ListReportInput, ListReportUseCase},
+            },
+            update::UpdateReportUseCase,
+        },
+    },
+};
+use common::{TenantSettings, allow_all, create_tenant, env, subject};
+use domain::{
+    Comment, DomainError, ReportId, ReportStatus, Title, UserId, error::ReportError,
+    tenant::config::feature::Feature,
+};
+use memory::{InMemoryReportRepository, InMemoryTenantRepository};
+
+#[tokio::test]
+async fn report_crud_runs_through_the_use_cases() {
+    let reports = Arc::new(InMemoryReportRepository::new());
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let ctx = subject("alice", "acme");
+    create_tenant(tenants.as_ref(), &ctx, TenantSettings::default()).await;
+    let auth = allow_all(AuthorizationEngine::new());
+
+    let created = CreateReportUseCase::new(reports.clone(), tenants.clone(), auth.clone())
+        .execute(
+            ctx.clone(),
+            env(),
+            ReportCommand {
+                title: Some(Title::new("Quarterly_report").unwrap()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+    assert_eq!(created.author_id, Some(UserId::new("alice")));
+    assert_eq!(created.status, Some(ReportStatus::Draft));
+
+    let updated = UpdateReportUseCase::new(reports.clone(), tenants.clone(), auth.clone())
+        .execute(
+            ctx.clone(),
+            env(),
+            ReportCommand {
+                id: Some(id.clone()),
+                title: Some(Title::new("Yearly_report").unwrap()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.title, Some(Title::new("Yearly_report").unwrap()));
+    assert_eq!(updated.version, Some(2));
+
+    let by_id = GetReportByIdUseCase::new(reports.clone(), tenants.clone(), auth.clone())
+        .execute(ctx.clone(), env(), id.clone())
+        .await
+        .unwrap();
+    assert_eq!(by_id.title, updated.title);
+    let by_title = GetReportByTitleUseCase::new(reports.clone(), tenants.clone(), auth.clone())
+        .execute(ctx.clone(), env(), Title::new("Yearly_report").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(by_title.id, Some(id.clone()));
+
+    let page = ListReportUseCase::new(
+        reports.clone(),
+        tenants.clone(),
+        auth.clone(),
+        Arc::new(TokenBucketRateLimiter::default()),
+    )
+    .execute(
+        ctx.clone(),
+        env(),
+        ListReportInput {
+            sort_by: Vec::new(),
+            after: None,
+            page_size: 10,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert!(!page.has_more);
+
+    assert!(
+        DeleteReportUseCase::new(reports.clone(), tenants.clone(), auth)
+            .execute(ctx, env(), id)
+            .await
+            .unwrap()
+    );
+    let actions: Vec<String> = reports
+        .events()
+        .all()
+        .unwrap()
+        .iter()
+        .map(|event| event.action().to_string())
+        .collect();
+    assert_eq!(actions, ["created", "updated", "deleted"]);
+}
+
+#[tokio::test]
+async fn reports_of_another_tenant_are_not_found() {
+    let reports = Arc::new(InMemoryReportRepository::new());
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let alice = subject("alice", "acme");
+    let mallory = subject("mallory", "evil");
+    create_tenant(tenants.as_ref(), &alice, TenantSettings::default()).await;
+    create_tenant(tenants.as_ref(), &mallory, TenantSettings::default()).await;
+    let auth = allow_all(AuthorizationEngine::new());
+
+    let created = CreateReportUseCase::new(reports.clone(), tenants.clone(), auth.clone())
+        .execute(
+            alice,
+            env(),
+            ReportCommand {
+                title: Some(Title::new("Quarterly_report").unwrap()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = GetReportByIdUseCase::new(reports, tenants, auth)
+        .execute(mallory, env(), created.id.unwrap())
+        .await;
+    assert!(matches!(result, Err(AppError::Repository(_))), "{result:?}");
+}
+
+/// Moves the report into review, as the submit and review steps would.
+async fn in_review(reports: &InMemoryReportRepository, author: &str, id: &ReportId) {
+    let ctx = subject(author, "acme");
+    let mut report = reports.load(ctx.clone(), id.clone()).await.unwrap();
+    report
+        .submit(&ctx.user_id(), domain::DateTime::new(1))
+        .unwrap();
+    report
+        .start_review(&ctx.user_id(), domain::DateTime::new(2))
+        .unwrap();
+    reports.save(ctx, report).await.unwrap();
+}
+
+#[tokio::test]
+async fn publishing_needs_a_comment_from_an_assigned_reviewer() {
+    let reports = Arc::new(InMemoryReportRepository::new());
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let alice = subject("alice", "acme");
+    let bob = subject("bob", "acme");
+    let carol = subject("carol", "acme");
+    create_tenant(
+        tenants.as_ref(),
+        &alice,
+        TenantSettings {
+            require_reviewer_for_publish: true,
+            features: vec![Feature::AdvancedReports],
+            ..Default::default()
+        },
+    )
+    .await;
+    let auth = allow_all(AuthorizationEngine::new());
+    let assign = AssignReportReviewerUseCase::new(reports.clone(), tenants.clone(), auth.clone());
+    let comment = CommentOnReportUseCase::new(reports.clone(), tenants.clone(), auth.clone());
+    let approve = ApproveReportUseCase::new(reports.clone(), tenants.clone(), auth.clone());
+
+    let id = CreateReportUseCase::new(reports.clone(), tenants.clone(), auth)
+        .execute(
+            alice.clone(),
+            env(),
+            ReportCommand {
+                title: Some(Title::new("Quarterly_report").unwrap()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    in_review(&reports, "alice", &id).await;
+
+    let result = approve.execute(bob.clone(), env(), id.clone()).await;
+    assert!(
+        matches!(result, Err(AppError::PolicyError(_))),
+        "{result:?}"
+    );
+
+    let result = assign
+        .execute(
+            alice.clone(),
+            env(),
+            ReportReviewer {
+                report_id: id.clone(),
+                reviewer_id: UserId::new("alice"),
+            },
+        )
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::Domain(DomainError::ReportError(
+                ReportError::AuthorAsReviewer
+            )))
+        ),
+        "{result:?}"
+    );
+    let assigned = assign
+        .execute(
+            alice.clone(),
+            env(),
+            ReportReviewer {
+                report_id: id.clone(),
+                reviewer_id: UserId::new("bob"),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(assigned.assigned_reviewer_id.contains(&UserId::new("bob")));
+
+    let review = |reviewer: &str| ReportReview {
+        report_id: id.clone(),
+        comment: Comment::new(&format!("Reviewed by {reviewer}")).unwrap(),
+    };
+    let result = comment.execute(carol, env(), review("carol")).await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::Domain(DomainError::ReportError(
+                ReportError::NotReviewer(_)
+            )))
+        ),
+        "{result:?}"
+    );
+    let commented = comment
+        .execute(bob.clone(), env(), review("bob"))
+        .await
+        .unwrap();
+    let comments = commented.content.unwrap().review_comments.unwrap();
+    assert_eq!(comments.len(), 1);
+    assert!(
+        comments
+            .iter()
+            .all(|comment| comment.reviewer_id == Some(UserId::new("bob")))
+    );
+
+    let result = approve.execute(alice, env(), id.clone()).await;
+    assert!(
+        matches!(result, Err(AppError::PolicyError(_))),
+        "{result:?}"
+    );
+    let approved = approve.execute(bob, env(), id).await.unwrap();
+    assert_eq!(approved.status, Some(ReportStatus::Approved));
+}
+
+#[tokio::test]
+async fn review_features_are_checked_after_access() {
+    let reports = Arc::new(InMemoryReportRepository::new());
+    let tenants = Arc::new(InMemoryTenantRepository::new());
+    let alice = subject("alice", "acme");
+    create_tenant(tenants.as_ref(), &alice, TenantSettings::default()).await;
+    let id = CreateReportUseCase::new(
+        reports.clone(),
+        tenants.clone(),
+        allow_all(AuthorizationEngine::new()),
+    )
+    .execute(
+        alice.clone(),
+        env(),
+        ReportCommand {
+            title: Some(Title::new("Quarterly_report").unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap()
+    .id
+    .unwrap();
+    let input = || ReportReviewer {
+        report_id: id.clone(),
+        reviewer_id: UserId::new("bob"),
+    };
+
+    // Nothing is allowed without a policy, so the feature is not revealed.
+    let denied = AssignReportReviewerUseCase::new(
+        reports.clone(),
+        tenants.clone(),
+        Arc::new(AuthorizationEngine::new()),
+    );
+    let result = denied.execute(alice.clone(), env(), input()).await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+
+    let allowed =
+        AssignReportReviewerUseCase::new(reports, tenants, allow_all(AuthorizationEngine::new()));
+    let result = allowed.execute(alice, env(), input()).await;
+    assert!(
+        matches!(result, Err(AppError::PolicyError(_))),
+        "{result:?}"
+    );
+}
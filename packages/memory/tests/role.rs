@@ -0,0 +1,85 @@
+mod common;
+
+use std::{collections::HashSet, sync::Arc};
+
+use application::{
+    authorization::engine::AuthorizationEngine,
+    dto::role::command::RoleCommand,
+    error::AppError,
+    usecases::{
+        pipeline::UseCase,
+        role::{create::CreateRoleUseCase, update::UpdateRoleUseCase},
+    },
+};
+use common::{allow_all, env, subject};
+use domain::{Description, DomainError, Name, RoleId, error::RoleError};
+use memory::InMemoryRoleRepository;
+
+fn role(id: &str, parents: &[&str]) -> RoleCommand {
+    RoleCommand {
+        id: Some(RoleId::new(id)),
+        name: Some(Name::new(id).unwrap()),
+        description: Some(Description::new("A role").unwrap()),
+        permissions: HashSet::new(),
+        parent_roles: Some(parents.iter().map(|id| RoleId::new(id)).collect()),
+        is_system_role: None,
+        created_at: None,
+        version: None,
+    }
+}
+
+#[tokio::test]
+async fn role_hierarchies_stay_acyclic() {
+    let roles = Arc::new(InMemoryRoleRepository::new());
+    let auth = allow_all(AuthorizationEngine::new());
+    let create = CreateRoleUseCase::new(roles.clone(), auth.clone());
+    let update = UpdateRoleUseCase::new(roles.clone(), auth);
+    let ctx = subject("boss", "acme");
+
+    create
+        .execute(ctx.clone(), env(), role("member", &[]))
+        .await
+        .unwrap();
+    create
+        .execute(ctx.clone(), env(), role("editor", &["member"]))
+        .await
+        .unwrap();
+    let admin = create
+        .execute(ctx.clone(), env(), role("admin", &["editor"]))
+        .await
+        .unwrap();
+    assert_eq!(admin.parent_roles, HashSet::from([RoleId::new("editor")]));
+
+    let result = create
+        .execute(ctx.clone(), env(), role("owner", &["ghost"]))
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(AppError::Domain(DomainError::RoleError(
+                RoleError::UnknownParentRole(_)
+            )))
+        ),
+        "{result:?}"
+    );
+    for parents in [&["member"][..], &["admin"], &["editor", "admin"]] {
+        let result = update
+            .execute(ctx.clone(), env(), role("member", parents))
+            .await;
+        assert!(
+            matches!(
+                result,
+                Err(AppError::Domain(DomainError::RoleError(
+                    RoleError::CyclicHierarchy(_)
+                )))
+            ),
+            "{result:?}"
+        );
+    }
+
+    let updated = update
+        .execute(ctx, env(), role("admin", &["member"]))
+        .await
+        .unwrap();
+    assert_eq!(updated.parent_roles, HashSet::from([RoleId::new("member")]));
+}
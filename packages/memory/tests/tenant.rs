@@ -0,0 +1,166 @@
+mod common;
+
+use std::{collections::HashSet, sync::Arc};
+
+use application::{
+    authorization::{engine::AuthorizationEngine, guard::tenant::TenantStatusPolicy},
+    dto::{report::command::ReportCommand, tenant::command::MembershipCommand},
+    error::AppError,
+    ports::{MembershipRepository, ReportRepository},
+    rate_limit::TokenBucketRateLimiter,
+    usecases::{
+        membership::list_members::{ListMembersInput, ListMembersUseCase},
+        pipeline::UseCase,
+        tenant::{
+            PLATFORM_TENANT, delete::DeleteTenantUseCase, purge::PurgeTenantsUseCase,
+            reactivate::ReactivateTenantUseCase, suspend::SuspendTenantUseCase,
+        },
+    },
+};
+use common::{TenantSettings, allow_all, at, create_tenant, env, subject};
+use domain::{DateTime, MembershipStatus, TenantId, TenantStatus, Title, UserId};
+use memory::{InMemoryMembershipRepository, InMemoryReportRepository, InMemoryTenantRepository};
+
+#[tokio::test]
+async fn suspended_tenants_are_locked_out_and_deleted_ones_purged() {
+    let reports = Arc::new(InMemoryReportRepository::new());
+    let members = Arc::new(InMemoryMembershipRepository::new());
+    let tenants =
+        Arc::new(InMemoryTenantRepository::new().with_dependents(reports.clone(), members.clone()));
+    let ctx = subject("boss", "acme");
+    let acme = ctx.tenant_id();
+    create_tenant(
+        tenants.as_ref(),
+        &ctx,
+        TenantSettings {
+            max_users: 5,
+            ..Default::default()
+        },
+    )
+    .await;
+    reports
+        .create(
+            ctx.clone(),
+            ReportCommand {
+                title: Some(Title::new("Quarterly_report").unwrap()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    members
+        .create(
+            ctx.clone(),
+            MembershipCommand {
+                user_id: Some(UserId::new("ann")),
+                roles: Some(HashSet::new()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let mut engine = AuthorizationEngine::new();
+    engine.register_guard_policy(TenantStatusPolicy);
+    let auth = allow_all(engine);
+    let list = ListMembersUseCase::new(
+        members.clone(),
+        tenants.clone(),
+        auth.clone(),
+        Arc::new(TokenBucketRateLimiter::default()),
+    );
+    let input = ListMembersInput {
+        sort_by: Vec::new(),
+        after: None,
+        page_size: 10,
+    };
+    let reactivate = ReactivateTenantUseCase::new(tenants.clone(), auth.clone());
+
+    assert!(
+        list.execute(ctx.clone(), env(), input.clone())
+            .await
+            .is_ok()
+    );
+    let suspended = SuspendTenantUseCase::new(tenants.clone(), auth.clone())
+        .execute(ctx.clone(), env(), acme.clone())
+        .await
+        .unwrap();
+    assert_eq!(suspended.status, Some(TenantStatus::Suspended));
+    let result = list.execute(ctx.clone(), env(), input.clone()).await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+    reactivate
+        .execute(ctx.clone(), env(), acme.clone())
+        .await
+        .unwrap();
+    assert!(list.execute(ctx.clone(), env(), input).await.is_ok());
+
+    let deleted = DeleteTenantUseCase::new(tenants.clone(), auth.clone())
+        .with_grace_period(100)
+        .execute(ctx.clone(), env(), acme.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        deleted.status,
+        Some(TenantStatus::PendingDeletion {
+            purge_at: DateTime::new(110)
+        })
+    );
+    let purge = PurgeTenantsUseCase::new(tenants.clone(), auth.clone());
+    assert!(
+        purge
+            .execute(ctx.clone(), env(), ())
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let purged = purge
+        .execute(ctx.clone(), at(DateTime::new(200)), ())
+        .await
+        .unwrap();
+    assert_eq!(purged[0].status, Some(TenantStatus::Deleted));
+
+    let actions: Vec<String> = reports
+        .events()
+        .all()
+        .unwrap()
+        .iter()
+        .map(|event| event.action().to_string())
+        .collect();
+    assert_eq!(actions.last().map(String::as_str), Some("archived"));
+    let member = members
+        .get(ctx.clone(), acme.clone(), UserId::new("ann"))
+        .await
+        .unwrap();
+    assert_eq!(member.status, Some(MembershipStatus::Archived));
+    assert!(reactivate.execute(ctx, env(), acme).await.is_err());
+}
+
+#[tokio::test]
+async fn only_platform_subjects_purge_other_tenants() {
+    let tenants = Arc::new(InMemoryTenantRepository::new().with_dependents(
+        Arc::new(InMemoryReportRepository::new()),
+        Arc::new(InMemoryMembershipRepository::new()),
+    ));
+    let auth = allow_all(AuthorizationEngine::new());
+    let delete = DeleteTenantUseCase::new(tenants.clone(), auth.clone()).with_grace_period(100);
+    let purge = PurgeTenantsUseCase::new(tenants.clone(), auth);
+    let acme = subject("boss", "acme");
+    let globex = subject("boss", "globex");
+    for ctx in [&acme, &globex] {
+        create_tenant(tenants.as_ref(), ctx, TenantSettings::default()).await;
+        delete
+            .execute(ctx.clone(), env(), ctx.tenant_id())
+            .await
+            .unwrap();
+    }
+    let later = || at(DateTime::new(200));
+
+    let purged = purge.execute(acme, later(), ()).await.unwrap();
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].id, Some(TenantId::new("acme")));
+    let purged = purge
+        .execute(subject("cron", PLATFORM_TENANT), later(), ())
+        .await
+        .unwrap();
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].id, Some(TenantId::new("globex")));
+}
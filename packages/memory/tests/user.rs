@@ -0,0 +1,71 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    SubjectContex,
+    authorization::{
+        AccessDecision,
+        engine::{AuthorizationContext, AuthorizationEngine},
+        guard::tenant::TenantIsolationPolicy,
+        policys::ApplicationAuthorizationPolicy,
+        ports::AuthorizationService,
+    },
+    dto::user::command::{UserCommand, UserProfileCommand},
+    error::AppError,
+    ports::UserRepository,
+    usecases::{pipeline::UseCase, user::read::get_by::id::GetUserByIdUseCase},
+};
+use common::{env, subject};
+use domain::{DateTime, Email, Name, Password, TenantId, UserId, Username};
+use memory::InMemoryUserRepository;
+
+/// Lets a subject read only its own user.
+struct SelfOnly;
+
+impl ApplicationAuthorizationPolicy for SelfOnly {
+    fn evaluate(&self, ctx: &AuthorizationContext) -> Option<AccessDecision> {
+        ctx.relations
+            .iter()
+            .any(|relation| &**relation == "self")
+            .then_some(AccessDecision::Allow)
+    }
+}
+
+#[tokio::test]
+async fn users_read_only_themselves() {
+    let users = Arc::new(InMemoryUserRepository::new());
+    let mut engine = AuthorizationEngine::new();
+    engine.register_policy(TenantIsolationPolicy);
+    engine.register_policy(SelfOnly);
+    let auth: Arc<dyn AuthorizationService> = Arc::new(engine);
+    let get = GetUserByIdUseCase::new(users.clone(), auth);
+    let admin = subject("admin", "acme");
+    let command = UserCommand {
+        email: Some(Email::new("alice@example.com").unwrap()),
+        username: Some(Username::new("alice").unwrap()),
+        profile: Some(UserProfileCommand {
+            first_name: Some(Name::new("Alice").unwrap()),
+            last_name: Some(Name::new("Smith").unwrap()),
+            password: Some(Password::default()),
+            date_of_birth: Some(DateTime::new(1)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let id = users
+        .create(admin.clone(), command)
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+
+    let alice = SubjectContex::new(id.clone(), TenantId::new("acme"), &[], &[]);
+    let view = get.execute(alice, env(), id.clone()).await.unwrap();
+    assert_eq!(view.username, Some(Username::new("alice").unwrap()));
+    let result = get.execute(admin.clone(), env(), id).await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+    // A missing user looks the same as a forbidden one.
+    let result = get.execute(admin, env(), UserId::new("nobody")).await;
+    assert!(matches!(result, Err(AppError::Forbidden)), "{result:?}");
+}